@@ -0,0 +1,263 @@
+//! Measures dispatching requests to the first and last operation of a 50-operation service
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use soap_service::service;
+
+#[derive(Debug)]
+pub struct ServiceError(pub String);
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+#[service(
+    namespace = "http://example.com/operations",
+    service_name = "OperationsService",
+    port_name = "OperationsPort",
+    bind_path = "/soap/operations"
+)]
+mod operations {
+    use super::ServiceError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ValueRequest {
+        #[serde(rename = "Value")]
+        pub value: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ValueResponse {
+        #[serde(rename = "Value")]
+        pub value: i32,
+    }
+
+    pub async fn op_00(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_01(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_02(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_03(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_04(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_05(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_06(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_07(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_08(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_09(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_10(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_11(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_12(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_13(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_14(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_15(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_16(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_17(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_18(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_19(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_20(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_21(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_22(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_23(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_24(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_25(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_26(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_27(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_28(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_29(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_30(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_31(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_32(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_33(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_34(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_35(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_36(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_37(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_38(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_39(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_40(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_41(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_42(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_43(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_44(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_45(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_46(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_47(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_48(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+
+    pub async fn op_49(req: ValueRequest) -> Result<ValueResponse, ServiceError> {
+        Ok(ValueResponse { value: req.value })
+    }
+}
+
+fn request(operation: &str) -> String {
+    format!(
+        r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+    <soap:Body><{0}><Value>1</Value></{0}></soap:Body>
+</soap:Envelope>"#,
+        operation
+    )
+}
+
+fn operation_dispatch(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    let first = request("Op00");
+    let last = request("Op49");
+
+    c.bench_function("dispatch_first_of_50", |b| {
+        b.to_async(&runtime).iter(|| operations::handle_raw(&first))
+    });
+    c.bench_function("dispatch_last_of_50", |b| {
+        b.to_async(&runtime).iter(|| operations::handle_raw(&last))
+    });
+}
+
+criterion_group!(benches, operation_dispatch);
+criterion_main!(benches);
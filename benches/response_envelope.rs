@@ -0,0 +1,67 @@
+//! Measures answering an operation in SOAP 1.1 and SOAP 1.2 envelopes
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use soap_service::service;
+
+#[derive(Debug)]
+pub struct ServiceError(pub String);
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+#[service(
+    namespace = "http://example.com/calculator",
+    service_name = "CalculatorService",
+    port_name = "CalculatorPort",
+    bind_path = "/soap/calculator",
+    soap_versions = "1.1, 1.2"
+)]
+mod calculator {
+    use super::ServiceError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i32,
+        #[serde(rename = "Operand2")]
+        pub b: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddResponse {
+        #[serde(rename = "Result")]
+        pub sum: i32,
+    }
+
+    pub async fn add(req: AddRequest) -> Result<AddResponse, ServiceError> {
+        Ok(AddResponse { sum: req.a + req.b })
+    }
+}
+
+const SOAP_11_REQUEST: &str = r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+    <soap:Body><Add><Operand1>15</Operand1><Operand2>25</Operand2></Add></soap:Body>
+</soap:Envelope>"#;
+
+const SOAP_12_REQUEST: &str = r#"<soap:Envelope xmlns:soap="http://www.w3.org/2003/05/soap-envelope">
+    <soap:Body><Add><Operand1>15</Operand1><Operand2>25</Operand2></Add></soap:Body>
+</soap:Envelope>"#;
+
+fn response_envelope(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+    c.bench_function("add_soap_11", |b| {
+        b.to_async(&runtime).iter(|| calculator::handle_raw(SOAP_11_REQUEST))
+    });
+    c.bench_function("add_soap_12", |b| {
+        b.to_async(&runtime).iter(|| calculator::handle_raw(SOAP_12_REQUEST))
+    });
+}
+
+criterion_group!(benches, response_envelope);
+criterion_main!(benches);
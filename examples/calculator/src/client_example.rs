@@ -0,0 +1,115 @@
+use soap_service::service;
+
+#[derive(Debug)]
+pub struct ServiceError(pub String);
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+// Also generates `calculator::client`, a typed client calling the service over HTTP
+#[service(
+    namespace = "http://example.com/calculator",
+    service_name = "CalculatorService",
+    port_name = "CalculatorPort",
+    bind_path = "/soap/calculator",
+    generate_client
+)]
+mod calculator {
+    use super::ServiceError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i32,
+        #[serde(rename = "Operand2")]
+        pub b: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddResponse {
+        #[serde(rename = "Result")]
+        pub sum: i32,
+    }
+
+    pub async fn add(req: AddRequest) -> Result<AddResponse, ServiceError> {
+        Ok(AddResponse { sum: req.a + req.b })
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct DivideRequest {
+        #[serde(rename = "Dividend")]
+        pub dividend: i32,
+        #[serde(rename = "Divisor")]
+        pub divisor: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct DivideResponse {
+        #[serde(rename = "Quotient")]
+        pub quotient: i32,
+    }
+
+    pub async fn divide(req: DivideRequest) -> Result<DivideResponse, ServiceError> {
+        if req.divisor == 0 {
+            return Err(ServiceError("Division by zero".to_string()));
+        }
+        Ok(DivideResponse { quotient: req.dividend / req.divisor })
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, calculator::router()).await.unwrap() });
+
+    let client = calculator::client::Client::new(&base_url);
+    let response = client.add(calculator::AddRequest { a: 2, b: 3 }).await.unwrap();
+    println!("2 + 3 = {}", response.sum);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::calculator;
+    use super::calculator::client::{Client, ClientError};
+
+    async fn serve() -> Client {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, calculator::router()).await.unwrap() });
+        Client::new(&base_url)
+    }
+
+    #[tokio::test]
+    async fn client_calls_the_served_router() {
+        let client = serve().await;
+
+        let response = client.add(calculator::AddRequest { a: 2, b: 3 }).await.unwrap();
+
+        assert_eq!(response.sum, 5);
+    }
+
+    #[tokio::test]
+    async fn client_returns_faults_as_errors() {
+        let client = serve().await;
+
+        let error = client
+            .divide(calculator::DivideRequest { dividend: 1, divisor: 0 })
+            .await
+            .unwrap_err();
+
+        match error {
+            ClientError::Fault { code, message } => {
+                assert_eq!(code, "soap:Server");
+                assert!(message.contains("Division by zero"), "{}", message);
+            }
+            other => panic!("expected a fault, got {}", other),
+        }
+    }
+}
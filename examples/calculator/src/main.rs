@@ -35,10 +35,17 @@ mod calculator {
         pub sum: i32,
     }
 
-    pub async fn add(req: AddRequest) -> Result<AddResponse, ServiceError> {
-        if req.a == 0 && req.b == 0 {
-            return Err(ServiceError("Cannot add two zeros".to_string()));
+    // Requests with equal operands are rejected with a Client fault before add runs
+    impl Validate for AddRequest {
+        fn validate(&self) -> Result<(), String> {
+            if self.a == self.b {
+                return Err("Operand1 and Operand2 must differ".to_string());
+            }
+            Ok(())
         }
+    }
+
+    pub async fn add(req: AddRequest) -> Result<AddResponse, ServiceError> {
         Ok(AddResponse { sum: req.a + req.b })
     }
 }
@@ -54,5 +61,255 @@ async fn main() {
     println!("Calculator SOAP Service running on http://localhost:3000");
     println!("WSDL available at: http://localhost:3000/soap/calculator/wsdl");
 
-    axum::serve(listener, app).await.unwrap();
-}
\ No newline at end of file
+    axum::serve(listener, app)
+        .with_graceful_shutdown(calculator::shutdown_signal())
+        .await
+        .unwrap();
+}
+#[cfg(test)]
+mod tests {
+    use super::calculator;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn add_response_matches_schema() {
+        let response = calculator::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Add><Operand1>15</Operand1><Operand2>25</Operand2></Add></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(calculator::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn add_rejects_equal_operands() {
+        let fault = calculator::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Add><Operand1>7</Operand1><Operand2>7</Operand2></Add></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Client</faultcode>"));
+        assert!(fault.contains("Operand1 and Operand2 must differ"));
+    }
+
+    #[tokio::test]
+    async fn fault_detail_names_the_operation() {
+        let fault = calculator::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Add><Operand1>7</Operand1><Operand2>7</Operand2></Add></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(
+            fault.contains(r#"<detail><Operation xmlns="http://example.com/calculator">Add</Operation></detail>"#),
+            "{}",
+            fault
+        );
+        // The request is only echoed with echo_request_in_fault
+        assert!(!fault.contains("<Request"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn handle_raw_returns_the_response_or_fault_envelope() {
+        let response = calculator::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Add><Operand1>2</Operand1><Operand2>3</Operand2></Add></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            response,
+            r#"<?xml version="1.0" encoding="UTF-8"?><soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/" xmlns:tns="http://example.com/calculator"><soap:Body><tns:AddResponse xmlns:tns="http://example.com/calculator" xmlns="http://example.com/calculator"><Result>5</Result></tns:AddResponse></soap:Body></soap:Envelope>"#
+        );
+
+        let fault = calculator::handle_raw("<soap:Envelope").await.unwrap_err();
+
+        assert!(fault.contains("<soap:Fault><faultcode>soap:Client</faultcode>"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn body_with_several_elements_is_refused() {
+        let fault = calculator::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body>
+                    <Add><Operand1>2</Operand1><Operand2>3</Operand2></Add>
+                    <Add><Operand1>4</Operand1><Operand2>5</Operand2></Add>
+                    <Extra/>
+                </soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Client</faultcode>"), "{}", fault);
+        assert!(
+            fault.contains("SOAP Body must contain a single operation element, found 3: Add, Add, Extra"),
+            "{}",
+            fault
+        );
+        // Neither operation element was dispatched
+        assert!(!fault.contains("<Result>"), "{}", fault);
+    }
+
+    async fn response_content_type(content_type: &str, accept: Option<&str>) -> String {
+        let mut request = axum::http::Request::post("/soap/calculator").header("Content-Type", content_type);
+        if let Some(accept) = accept {
+            request = request.header("Accept", accept);
+        }
+        let request = request
+            .body(axum::body::Body::from(
+                r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                    <soap:Body><Add><Operand1>2</Operand1><Operand2>3</Operand2></Add></soap:Body>
+                </soap:Envelope>"#,
+            ))
+            .unwrap();
+        let response = calculator::router().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        response.headers()["Content-Type"].to_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn text_xml_request_without_accept_gets_text_xml() {
+        assert_eq!(response_content_type("text/xml", None).await, "text/xml; charset=utf-8");
+    }
+
+    #[tokio::test]
+    async fn text_xml_request_accepting_soap_xml_gets_soap_xml() {
+        assert_eq!(
+            response_content_type("text/xml", Some("application/soap+xml")).await,
+            "application/soap+xml; charset=utf-8"
+        );
+    }
+
+    #[tokio::test]
+    async fn soap_xml_request_without_accept_gets_soap_xml() {
+        assert_eq!(
+            response_content_type("application/soap+xml; charset=utf-8", None).await,
+            "application/soap+xml; charset=utf-8"
+        );
+    }
+
+    #[tokio::test]
+    async fn soap_xml_request_accepting_text_xml_gets_text_xml() {
+        assert_eq!(
+            response_content_type("application/soap+xml", Some("text/xml")).await,
+            "text/xml; charset=utf-8"
+        );
+    }
+
+    #[tokio::test]
+    async fn accept_prefers_the_higher_quality() {
+        assert_eq!(
+            response_content_type("text/xml", Some("application/soap+xml;q=0.5, text/xml")).await,
+            "text/xml; charset=utf-8"
+        );
+        assert_eq!(
+            response_content_type("text/xml", Some("text/xml;q=0.2, application/soap+xml;q=0.8")).await,
+            "application/soap+xml; charset=utf-8"
+        );
+    }
+
+    #[tokio::test]
+    async fn wildcard_accept_keeps_the_request_type() {
+        assert_eq!(response_content_type("text/xml", Some("*/*")).await, "text/xml; charset=utf-8");
+        assert_eq!(
+            response_content_type("application/soap+xml", Some("*/*")).await,
+            "application/soap+xml; charset=utf-8"
+        );
+    }
+
+    async fn get(uri: &str) -> (axum::http::StatusCode, String) {
+        let request = axum::http::Request::get(uri).body(axum::body::Body::empty()).unwrap();
+        let response = calculator::router().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn bind_path_serves_the_wsdl_on_a_wsdl_query() {
+        let (status, wsdl) = get("/soap/calculator?wsdl").await;
+
+        assert_eq!(status, 200);
+        assert!(wsdl.contains(r#"<service name="CalculatorService">"#), "{}", wsdl);
+        assert_eq!(wsdl, get("/soap/calculator/wsdl").await.1);
+        assert_eq!(get("/soap/calculator?WSDL").await.0, 200);
+    }
+
+    #[tokio::test]
+    async fn bind_path_without_a_wsdl_query_points_to_the_wsdl() {
+        let (status, message) = get("/soap/calculator").await;
+
+        assert_eq!(status, 405);
+        assert_eq!(
+            message,
+            "CalculatorService accepts SOAP requests via POST. \
+             The WSDL is available at /soap/calculator/wsdl and /soap/calculator?wsdl."
+        );
+    }
+
+    async fn wsdl_with_forwarded_proto(forwarded_proto: Option<&str>) -> String {
+        let mut request = axum::http::Request::get("/soap/calculator/wsdl").header("Host", "calculator.example.com");
+        if let Some(forwarded_proto) = forwarded_proto {
+            request = request.header("X-Forwarded-Proto", forwarded_proto);
+        }
+        let response = calculator::router()
+            .oneshot(request.body(axum::body::Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn forwarded_https_is_advertised_in_the_address() {
+        let wsdl = wsdl_with_forwarded_proto(Some("https")).await;
+        assert!(
+            wsdl.contains(r#"<soap:address location="https://calculator.example.com/soap/calculator"/>"#),
+            "{}",
+            wsdl
+        );
+
+        // A proxy chain lists the client-facing scheme first
+        let wsdl = wsdl_with_forwarded_proto(Some("https, http")).await;
+        assert!(wsdl.contains(r#"location="https://calculator.example.com/soap/calculator""#), "{}", wsdl);
+    }
+
+    #[tokio::test]
+    async fn address_without_a_forwarded_proto_uses_http() {
+        let wsdl = wsdl_with_forwarded_proto(None).await;
+
+        assert!(
+            wsdl.contains(r#"<soap:address location="http://calculator.example.com/soap/calculator"/>"#),
+            "{}",
+            wsdl
+        );
+    }
+
+    #[tokio::test]
+    async fn add_accepts_a_bom_prefixed_request() {
+        let response = calculator::handle_raw(
+            "\u{feff}\n  <?xml version=\"1.0\" encoding=\"UTF-8\"?>
+            <soap:Envelope xmlns:soap=\"http://schemas.xmlsoap.org/soap/envelope/\">
+                <soap:Body><Add><Operand1>15</Operand1><Operand2>25</Operand2></Add></soap:Body>
+            </soap:Envelope>",
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Result>40</Result>"), "{}", response);
+    }
+}
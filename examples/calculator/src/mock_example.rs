@@ -0,0 +1,133 @@
+use soap_service::service;
+
+#[derive(Debug)]
+pub struct ServiceError(pub String);
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+// A stand-in for the calculator, so clients can be tested against its contract
+#[service(
+    namespace = "http://example.com/calculator",
+    service_name = "CalculatorService",
+    port_name = "CalculatorPort",
+    bind_path = "/soap/calculator",
+    mock = true
+)]
+mod calculator_mock {
+    use super::ServiceError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i32,
+        #[serde(rename = "Operand2")]
+        pub b: i32,
+    }
+
+    // Answered when no response is registered
+    #[derive(Deserialize, Serialize, Debug, Default)]
+    pub struct AddResponse {
+        #[serde(rename = "Result")]
+        pub sum: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct SubtractRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i32,
+        #[serde(rename = "Operand2")]
+        pub b: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug, Default)]
+    pub struct SubtractResponse {
+        #[serde(rename = "Result")]
+        pub difference: i32,
+    }
+
+    // Never called by the mock service
+    pub async fn add(req: AddRequest) -> Result<AddResponse, ServiceError> {
+        Ok(AddResponse { sum: req.a + req.b })
+    }
+
+    pub async fn subtract(req: SubtractRequest) -> Result<SubtractResponse, ServiceError> {
+        Ok(SubtractResponse { difference: req.a - req.b })
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    calculator_mock::set_mock_response(
+        "Add",
+        r#"<tns:AddResponse xmlns:tns="http://example.com/calculator"><Result>42</Result></tns:AddResponse>"#,
+    );
+
+    let app = calculator_mock::router();
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:3004").await.unwrap();
+
+    println!("Mock Calculator SOAP Service running on http://localhost:3004");
+    println!("WSDL available at: http://localhost:3004/soap/calculator/wsdl");
+
+    axum::serve(listener, app).await.unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::calculator_mock;
+
+    #[tokio::test]
+    async fn valid_request_gets_the_registered_response() {
+        calculator_mock::set_mock_response(
+            "Add",
+            r#"<tns:AddResponse xmlns:tns="http://example.com/calculator"><Result>42</Result></tns:AddResponse>"#,
+        );
+
+        let response = calculator_mock::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Add xmlns="http://example.com/calculator"><Operand1>1</Operand1><Operand2>2</Operand2></Add></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Result>42</Result>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn unregistered_operation_gets_the_default_response() {
+        let response = calculator_mock::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Subtract xmlns="http://example.com/calculator"><Operand1>5</Operand1><Operand2>2</Operand2></Subtract></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Result>0</Result>"), "{}", response);
+        assert_eq!(calculator_mock::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn request_not_matching_the_schema_is_a_client_fault() {
+        let fault = calculator_mock::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Add xmlns="http://example.com/calculator"><Operand1>one</Operand1></Add></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Client</faultcode>"), "{}", fault);
+        assert!(fault.contains("Request does not match the schema"), "{}", fault);
+        assert!(fault.contains("Add: element Operand1 value \"one\" is not a valid xsd:int"), "{}", fault);
+        assert!(fault.contains("Add: missing required element Operand2"), "{}", fault);
+    }
+}
@@ -12,10 +12,15 @@ impl std::fmt::Display for ServiceError {
 impl std::error::Error for ServiceError {}
 
 #[service(
-    namespace = "http://example.com/math",
+    // Any absolute URI names the namespace, not only http(s) URLs
+    namespace = "urn:example:math",
     service_name = "MathService", 
     port_name = "MathPort",
-    bind_path = "/soap/math"
+    bind_path = "/soap/math",
+    // Some clients only accept envelopes in the prefix their own stack writes
+    envelope_prefix = "soapenv",
+    // Legacy clients send the operation element in any casing
+    case_insensitive_operations = true
 )]
 mod math_service {
     use super::ServiceError;
@@ -63,4 +68,146 @@ async fn main() {
     println!("WSDL available at: http://localhost:3001/soap/math/wsdl");
 
     axum::serve(listener, app).await.unwrap();
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::math_service;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn urn_namespace_round_trips() {
+        let request = axum::http::Request::get("/soap/math/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = math_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(wsdl.contains(r#"targetNamespace="urn:example:math""#), "{}", wsdl);
+        assert!(wsdl.contains(r#"xmlns:tns="urn:example:math""#), "{}", wsdl);
+        assert!(wsdl.contains(r#"<soap:operation soapAction="urn:example:math/Multiply"/>"#), "{}", wsdl);
+
+        let response = math_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><m:Multiply xmlns:m="urn:example:math"><FirstNumber>6</FirstNumber><SecondNumber>7</SecondNumber></m:Multiply></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains(r#"<tns:MultiplyResponse xmlns:tns="urn:example:math""#), "{}", response);
+        assert_eq!(math_service::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn lower_cased_operation_is_dispatched() {
+        let response = math_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><multiply><FirstNumber>6</FirstNumber><SecondNumber>7</SecondNumber></multiply></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<soapenv:Body><tns:MultiplyResponse "), "{}", response);
+        assert!(response.contains("<Product>42</Product>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn response_uses_the_envelope_prefix() {
+        let response = math_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Multiply><FirstNumber>6</FirstNumber><SecondNumber>7</SecondNumber></Multiply></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(
+            response.contains(r#"<soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/""#),
+            "{}",
+            response
+        );
+        assert!(response.contains("<soapenv:Body><tns:MultiplyResponse "), "{}", response);
+        assert!(response.ends_with("</soapenv:Body></soapenv:Envelope>"), "{}", response);
+        assert_eq!(math_service::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn optional_precision_is_an_optional_unsigned_byte() {
+        let request = axum::http::Request::get("/soap/math/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = math_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(
+            wsdl.contains(r#"<xsd:element name="Precision" type="xsd:unsignedByte" minOccurs="0" nillable="true"/>"#),
+            "{}",
+            wsdl
+        );
+    }
+
+    #[tokio::test]
+    async fn nil_precision_reads_as_none() {
+        let response = math_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"
+                    xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+                <soap:Body><Multiply>
+                    <FirstNumber>1.234</FirstNumber><SecondNumber>1</SecondNumber><Precision xsi:nil="true"/>
+                </Multiply></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        // Without a precision the product is rounded to two decimals
+        assert!(response.contains("<Product>1.23</Product>"), "{}", response);
+        assert_eq!(math_service::validate_against_schema(&response), Ok(()));
+    }
+
+    async fn multiply(x: &str, y: &str, precision: u8) -> String {
+        let response = math_service::handle_raw(&format!(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Multiply>
+                    <FirstNumber>{}</FirstNumber><SecondNumber>{}</SecondNumber><Precision>{}</Precision>
+                </Multiply></soap:Body>
+            </soap:Envelope>"#,
+            x, y, precision
+        ))
+        .await
+        .unwrap();
+
+        assert_eq!(math_service::validate_against_schema(&response), Ok(()));
+        let start = response.find("<Product>").unwrap() + "<Product>".len();
+        let end = response.find("</Product>").unwrap();
+        response[start..end].to_string()
+    }
+
+    #[tokio::test]
+    async fn very_large_product_uses_an_exponent() {
+        assert_eq!(multiply("1E200", "1E100", 0).await, "1E300");
+    }
+
+    #[tokio::test]
+    async fn very_small_product_uses_an_exponent() {
+        assert_eq!(multiply("2E-5", "4E-5", 12).await, "8E-10");
+    }
+
+    #[tokio::test]
+    async fn fault_uses_the_envelope_prefix() {
+        let fault = math_service::handle_raw(
+            r#"<soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/">
+                <soapenv:Body><Divide><FirstNumber>6</FirstNumber></Divide></soapenv:Body>
+            </soapenv:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("<soapenv:Fault><faultcode>soapenv:Client</faultcode>"), "{}", fault);
+        assert!(fault.contains("Unknown operation: Divide"), "{}", fault);
+        assert!(!fault.contains("soap:"), "{}", fault);
+    }
+}
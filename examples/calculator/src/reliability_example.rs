@@ -0,0 +1,213 @@
+use soap_service::service;
+
+#[derive(Debug)]
+pub struct ServiceError(pub String);
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+// Every operation gets 300 ms unless it sets its own timeout, and WS-ReliableMessaging
+// clients get their messages acknowledged
+#[service(
+    namespace = "http://example.com/calculator",
+    service_name = "CalculatorService",
+    port_name = "CalculatorPort",
+    bind_path = "/soap/calculator",
+    timeout_ms = 300,
+    ws_rm = true
+)]
+mod calculator_reliable {
+    use super::ServiceError;
+    use serde::{Deserialize, Serialize};
+
+    // The delay stands in for a slow backend
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i32,
+        #[serde(rename = "Operand2")]
+        pub b: i32,
+        #[serde(rename = "DelayMs")]
+        pub delay_ms: Option<u64>,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddResponse {
+        #[serde(rename = "Result")]
+        pub sum: i32,
+    }
+
+    pub async fn add(req: AddRequest) -> Result<AddResponse, ServiceError> {
+        delay(req.delay_ms).await;
+        Ok(AddResponse { sum: req.a + req.b })
+    }
+
+    // Quotes must arrive quickly or not at all
+    #[operation(timeout_ms = 50)]
+    pub async fn quick_add(req: AddRequest) -> Result<AddResponse, ServiceError> {
+        delay(req.delay_ms).await;
+        Ok(AddResponse { sum: req.a + req.b })
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct SubtractRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i32,
+        #[serde(rename = "Operand2")]
+        pub b: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct SubtractResponse {
+        #[serde(rename = "Result")]
+        pub difference: i32,
+    }
+
+    // Shields a metered backend: three calls, refilled at three per minute
+    #[operation(rate_limit = "3/m")]
+    pub async fn subtract(req: SubtractRequest) -> Result<SubtractResponse, ServiceError> {
+        Ok(SubtractResponse { difference: req.a - req.b })
+    }
+
+    async fn delay(delay_ms: Option<u64>) {
+        if let Some(delay_ms) = delay_ms {
+            tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = axum::Router::new()
+        .merge(calculator_reliable::router())
+        .route("/health", axum::routing::get(|| async { "OK" }));
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:3007").await.unwrap();
+
+    println!("Reliable Calculator SOAP Service running on http://localhost:3007");
+    println!("WSDL available at: http://localhost:3007/soap/calculator/wsdl");
+
+    axum::serve(listener, app).await.unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::calculator_reliable;
+    use tower::ServiceExt;
+
+    async fn call(operation: &str, delay_ms: u64) -> Result<String, String> {
+        calculator_reliable::handle_raw(&format!(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><{0}><Operand1>2</Operand1><Operand2>3</Operand2><DelayMs>{1}</DelayMs></{0}></soap:Body>
+            </soap:Envelope>"#,
+            operation, delay_ms
+        ))
+        .await
+    }
+
+    #[tokio::test]
+    async fn operation_within_the_service_timeout_answers() {
+        let response = call("Add", 100).await.unwrap();
+
+        assert!(response.contains("<Result>5</Result>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn operation_beyond_the_service_timeout_is_a_server_fault() {
+        let fault = call("Add", 1000).await.unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Server</faultcode>"), "{}", fault);
+        assert!(fault.contains("Operation Add timed out after 300 ms"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn operation_timeout_overrides_the_service_timeout() {
+        // Well within the service's 300 ms, but beyond QuickAdd's own 50 ms
+        let fault = call("QuickAdd", 100).await.unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Server</faultcode>"), "{}", fault);
+        assert!(fault.contains("Operation QuickAdd timed out after 50 ms"), "{}", fault);
+
+        let response = call("QuickAdd", 0).await.unwrap();
+        assert!(response.contains("<Result>5</Result>"), "{}", response);
+    }
+
+    async fn add_in_sequence(identifier: &str, message_number: u64) -> String {
+        calculator_reliable::handle_raw(&format!(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"
+                    xmlns:wsrm="http://docs.oasis-open.org/ws-rx/wsrm/200702">
+                <soap:Header>
+                    <wsrm:Sequence soap:mustUnderstand="1">
+                        <wsrm:Identifier>{}</wsrm:Identifier>
+                        <wsrm:MessageNumber>{}</wsrm:MessageNumber>
+                    </wsrm:Sequence>
+                </soap:Header>
+                <soap:Body><Add><Operand1>2</Operand1><Operand2>3</Operand2></Add></soap:Body>
+            </soap:Envelope>"#,
+            identifier, message_number
+        ))
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn sequence_header_is_acknowledged() {
+        let response = add_in_sequence("urn:uuid:acknowledged", 1).await;
+
+        assert!(
+            response.contains(
+                r#"<wsrm:SequenceAcknowledgement xmlns:wsrm="http://docs.oasis-open.org/ws-rx/wsrm/200702"><wsrm:Identifier>urn:uuid:acknowledged</wsrm:Identifier><wsrm:AcknowledgementRange Upper="1" Lower="1"/></wsrm:SequenceAcknowledgement>"#
+            ),
+            "{}",
+            response
+        );
+        assert!(response.contains("<Result>5</Result>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn acknowledgement_lists_the_ranges_received() {
+        for message_number in [1, 2, 4] {
+            add_in_sequence("urn:uuid:ranges", message_number).await;
+        }
+        let response = add_in_sequence("urn:uuid:ranges", 5).await;
+
+        assert!(
+            response.contains(
+                r#"<wsrm:AcknowledgementRange Upper="2" Lower="1"/><wsrm:AcknowledgementRange Upper="5" Lower="4"/>"#
+            ),
+            "{}",
+            response
+        );
+    }
+
+    #[tokio::test]
+    async fn call_beyond_the_rate_limit_is_rejected() {
+        let router = calculator_reliable::router();
+        let mut statuses = Vec::new();
+        let mut body = String::new();
+        // Only this test calls Subtract, so the bucket starts full
+        for _ in 0..4 {
+            let request = axum::http::Request::post("/soap/calculator")
+                .header("Content-Type", "text/xml")
+                .body(axum::body::Body::from(
+                    r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                        <soap:Body><Subtract><Operand1>5</Operand1><Operand2>3</Operand2></Subtract></soap:Body>
+                    </soap:Envelope>"#,
+                ))
+                .unwrap();
+            let response = router.clone().oneshot(request).await.unwrap();
+            statuses.push(response.status().as_u16());
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            body = String::from_utf8(bytes.to_vec()).unwrap();
+        }
+
+        assert_eq!(statuses, [200, 200, 200, 429]);
+        assert!(body.contains("<faultcode>soap:Server</faultcode>"), "{}", body);
+        assert!(body.contains("Operation Subtract is limited to 3 requests per minute"), "{}", body);
+    }
+}
@@ -0,0 +1,221 @@
+use soap_service::service;
+
+#[derive(Debug)]
+pub struct ServiceError(pub String);
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+// The request and response types are described by a schema shared between services,
+// which the WSDL imports instead of declaring them itself
+#[service(
+    namespace = "http://example.com/calculator",
+    service_name = "CalculatorService",
+    port_name = "CalculatorPort",
+    bind_path = "/soap/calculator/shared",
+    types_namespace = "http://example.com/types",
+    schema_import = "https://schemas.example.com/types.xsd?version=2&format=xsd"
+)]
+mod calculator_shared {
+    use super::ServiceError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i32,
+        #[serde(rename = "Operand2")]
+        pub b: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddResponse {
+        #[serde(rename = "Result")]
+        pub sum: i32,
+    }
+
+    pub async fn add(req: AddRequest) -> Result<AddResponse, ServiceError> {
+        Ok(AddResponse { sum: req.a + req.b })
+    }
+}
+
+// The schema is served on its own at /soap/calculator/split/schema.xsd and imported by
+// the WSDL, as some .NET tooling expects
+#[service(
+    namespace = "http://example.com/calculator",
+    service_name = "CalculatorService",
+    port_name = "CalculatorPort",
+    bind_path = "/soap/calculator/split",
+    split_schema = true
+)]
+mod calculator_split {
+    use super::ServiceError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i32,
+        #[serde(rename = "Operand2")]
+        pub b: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddResponse {
+        #[serde(rename = "Result")]
+        pub sum: i32,
+    }
+
+    pub async fn add(req: AddRequest) -> Result<AddResponse, ServiceError> {
+        Ok(AddResponse { sum: req.a + req.b })
+    }
+}
+
+// Children of the operation elements are in no namespace, for clients that match them by
+// local name only
+#[service(
+    namespace = "http://example.com/calculator",
+    service_name = "CalculatorService",
+    port_name = "CalculatorPort",
+    bind_path = "/soap/calculator/unqualified",
+    element_form = "unqualified"
+)]
+mod calculator_unqualified {
+    use super::ServiceError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i32,
+        #[serde(rename = "Operand2")]
+        pub b: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddResponse {
+        #[serde(rename = "Result")]
+        pub sum: i32,
+    }
+
+    pub async fn add(req: AddRequest) -> Result<AddResponse, ServiceError> {
+        Ok(AddResponse { sum: req.a + req.b })
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = axum::Router::new()
+        .merge(calculator_shared::router())
+        .merge(calculator_split::router())
+        .merge(calculator_unqualified::router());
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:3008").await.unwrap();
+
+    println!("Calculator SOAP Services running on http://localhost:3008");
+    println!("WSDL with an imported schema at: http://localhost:3008/soap/calculator/shared/wsdl");
+    println!("WSDL with a separate schema at: http://localhost:3008/soap/calculator/split/wsdl");
+    println!("WSDL with unqualified elements at: http://localhost:3008/soap/calculator/unqualified/wsdl");
+
+    axum::serve(listener, app).await.unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculator_shared, calculator_split, calculator_unqualified};
+    use tower::ServiceExt;
+
+    async fn get(router: axum::Router, uri: &str) -> String {
+        let request = axum::http::Request::get(uri).body(axum::body::Body::empty()).unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), 200);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn wsdl_imports_the_shared_types_schema() {
+        let wsdl = get(calculator_shared::router(), "/soap/calculator/shared/wsdl").await;
+
+        assert!(wsdl.contains(r#"xmlns:types="http://example.com/types""#), "{}", wsdl);
+        // The ampersand of the location's query is escaped
+        assert!(
+            wsdl.contains(
+                r#"<xsd:import namespace="http://example.com/types" schemaLocation="https://schemas.example.com/types.xsd?version=2&amp;format=xsd"/>"#
+            ),
+            "{}",
+            wsdl
+        );
+        assert!(!wsdl.contains("version=2&format"), "{}", wsdl);
+        // The messages reference the imported elements, which the WSDL does not declare
+        assert!(wsdl.contains(r#"<part name="parameters" element="types:AddRequest"/>"#), "{}", wsdl);
+        assert!(wsdl.contains(r#"<part name="parameters" element="types:AddResponse"/>"#), "{}", wsdl);
+        assert!(!wsdl.contains(r#"<xsd:complexType name="AddRequestType">"#), "{}", wsdl);
+    }
+
+    #[tokio::test]
+    async fn split_schema_is_served_and_imported() {
+        let wsdl = get(calculator_split::router(), "/soap/calculator/split/wsdl").await;
+
+        assert!(
+            wsdl.contains(
+                r#"<xsd:import namespace="http://example.com/calculator" schemaLocation="/soap/calculator/split/schema.xsd"/>"#
+            ),
+            "{}",
+            wsdl
+        );
+        // The types live in the served schema only
+        assert!(!wsdl.contains(r#"<xsd:complexType name="AddRequestType">"#), "{}", wsdl);
+
+        let schema = get(calculator_split::router(), "/soap/calculator/split/schema.xsd").await;
+        assert!(schema.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#), "{}", schema);
+        assert!(schema.contains(r#"targetNamespace="http://example.com/calculator""#), "{}", schema);
+        assert!(schema.contains(r#"<xsd:element name="Add" type="tns:AddRequestType"/>"#), "{}", schema);
+        assert!(schema.contains(r#"<xsd:complexType name="AddRequestType">"#), "{}", schema);
+        assert!(schema.contains(r#"<xsd:element name="Result" type="xsd:int"/>"#), "{}", schema);
+
+        // The schema is also served at ?xsd
+        assert_eq!(get(calculator_split::router(), "/soap/calculator/split?xsd").await, schema);
+    }
+
+    const ADD: &str = r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+        <soap:Body><c:Add xmlns:c="http://example.com/calculator"><Operand1>2</Operand1><Operand2>3</Operand2></c:Add></soap:Body>
+    </soap:Envelope>"#;
+
+    #[tokio::test]
+    async fn unqualified_element_form_is_declared_and_used() {
+        let wsdl = get(calculator_unqualified::router(), "/soap/calculator/unqualified/wsdl").await;
+        assert!(wsdl.contains(r#"elementFormDefault="unqualified""#), "{}", wsdl);
+        assert!(!wsdl.contains(r#"elementFormDefault="qualified""#), "{}", wsdl);
+
+        // The response element keeps its prefix, but no default namespace reaches the children
+        let response = calculator_unqualified::handle_raw(ADD).await.unwrap();
+        assert!(
+            response.contains(r#"<tns:AddResponse xmlns:tns="http://example.com/calculator"><Result>5</Result>"#),
+            "{}",
+            response
+        );
+        assert!(!response.contains(r#"xmlns="http://example.com/calculator""#), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn qualified_element_form_is_the_default() {
+        let wsdl = get(calculator_split::router(), "/soap/calculator/split/wsdl").await;
+        assert!(wsdl.contains(r#"elementFormDefault="qualified""#), "{}", wsdl);
+
+        let response = calculator_split::handle_raw(ADD).await.unwrap();
+        assert!(
+            response.contains(
+                r#"<tns:AddResponse xmlns:tns="http://example.com/calculator" xmlns="http://example.com/calculator"><Result>5</Result>"#
+            ),
+            "{}",
+            response
+        );
+    }
+}
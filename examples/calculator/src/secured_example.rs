@@ -0,0 +1,238 @@
+use soap_service::service;
+
+#[derive(Debug)]
+pub struct ServiceError(pub String);
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+// Operations need Basic credentials, the WSDL stays public
+#[service(
+    namespace = "http://example.com/calculator",
+    service_name = "CalculatorService",
+    port_name = "CalculatorPort",
+    bind_path = "/soap/calculator",
+    auth = "basic"
+)]
+mod calculator_secured {
+    use super::ServiceError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i32,
+        #[serde(rename = "Operand2")]
+        pub b: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddResponse {
+        #[serde(rename = "Result")]
+        pub sum: i32,
+    }
+
+    pub async fn add(req: AddRequest) -> Result<AddResponse, ServiceError> {
+        Ok(AddResponse { sum: req.a + req.b })
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct SubtractRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i32,
+        #[serde(rename = "Operand2")]
+        pub b: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct SubtractResponse {
+        #[serde(rename = "Result")]
+        pub difference: i32,
+    }
+
+    // Any authenticated user may add, only administrators subtract
+    #[operation(requires_role = "admin")]
+    pub async fn subtract(req: SubtractRequest) -> Result<SubtractResponse, ServiceError> {
+        Ok(SubtractResponse { difference: req.a - req.b })
+    }
+}
+
+fn verify_credentials(username: &str, password: &str) -> bool {
+    matches!((username, password), ("admin", "secret") | ("ann", "pwd") | ("ann", "pwd1"))
+}
+
+fn grant_role(principal: &str, role: &str) -> bool {
+    matches!((principal, role), ("admin", "admin"))
+}
+
+// Stamps every response with the server time, so clients can detect replayed answers
+fn server_time_header(_operation: &str) -> String {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default();
+    format!(r#"<h:ServerTime xmlns:h="http://example.com/calculator/headers">{}</h:ServerTime>"#, seconds)
+}
+
+#[tokio::main]
+async fn main() {
+    calculator_secured::set_basic_authenticator(verify_credentials);
+    calculator_secured::set_role_authorizer(grant_role);
+    calculator_secured::set_response_header(server_time_header);
+
+    let app = calculator_secured::router();
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:3005").await.unwrap();
+
+    println!("Secured Calculator SOAP Service running on http://localhost:3005");
+    println!("WSDL available at: http://localhost:3005/soap/calculator/wsdl");
+
+    axum::serve(listener, app).await.unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculator_secured, grant_role, server_time_header, verify_credentials};
+    use tower::ServiceExt;
+
+    async fn add_with(authorization: Option<&str>) -> (axum::http::StatusCode, axum::http::HeaderMap, String) {
+        call_with("Add", authorization).await
+    }
+
+    async fn call_with(
+        operation: &str,
+        authorization: Option<&str>,
+    ) -> (axum::http::StatusCode, axum::http::HeaderMap, String) {
+        calculator_secured::set_basic_authenticator(verify_credentials);
+        calculator_secured::set_role_authorizer(grant_role);
+
+        let mut request = axum::http::Request::post("/soap/calculator").header("Content-Type", "text/xml");
+        if let Some(authorization) = authorization {
+            request = request.header("Authorization", authorization);
+        }
+        let request = request
+            .body(axum::body::Body::from(format!(
+                r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                    <soap:Body><{0}><Operand1>15</Operand1><Operand2>25</Operand2></{0}></soap:Body>
+                </soap:Envelope>"#,
+                operation
+            )))
+            .unwrap();
+        let response = calculator_secured::router().oneshot(request).await.unwrap();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, headers, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn valid_credentials_are_dispatched() {
+        let (status, _, body) = add_with(Some("Basic YWRtaW46c2VjcmV0")).await;
+
+        assert_eq!(status, 200, "{}", body);
+        assert!(body.contains("<Result>40</Result>"), "{}", body);
+    }
+
+    #[tokio::test]
+    async fn missing_credentials_are_refused() {
+        let (status, headers, body) = add_with(None).await;
+
+        assert_eq!(status, 401);
+        assert_eq!(
+            headers["WWW-Authenticate"],
+            r#"Basic realm="CalculatorService", charset="UTF-8""#
+        );
+        assert!(body.contains("<faultcode>soap:Client</faultcode>"), "{}", body);
+        assert!(body.contains("Missing or malformed Basic credentials"), "{}", body);
+    }
+
+    #[tokio::test]
+    async fn invalid_credentials_are_refused() {
+        // admin:wrong
+        let (status, _, body) = add_with(Some("Basic YWRtaW46d3Jvbmc=")).await;
+
+        assert_eq!(status, 401);
+        assert!(body.contains("Invalid credentials"), "{}", body);
+    }
+
+    #[tokio::test]
+    async fn credentials_with_one_padding_character_are_decoded() {
+        // ann:pwd1
+        let (status, _, body) = add_with(Some("Basic YW5uOnB3ZDE=")).await;
+
+        assert_eq!(status, 200, "{}", body);
+    }
+
+    #[tokio::test]
+    async fn credentials_with_two_padding_characters_are_decoded() {
+        // ann:pwd
+        let (status, _, body) = add_with(Some("Basic YW5uOnB3ZA==")).await;
+
+        assert_eq!(status, 200, "{}", body);
+    }
+
+    #[tokio::test]
+    async fn credentials_with_invalid_base64_characters_are_malformed() {
+        let (status, _, body) = add_with(Some("Basic YW5u*nB3ZA==")).await;
+
+        assert_eq!(status, 401);
+        assert!(body.contains("Missing or malformed Basic credentials"), "{}", body);
+    }
+
+    #[tokio::test]
+    async fn granted_role_is_dispatched() {
+        // admin:secret
+        let (status, _, body) = call_with("Subtract", Some("Basic YWRtaW46c2VjcmV0")).await;
+
+        assert_eq!(status, 200, "{}", body);
+        assert!(body.contains("<Result>-10</Result>"), "{}", body);
+    }
+
+    #[tokio::test]
+    async fn denied_role_is_a_client_fault() {
+        // ann:pwd, authenticated but not an administrator
+        let (status, _, body) = call_with("Subtract", Some("Basic YW5uOnB3ZA==")).await;
+
+        assert_eq!(status, 500);
+        assert!(body.contains("<faultcode>soap:Client</faultcode>"), "{}", body);
+        assert!(body.contains("Operation Subtract requires the admin role"), "{}", body);
+        assert!(!body.contains("<Result>"), "{}", body);
+
+        // Operations without a role stay open to every authenticated user
+        let (status, _, body) = add_with(Some("Basic YW5uOnB3ZA==")).await;
+        assert_eq!(status, 200, "{}", body);
+    }
+
+    #[tokio::test]
+    async fn responses_carry_the_current_server_time() {
+        calculator_secured::set_response_header(server_time_header);
+        let before = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
+
+        let (status, _, body) = add_with(Some("Basic YWRtaW46c2VjcmV0")).await;
+
+        let after = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
+        assert_eq!(status, 200, "{}", body);
+        let start = r#"<soap:Header><h:ServerTime xmlns:h="http://example.com/calculator/headers">"#;
+        let header = &body[body.find(start).unwrap_or_else(|| panic!("{}", body)) + start.len()..];
+        let seconds: u64 = header[..header.find("</h:ServerTime></soap:Header><soap:Body>").unwrap()]
+            .parse()
+            .unwrap();
+        assert!((before..=after).contains(&seconds), "{} not in {}..={}", seconds, before, after);
+    }
+
+    #[tokio::test]
+    async fn wsdl_stays_public() {
+        let request = axum::http::Request::get("/soap/calculator/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = calculator_secured::router().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), 200);
+    }
+}
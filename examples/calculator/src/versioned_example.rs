@@ -0,0 +1,376 @@
+use soap_service::service;
+
+#[derive(Debug)]
+pub struct ServiceError(pub String);
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+// Version 1 keeps serving existing clients unchanged, and only exposes marked functions
+#[service(
+    namespace = "http://example.com/calculator/v1",
+    service_name = "CalculatorService",
+    port_name = "CalculatorPort",
+    bind_path = "/soap/calculator/v1",
+    operations = "explicit",
+    pretty_print = true
+)]
+mod calculator_v1 {
+    use super::ServiceError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i32,
+        #[serde(rename = "Operand2")]
+        pub b: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddResponse {
+        #[serde(rename = "Result")]
+        pub sum: i32,
+    }
+
+    #[operation]
+    pub async fn add(req: AddRequest) -> Result<AddResponse, ServiceError> {
+        checked_add(&req).await
+    }
+
+    // Unmarked, so a helper even though its signature fits an operation
+    pub async fn checked_add(req: &AddRequest) -> Result<AddResponse, ServiceError> {
+        req.a
+            .checked_add(req.b)
+            .map(|sum| AddResponse { sum })
+            .ok_or_else(|| ServiceError("Result out of range".to_string()))
+    }
+}
+
+// Version 2 widens the operands and adds an operation, under its own namespace and path.
+// Its operation elements must be in that namespace, so v1 requests are refused, and it
+// also answers SOAP 1.2 clients.
+#[service(
+    namespace = "http://example.com/calculator/v2",
+    service_name = "CalculatorService",
+    port_name = "CalculatorPort",
+    bind_path = "/soap/calculator/v2",
+    strict_namespace = true,
+    soap_versions = ["1.1", "1.2"],
+    policy_url = "https://example.com/policies/calculator.xml"
+)]
+mod calculator_v2 {
+    use super::ServiceError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i64,
+        #[serde(rename = "Operand2")]
+        pub b: i64,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct AddResponse {
+        #[serde(rename = "Result")]
+        pub sum: i64,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct SubtractRequest {
+        #[serde(rename = "Operand1")]
+        pub a: i64,
+        #[serde(rename = "Operand2")]
+        pub b: i64,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct SubtractResponse {
+        #[serde(rename = "Result")]
+        pub difference: i64,
+    }
+
+    pub async fn add(req: AddRequest) -> Result<AddResponse, ServiceError> {
+        req.a
+            .checked_add(req.b)
+            .map(|sum| AddResponse { sum })
+            .ok_or_else(|| ServiceError("Result out of range".to_string()))
+    }
+
+    pub async fn subtract(req: SubtractRequest) -> Result<SubtractResponse, ServiceError> {
+        req.a
+            .checked_sub(req.b)
+            .map(|difference| SubtractResponse { difference })
+            .ok_or_else(|| ServiceError("Result out of range".to_string()))
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    // Each module generates its own handlers, so the versions merge side by side
+    let app = calculator_v1::register(axum::Router::new());
+    let app = calculator_v2::register(app);
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:3003").await.unwrap();
+
+    println!("Versioned Calculator SOAP Service running on http://localhost:3003");
+    println!("v1 WSDL available at: http://localhost:3003/soap/calculator/v1/wsdl");
+    println!("v2 WSDL available at: http://localhost:3003/soap/calculator/v2/wsdl");
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(calculator_v1::shutdown_signal())
+        .await
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculator_v1, calculator_v2};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn explicit_mode_exposes_only_marked_functions() {
+        let names: Vec<&str> = calculator_v1::operations().iter().map(|operation| operation.name).collect();
+        assert_eq!(names, ["Add"]);
+
+        let fault = calculator_v1::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><CheckedAdd><Operand1>2</Operand1><Operand2>3</Operand2></CheckedAdd></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("Unknown operation: CheckedAdd. Available operations: Add"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn pretty_and_compact_responses() {
+        let request = r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Add xmlns="NAMESPACE"><Operand1>2</Operand1><Operand2>3</Operand2></Add></soap:Body>
+            </soap:Envelope>"#;
+
+        let pretty = calculator_v1::handle_raw(&request.replace("NAMESPACE", "http://example.com/calculator/v1"))
+            .await
+            .unwrap();
+        assert_eq!(
+            pretty,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"
+               xmlns:tns="http://example.com/calculator/v1">
+    <soap:Body>
+        <tns:AddResponse xmlns:tns="http://example.com/calculator/v1" xmlns="http://example.com/calculator/v1">
+            <Result>5</Result>
+        </tns:AddResponse>
+    </soap:Body>
+</soap:Envelope>"#
+        );
+
+        let compact = calculator_v2::handle_raw(&request.replace("NAMESPACE", "http://example.com/calculator/v2"))
+            .await
+            .unwrap();
+        assert_eq!(
+            compact,
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+                r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/" xmlns:tns="http://example.com/calculator/v2">"#,
+                r#"<soap:Body><tns:AddResponse xmlns:tns="http://example.com/calculator/v2" xmlns="http://example.com/calculator/v2">"#,
+                r#"<Result>5</Result></tns:AddResponse></soap:Body></soap:Envelope>"#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn implicit_mode_exposes_every_public_async_function() {
+        let names: Vec<&str> = calculator_v2::operations().iter().map(|operation| operation.name).collect();
+        assert_eq!(names, ["Add", "Subtract"]);
+    }
+
+    #[tokio::test]
+    async fn wsdl_lists_operations_in_declaration_order() {
+        let request = axum::http::Request::get("/soap/calculator/v2/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = calculator_v2::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        let positions = |needles: &[&str]| -> Vec<usize> {
+            needles.iter().map(|needle| wsdl.find(needle).unwrap()).collect()
+        };
+        let is_ordered = |positions: Vec<usize>| positions.windows(2).all(|pair| pair[0] < pair[1]);
+
+        assert!(is_ordered(positions(&[
+            r#"<xsd:element name="AddRequest""#,
+            r#"<xsd:element name="AddResponse""#,
+            r#"<xsd:element name="SubtractRequest""#,
+            r#"<xsd:element name="SubtractResponse""#,
+        ])));
+        let port_type = &wsdl[wsdl.find("<portType").unwrap()..];
+        let add = port_type.find(r#"<operation name="Add">"#).unwrap();
+        let subtract = port_type.find(r#"<operation name="Subtract">"#).unwrap();
+        assert!(add < subtract);
+    }
+
+    #[tokio::test]
+    async fn unknown_operation_lists_available_operations() {
+        let request = axum::http::Request::post("/soap/calculator/v2")
+            .header("Content-Type", "text/xml")
+            .body(axum::body::Body::from(
+                r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                    <soap:Body><Divide xmlns="http://example.com/calculator/v2"><Operand1>6</Operand1><Operand2>3</Operand2></Divide></soap:Body>
+                </soap:Envelope>"#,
+            ))
+            .unwrap();
+        let response = calculator_v2::router().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let fault = String::from_utf8(body.to_vec()).unwrap();
+        assert!(fault.contains("<faultcode>soap:Client</faultcode>"), "{}", fault);
+        assert!(fault.contains("Unknown operation: Divide. Available operations: Add, Subtract"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn wsdl_references_the_policy_from_the_binding() {
+        let request = axum::http::Request::get("/soap/calculator/v2/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = calculator_v2::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(wsdl.contains(r#"xmlns:wsp="http://www.w3.org/ns/ws-policy""#), "{}", wsdl);
+        let binding = &wsdl[wsdl.find("<binding").unwrap()..wsdl.find("</binding>").unwrap()];
+        assert!(
+            binding.contains(r#"<wsp:PolicyReference URI="https://example.com/policies/calculator.xml"/>"#),
+            "{}",
+            binding
+        );
+    }
+
+    #[tokio::test]
+    async fn wsdl_declares_a_port_per_soap_version() {
+        let request = axum::http::Request::get("/soap/calculator/v2/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = calculator_v2::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(wsdl.contains(r#"<port name="CalculatorPort" binding="tns:CalculatorServiceBinding">"#), "{}", wsdl);
+        assert!(
+            wsdl.contains(r#"<port name="CalculatorPortSoap12" binding="tns:CalculatorServiceSoap12Binding">"#),
+            "{}",
+            wsdl
+        );
+    }
+
+    #[tokio::test]
+    async fn each_soap_version_is_answered_in_its_own_envelope() {
+        let request = r#"<soap:Envelope xmlns:soap="ENVELOPE">
+                <soap:Body><Add xmlns="http://example.com/calculator/v2"><Operand1>2</Operand1><Operand2>3</Operand2></Add></soap:Body>
+            </soap:Envelope>"#;
+
+        for envelope in ["http://schemas.xmlsoap.org/soap/envelope/", "http://www.w3.org/2003/05/soap-envelope"] {
+            let response = calculator_v2::handle_raw(&request.replace("ENVELOPE", envelope)).await.unwrap();
+
+            assert!(response.contains(&format!(r#"<soap:Envelope xmlns:soap="{}""#, envelope)), "{}", response);
+            assert!(response.contains("<Result>5</Result>"), "{}", response);
+        }
+    }
+
+    #[tokio::test]
+    async fn prefixed_operation_element_is_in_the_declared_namespace() {
+        let response = calculator_v2::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><tns:Add xmlns:tns="http://example.com/calculator/v2"><Operand1>2</Operand1><Operand2>3</Operand2></tns:Add></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Result>5</Result>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn prefix_declaration_with_spaces_is_resolved() {
+        let response = calculator_v2::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><tns:Add xmlns:tns = 'http://example.com/calculator/v2'><Operand1>2</Operand1><Operand2>3</Operand2></tns:Add></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Result>5</Result>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn prefix_wins_over_the_default_namespace() {
+        let fault = calculator_v2::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><tns:Add xmlns="http://example.com/calculator/v2" xmlns:tns="http://example.com/calculator/v1">
+                    <Operand1>2</Operand1><Operand2>3</Operand2>
+                </tns:Add></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Client</faultcode>"), "{}", fault);
+        assert!(
+            fault.contains("Operation element namespace http://example.com/calculator/v1 does not match"),
+            "{}",
+            fault
+        );
+    }
+
+    async fn request(app: &axum::Router, request: axum::http::Request<axum::body::Body>) -> String {
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), 200);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn merged_router_serves_both_versions() {
+        let app = calculator_v1::register(axum::Router::new());
+        let app = calculator_v2::register(app);
+
+        for version in ["v1", "v2"] {
+            let namespace = format!("http://example.com/calculator/{}", version);
+            let wsdl_request = axum::http::Request::get(format!("/soap/calculator/{}/wsdl", version))
+                .body(axum::body::Body::empty())
+                .unwrap();
+            let wsdl = request(&app, wsdl_request).await;
+            assert!(wsdl.contains(&format!(r#"targetNamespace="{}""#, namespace)), "{}", wsdl);
+            assert!(wsdl.contains(&format!(r#"/soap/calculator/{}"/>"#, version)), "{}", wsdl);
+
+            let add_request = axum::http::Request::post(format!("/soap/calculator/{}", version))
+                .header("Content-Type", "text/xml")
+                .body(axum::body::Body::from(format!(
+                    r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                        <soap:Body><Add xmlns="{}"><Operand1>2</Operand1><Operand2>3</Operand2></Add></soap:Body>
+                    </soap:Envelope>"#,
+                    namespace
+                )))
+                .unwrap();
+            let response = request(&app, add_request).await;
+            assert!(response.contains(&format!(r#"<tns:AddResponse xmlns:tns="{}""#, namespace)), "{}", response);
+        }
+
+        // Only v2 has Subtract
+        let wsdl_request = axum::http::Request::get("/soap/calculator/v1/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        assert!(!request(&app, wsdl_request).await.contains("Subtract"));
+    }
+}
@@ -15,11 +15,18 @@ impl std::error::Error for ServiceError {}
     namespace = "http://example.com/strings",
     service_name = "StringService",
     port_name = "StringPort",
-    bind_path = "/soap/strings"
+    bind_path = "/soap/strings",
+    validate_restrictions = true,
+    // The operations are also served as JSON under /soap/strings/json
+    json_api,
+    json_schema_path = "/soap/strings/operations.json",
+    // Clients may mark the headers Greet reads as mustUnderstand
+    understood_headers = "ApiKey, TenantId"
 )]
 mod string_service {
     use super::ServiceError;
     use serde::{Deserialize, Serialize};
+    use std::borrow::Cow;
 
     // String concatenation service demonstrating various field types
     #[derive(Deserialize, Serialize, Debug)]
@@ -28,7 +35,9 @@ mod string_service {
         pub first: String,
         #[serde(rename = "SecondText")]
         pub second: String,
+        // A space when left out; an empty element still joins without one
         #[serde(rename = "Separator")]
+        #[soap(default = " ")]
         pub separator: Option<String>,
         #[serde(rename = "UpperCase")]
         pub uppercase: bool,
@@ -46,17 +55,17 @@ mod string_service {
 
     pub async fn concatenate(req: ConcatenateRequest) -> Result<ConcatenateResponse, ServiceError> {
         if req.first.is_empty() && req.second.is_empty() {
-            return Err(ServiceError("Both texts cannot be empty".to_string()));
+            return Err(create_soap_fault("Both texts cannot be empty"));
         }
 
-        let separator = req.separator.unwrap_or(" ".to_string());
+        let separator = req.separator.unwrap_or_default();
         let mut result = format!("{}{}{}", req.first, separator, req.second);
         
         if req.uppercase {
             result = result.to_uppercase();
         }
 
-        let word_count = result.split_whitespace().count() as i32;
+        let word_count = count_words(&result).await?;
 
         Ok(ConcatenateResponse {
             length: result.len() as i32,
@@ -64,6 +73,532 @@ mod string_service {
             word_count,
         })
     }
+
+    // Shares its name with a generated helper, which is kept out of the module
+    fn create_soap_fault(message: &str) -> ServiceError {
+        ServiceError(message.to_string())
+    }
+
+    // A helper, not exposed as an operation despite its signature
+    #[operation(skip)]
+    pub async fn count_words(text: &str) -> Result<i32, ServiceError> {
+        Ok(text.split_whitespace().count() as i32)
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct FirstWordRequest {
+        #[serde(rename = "Text")]
+        pub text: String,
+    }
+
+    // Borrows from the request, so the word is serialized without being copied
+    #[derive(Serialize, Debug)]
+    pub struct FirstWordResponse<'a> {
+        #[serde(rename = "Word")]
+        pub word: Cow<'a, str>,
+    }
+
+    pub async fn first_word<'a>(req: &'a FirstWordRequest) -> Result<FirstWordResponse<'a>, ServiceError> {
+        let word = req.text.split_whitespace().next().unwrap_or_default();
+
+        Ok(FirstWordResponse {
+            word: Cow::Borrowed(word),
+        })
+    }
+
+    // A recursive tree
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct Section {
+        #[serde(rename = "Title")]
+        pub title: String,
+        // Boxed on purpose, to show that the schema sees through pointer wrappers
+        #[allow(clippy::vec_box)]
+        #[serde(rename = "Section", default)]
+        pub sections: Vec<Box<Section>>,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct OutlineRequest {
+        #[serde(rename = "Section")]
+        pub root: Section,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct OutlineResponse {
+        #[serde(rename = "Title")]
+        pub titles: Vec<String>,
+    }
+
+    pub async fn outline(req: OutlineRequest) -> Result<OutlineResponse, ServiceError> {
+        fn collect_titles(section: &Section, titles: &mut Vec<String>) {
+            titles.push(section.title.clone());
+            for nested in &section.sections {
+                collect_titles(nested, titles);
+            }
+        }
+
+        let mut titles = Vec::new();
+        collect_titles(&req.root, &mut titles);
+
+        Ok(OutlineResponse { titles })
+    }
+
+    // Plain strings, declared with the XSD types the contract asks for
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ResolveLinkRequest {
+        #[serde(rename = "Base")]
+        #[soap(xsd = "anyURI")]
+        pub base: String,
+        #[serde(rename = "Path")]
+        #[soap(xsd = "token")]
+        pub path: String,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ResolveLinkResponse {
+        #[serde(rename = "Link")]
+        #[soap(xsd = "anyURI")]
+        pub link: String,
+        // Only written when the path has a fragment
+        #[serde(rename = "Fragment")]
+        pub fragment: Option<String>,
+    }
+
+    pub async fn resolve_link(req: ResolveLinkRequest) -> Result<ResolveLinkResponse, ServiceError> {
+        let link = format!("{}/{}", req.base.trim_end_matches('/'), req.path.trim_start_matches('/'));
+        let fragment = link.split_once('#').map(|(_, fragment)| fragment.to_string());
+
+        Ok(ResolveLinkResponse { link, fragment })
+    }
+
+    // Built from the ApiKey and TenantId headers of each request that takes it
+    #[derive(Debug)]
+    pub struct RequestContext {
+        pub api_key: String,
+        pub tenant_id: String,
+    }
+
+    impl FromSoapHeaders for RequestContext {
+        fn from_soap_headers(headers: &SoapHeaders) -> Result<Self, String> {
+            Ok(RequestContext {
+                api_key: headers.require("ApiKey")?.to_string(),
+                tenant_id: headers.require("TenantId")?.to_string(),
+            })
+        }
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct GreetRequest {
+        #[serde(rename = "Name")]
+        pub name: String,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct GreetResponse {
+        #[serde(rename = "Greeting")]
+        pub greeting: String,
+    }
+
+    pub async fn greet(req: GreetRequest, context: RequestContext) -> Result<GreetResponse, ServiceError> {
+        if context.api_key != "secret" {
+            return Err(ServiceError("Unknown API key".to_string()));
+        }
+
+        Ok(GreetResponse {
+            greeting: format!("Hello {} from {}", req.name, context.tenant_id),
+        })
+    }
+
+    // The repeat count travels as an attribute, declared after the element field
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct RepeatRequest {
+        #[serde(rename = "Text")]
+        pub text: String,
+        #[serde(rename = "count")]
+        #[soap(attribute)]
+        pub count: u32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct RepeatResponse {
+        #[serde(rename = "Result")]
+        pub result: String,
+        #[serde(rename = "length")]
+        #[soap(attribute)]
+        pub length: u32,
+    }
+
+    pub async fn repeat(req: RepeatRequest) -> Result<RepeatResponse, ServiceError> {
+        let result = req.text.repeat(req.count as usize);
+
+        Ok(RepeatResponse {
+            length: result.chars().count() as u32,
+            result,
+        })
+    }
+
+    // FNV-1a over the text, starting from a caller-chosen 64-bit seed
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct HashRequest {
+        #[serde(rename = "Text")]
+        pub text: String,
+        #[serde(rename = "Seed")]
+        pub seed: u64,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct HashResponse {
+        #[serde(rename = "Hash")]
+        pub hash: u64,
+    }
+
+    pub async fn hash(req: HashRequest) -> Result<HashResponse, ServiceError> {
+        let hash = req
+            .text
+            .bytes()
+            .fold(req.seed, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3));
+
+        Ok(HashResponse { hash })
+    }
+
+    // Streamed word by word with chunked transfer encoding
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct SplitWordsRequest {
+        #[serde(rename = "Text")]
+        pub text: String,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct SplitWordsResponse {
+        #[serde(rename = "Word")]
+        pub words: Vec<String>,
+    }
+
+    impl SoapStreamingResponse for SplitWordsResponse {
+        type Item = String;
+
+        const ITEM_ELEMENT: &'static str = "Word";
+
+        fn into_stream(
+            self,
+        ) -> std::pin::Pin<Box<dyn futures_core::Stream<Item = Result<String, String>> + Send>> {
+            Box::pin(futures_util::stream::iter(self.words.into_iter().map(Ok)))
+        }
+    }
+
+    pub async fn split_words(req: SplitWordsRequest) -> Result<SplitWordsResponse, ServiceError> {
+        Ok(SplitWordsResponse {
+            words: req.text.split_whitespace().map(str::to_string).collect(),
+        })
+    }
+
+    // Nil parts are skipped, and the optional list of tags is appended in brackets
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct JoinRequest {
+        #[serde(rename = "Part")]
+        pub parts: Vec<Option<String>>,
+        #[serde(rename = "Tag")]
+        pub tags: Option<Vec<String>>,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct JoinResponse {
+        #[serde(rename = "Result")]
+        pub result: String,
+    }
+
+    pub async fn join(req: JoinRequest) -> Result<JoinResponse, ServiceError> {
+        let mut result = req.parts.into_iter().flatten().collect::<Vec<_>>().join(" ");
+        if let Some(tags) = req.tags {
+            result.push_str(&format!(" [{}]", tags.join(", ")));
+        }
+
+        Ok(JoinResponse { result })
+    }
+
+    // Replaces each key with its value, counting the replacements per key
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct SubstituteRequest {
+        #[serde(rename = "Text")]
+        pub text: String,
+        #[serde(rename = "Replacements")]
+        pub replacements: std::collections::BTreeMap<String, String>,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct SubstituteResponse {
+        #[serde(rename = "Result")]
+        pub result: String,
+        #[serde(rename = "Counts")]
+        pub counts: std::collections::BTreeMap<String, u32>,
+    }
+
+    pub async fn substitute(req: SubstituteRequest) -> Result<SubstituteResponse, ServiceError> {
+        let mut result = req.text;
+        let mut counts = std::collections::BTreeMap::new();
+        for (key, value) in req.replacements {
+            counts.insert(key.clone(), result.matches(key.as_str()).count() as u32);
+            result = result.replace(&key, &value);
+        }
+
+        Ok(SubstituteResponse { result, counts })
+    }
+
+    // The same type is both the request and the response
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct EchoMessage {
+        #[serde(rename = "Text")]
+        pub text: String,
+        #[serde(rename = "Note")]
+        pub note: Option<String>,
+    }
+
+    pub async fn echo(message: EchoMessage) -> Result<EchoMessage, ServiceError> {
+        Ok(message)
+    }
+
+    // Failures of the dictionary backend are answered with their own HTTP status
+    #[derive(Debug)]
+    pub enum TranslateError {
+        UnsupportedLanguage(String),
+        ServiceUnavailable,
+    }
+
+    impl std::fmt::Display for TranslateError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                TranslateError::UnsupportedLanguage(language) => write!(f, "Unsupported language: {}", language),
+                TranslateError::ServiceUnavailable => write!(f, "The dictionary is unavailable"),
+            }
+        }
+    }
+
+    impl std::error::Error for TranslateError {}
+
+    impl SoapFault for TranslateError {
+        fn http_status(&self) -> Option<axum::http::StatusCode> {
+            match self {
+                TranslateError::ServiceUnavailable => Some(axum::http::StatusCode::SERVICE_UNAVAILABLE),
+                TranslateError::UnsupportedLanguage(_) => None,
+            }
+        }
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct TranslateRequest {
+        #[serde(rename = "Word")]
+        pub word: String,
+        #[serde(rename = "Language")]
+        pub language: String,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct TranslateResponse {
+        #[serde(rename = "Translation")]
+        pub translation: String,
+    }
+
+    pub async fn translate(req: TranslateRequest) -> Result<TranslateResponse, TranslateError> {
+        match req.language.as_str() {
+            "nl" if req.word == "hello" => Ok(TranslateResponse {
+                translation: "hallo".to_string(),
+            }),
+            "nl" => Ok(TranslateResponse { translation: req.word }),
+            // The French dictionary is hosted elsewhere and currently offline
+            "fr" => Err(TranslateError::ServiceUnavailable),
+            language => Err(TranslateError::UnsupportedLanguage(language.to_string())),
+        }
+    }
+
+    // Paging fields shared by list requests, inlined into each of them
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct Pagination {
+        #[serde(rename = "Page")]
+        pub page: u32,
+        #[serde(rename = "Size")]
+        pub size: u32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ListWordsRequest {
+        #[serde(rename = "Text")]
+        pub text: String,
+        #[serde(flatten)]
+        pub pagination: Pagination,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ListWordsResponse {
+        #[serde(rename = "Word", default)]
+        pub words: Vec<String>,
+    }
+
+    pub async fn list_words(req: ListWordsRequest) -> Result<ListWordsResponse, ServiceError> {
+        let Pagination { page, size } = req.pagination;
+        let words = req
+            .text
+            .split_whitespace()
+            .skip((page.saturating_sub(1) * size) as usize)
+            .take(size as usize)
+            .map(str::to_string)
+            .collect();
+
+        Ok(ListWordsResponse { words })
+    }
+
+    // A request without fields, answered without parsing the body
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct PingRequest {}
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct PingResponse {
+        #[serde(rename = "Status")]
+        pub status: String,
+    }
+
+    pub async fn ping(_req: PingRequest) -> Result<PingResponse, ServiceError> {
+        Ok(PingResponse {
+            status: "OK".to_string(),
+        })
+    }
+
+    // A batch operation: each text reports its own outcome, and only a batch in which
+    // every text failed is a fault
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ParseNumbersRequest {
+        #[serde(rename = "Text", default)]
+        pub texts: Vec<String>,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ParsedNumber {
+        #[serde(rename = "Text")]
+        pub text: String,
+        #[serde(rename = "Value", skip_serializing_if = "Option::is_none")]
+        pub value: Option<i64>,
+        #[serde(rename = "Error", skip_serializing_if = "Option::is_none")]
+        pub error: Option<String>,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ParseNumbersResponse {
+        #[serde(rename = "Number", default)]
+        pub numbers: Vec<ParsedNumber>,
+    }
+
+    impl PartialSuccess for ParseNumbersResponse {
+        fn item_count(&self) -> usize {
+            self.numbers.len()
+        }
+
+        fn failed_count(&self) -> usize {
+            self.numbers.iter().filter(|number| number.error.is_some()).count()
+        }
+    }
+
+    #[operation(fault_when_all_fail)]
+    pub async fn parse_numbers(req: ParseNumbersRequest) -> Result<ParseNumbersResponse, ServiceError> {
+        let numbers = req
+            .texts
+            .into_iter()
+            .map(|text| match text.trim().parse::<i64>() {
+                Ok(value) => ParsedNumber { text, value: Some(value), error: None },
+                Err(e) => ParsedNumber { error: Some(e.to_string()), text, value: None },
+            })
+            .collect();
+
+        Ok(ParseNumbersResponse { numbers })
+    }
+
+    // Regulated formats are declared as restriction facets in the schema
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct FormatPriceRequest {
+        #[serde(rename = "Currency")]
+        #[soap(pattern = "[A-Z]{3}")]
+        pub currency: String,
+        #[serde(rename = "Label")]
+        #[soap(min_length = 1, max_length = 20)]
+        pub label: String,
+        #[serde(rename = "Amount")]
+        #[soap(min = 0, max = 1000000)]
+        pub amount: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct FormatPriceResponse {
+        #[serde(rename = "Price")]
+        pub price: String,
+    }
+
+    pub async fn format_price(req: FormatPriceRequest) -> Result<FormatPriceResponse, ServiceError> {
+        Ok(FormatPriceResponse {
+            price: format!("{}: {} {}", req.label, req.amount, req.currency),
+        })
+    }
+
+    // Every way of wrapping a list in Option, for a simple and a complex item type
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct TallyRequest {
+        #[serde(rename = "Word")]
+        pub words: Vec<String>,
+        #[serde(rename = "OptionalWord")]
+        pub optional_words: Option<Vec<String>>,
+        #[serde(rename = "NillableWord")]
+        pub nillable_words: Vec<Option<String>>,
+        #[serde(rename = "OptionalNillableWord")]
+        pub optional_nillable_words: Option<Vec<Option<String>>>,
+        #[serde(rename = "OptionalLabel")]
+        pub optional_labels: Option<Vec<Label>>,
+        #[serde(rename = "NillableLabel")]
+        pub nillable_labels: Vec<Option<Label>>,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct Label {
+        #[serde(rename = "Text")]
+        pub text: String,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct TallyResponse {
+        #[serde(rename = "Items")]
+        pub items: usize,
+    }
+
+    pub async fn tally(req: TallyRequest) -> Result<TallyResponse, ServiceError> {
+        let optional_words = req.optional_words.unwrap_or_default();
+        let optional_nillable_words = req.optional_nillable_words.unwrap_or_default();
+        let optional_labels = req.optional_labels.unwrap_or_default();
+
+        Ok(TallyResponse {
+            items: req.words.len()
+                + optional_words.len()
+                + req.nillable_words.len()
+                + optional_nillable_words.len()
+                + optional_labels.len()
+                + req.nillable_labels.len(),
+        })
+    }
+
+    // Bound to a namespace of its own, declared in a separate schema of the WSDL
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ReverseRequest {
+        #[serde(rename = "Text")]
+        pub text: String,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ReverseResponse {
+        #[serde(rename = "Reversed")]
+        pub reversed: String,
+    }
+
+    #[operation(namespace = "http://example.com/strings/v2")]
+    pub async fn reverse(req: ReverseRequest) -> Result<ReverseResponse, ServiceError> {
+        Ok(ReverseResponse {
+            reversed: req.text.chars().rev().collect(),
+        })
+    }
 }
 
 #[tokio::main]
@@ -78,4 +613,1110 @@ async fn main() {
     println!("WSDL available at: http://localhost:3002/soap/strings/wsdl");
 
     axum::serve(listener, app).await.unwrap();
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::string_service;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn concatenate_response_matches_schema() {
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Concatenate>
+                    <FirstText>Hello</FirstText><SecondText>World</SecondText><UpperCase>false</UpperCase>
+                </Concatenate></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+
+    async fn concatenate_with(separator: &str) -> String {
+        let response = string_service::handle_raw(&format!(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"
+                    xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+                <soap:Body><Concatenate>
+                    <FirstText>Hello</FirstText><SecondText>World</SecondText>{}<UpperCase>false</UpperCase>
+                </Concatenate></soap:Body>
+            </soap:Envelope>"#,
+            separator
+        ))
+        .await
+        .unwrap();
+
+        let start = response.find("<Result>").unwrap() + "<Result>".len();
+        let end = response.find("</Result>").unwrap();
+        response[start..end].to_string()
+    }
+
+    #[tokio::test]
+    async fn empty_separator_is_an_empty_string() {
+        assert_eq!(concatenate_with("<Separator></Separator>").await, "HelloWorld");
+        assert_eq!(concatenate_with("<Separator/>").await, "HelloWorld");
+    }
+
+    #[tokio::test]
+    async fn missing_separator_uses_the_default() {
+        assert_eq!(concatenate_with("").await, "Hello World");
+    }
+
+    #[tokio::test]
+    async fn separator_default_is_not_declared_on_the_element() {
+        let request = axum::http::Request::get("/soap/strings/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        // An XSD element default would apply to <Separator/>, which reads as ""
+        assert!(
+            wsdl.contains(r#"<xsd:element name="Separator" type="xsd:string" minOccurs="0" nillable="true"/>"#),
+            "{}",
+            wsdl
+        );
+    }
+
+    #[tokio::test]
+    async fn nil_separator_uses_the_default() {
+        assert_eq!(concatenate_with(r#"<Separator xsi:nil="true"/>"#).await, "Hello World");
+        assert_eq!(concatenate_with(r#"<Separator xsi:nil="1"></Separator>"#).await, "Hello World");
+        assert_eq!(
+            concatenate_with(r#"<Separator xmlns:i="http://www.w3.org/2001/XMLSchema-instance" i:nil="true"/>"#).await,
+            "Hello World"
+        );
+    }
+
+    #[tokio::test]
+    async fn cdata_content_is_concatenated_verbatim() {
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Concatenate>
+                    <FirstText><![CDATA[a & b <c>]]></FirstText><SecondText><![CDATA["d" > 'e']]></SecondText>
+                    <Separator>+</Separator><UpperCase>false</UpperCase>
+                </Concatenate></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(
+            response.contains("<Result>a &amp; b &lt;c&gt;+&quot;d&quot; &gt; &apos;e&apos;</Result>"),
+            "{}",
+            response
+        );
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn doctype_declaration_is_refused() {
+        let fault = string_service::handle_raw(
+            r#"<?xml version="1.0"?>
+            <!DOCTYPE foo [<!ENTITY xxe SYSTEM "file:///etc/passwd">]>
+            <soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><FirstWord><Text>&xxe;</Text></FirstWord></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Client</faultcode>"), "{}", fault);
+        assert!(fault.contains("DOCTYPE declarations are not allowed"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn entity_declaration_is_refused() {
+        let fault = string_service::handle_raw(
+            r#"<!ENTITY xxe SYSTEM "file:///etc/passwd">
+            <soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><FirstWord><Text>&xxe;</Text></FirstWord></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("Entity declarations are not allowed"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn undeclared_entity_reference_is_refused() {
+        let fault = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><FirstWord><Text>&foo; bar</Text></FirstWord></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("Undeclared entity reference &amp;foo; is not allowed"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn entity_references_in_cdata_and_comments_are_text() {
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <!-- &foo; is only mentioned here -->
+                <soap:Body><FirstWord><Text><![CDATA[&foo; bar]]></Text></FirstWord></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Word>&amp;foo;</Word>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn first_word_echoes_part_of_the_request() {
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><FirstWord><Text>Hello big world</Text></FirstWord></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Word>Hello</Word>"), "{}", response);
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn outline_walks_nested_sections() {
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Outline><Section>
+                    <Title>Intro</Title>
+                    <Section><Title>Scope</Title><Section><Title>Limits</Title></Section></Section>
+                    <Section><Title>Usage</Title></Section>
+                </Section></Outline></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(
+            response.contains("<Title>Intro</Title><Title>Scope</Title><Title>Limits</Title><Title>Usage</Title>"),
+            "{}",
+            response
+        );
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn resolve_link_declares_the_overridden_types() {
+        let request = axum::http::Request::get("/soap/strings/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(wsdl.contains(r#"<xsd:element name="Base" type="xsd:anyURI"/>"#), "{}", wsdl);
+        assert!(wsdl.contains(r#"<xsd:element name="Path" type="xsd:token"/>"#), "{}", wsdl);
+        assert!(wsdl.contains(r#"<xsd:element name="Link" type="xsd:anyURI"/>"#), "{}", wsdl);
+
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><ResolveLink><Base>http://example.com/docs/</Base><Path>guide</Path></ResolveLink></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Link>http://example.com/docs/guide</Link>"), "{}", response);
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn optional_response_field_is_omitted_when_none() {
+        let request = axum::http::Request::get("/soap/strings/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(
+            wsdl.contains(r#"<xsd:element name="Fragment" type="xsd:string" minOccurs="0" nillable="true"/>"#),
+            "{}",
+            wsdl
+        );
+
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><ResolveLink><Base>http://example.com</Base><Path>guide</Path></ResolveLink></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(!response.contains("Fragment"), "{}", response);
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><ResolveLink><Base>http://example.com</Base><Path>guide#install</Path></ResolveLink></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Fragment>install</Fragment>"), "{}", response);
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn greet_gets_a_context_built_from_two_headers() {
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Header><ApiKey>secret</ApiKey><TenantId> acme </TenantId></soap:Header>
+                <soap:Body><Greet><Name>Ann</Name></Greet></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Greeting>Hello Ann from acme</Greeting>"), "{}", response);
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn greet_without_a_required_header_is_a_client_fault() {
+        let fault = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Header><ApiKey>secret</ApiKey></soap:Header>
+                <soap:Body><Greet><Name>Ann</Name></Greet></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Client</faultcode>"), "{}", fault);
+        assert!(fault.contains("Missing required SOAP header TenantId"), "{}", fault);
+    }
+
+    async fn greet_with_headers(headers: &str) -> Result<String, String> {
+        string_service::handle_raw(&format!(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Header>{}</soap:Header>
+                <soap:Body><Greet><Name>Ann</Name></Greet></soap:Body>
+            </soap:Envelope>"#,
+            headers
+        ))
+        .await
+    }
+
+    #[tokio::test]
+    async fn understood_header_marked_must_understand_is_accepted() {
+        let response = greet_with_headers(
+            r#"<ApiKey soap:mustUnderstand="1">secret</ApiKey><TenantId soap:mustUnderstand="1">acme</TenantId>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Greeting>Hello Ann from acme</Greeting>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn unlisted_header_marked_must_understand_is_a_fault() {
+        let fault = greet_with_headers(
+            r#"<ApiKey>secret</ApiKey><TenantId>acme</TenantId><Trace soap:mustUnderstand="1">abc</Trace>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:MustUnderstand</faultcode>"), "{}", fault);
+        assert!(fault.contains("Header Trace must be understood but is not supported by this service"), "{}", fault);
+
+        // Without mustUnderstand an unknown header is ignored
+        let response = greet_with_headers("<ApiKey>secret</ApiKey><TenantId>acme</TenantId><Trace>abc</Trace>")
+            .await
+            .unwrap();
+        assert!(response.contains("<Greeting>Hello Ann from acme</Greeting>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn attributes_after_element_fields_round_trip() {
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Repeat count="3"><Text>ab</Text></Repeat></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains(r#"length="6""#), "{}", response);
+        assert!(response.contains("<Result>ababab</Result>"), "{}", response);
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn u64_above_2_pow_53_round_trips_exactly() {
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Hash><Text></Text><Seed>18446744073709551557</Seed></Hash></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Hash>18446744073709551557</Hash>"), "{}", response);
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Hash><Text>a</Text><Seed>14695981039346656037</Seed></Hash></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Hash>12638187200555641996</Hash>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn split_words_streams_chunked_items() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, string_service::router()).await });
+
+        let body = r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+            <soap:Body><SplitWords><Text>one two three</Text></SplitWords></soap:Body>
+        </soap:Envelope>"#;
+        let mut stream = tokio::net::TcpStream::connect(address).await.unwrap();
+        stream
+            .write_all(
+                format!(
+                    "POST /soap/strings HTTP/1.1\r\nHost: localhost\r\nContent-Type: text/xml\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .as_bytes(),
+            )
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        let (head, chunks) = response.split_once("\r\n\r\n").unwrap();
+        assert!(head.starts_with("HTTP/1.1 200"), "{}", head);
+        assert!(head.to_lowercase().contains("transfer-encoding: chunked"), "{}", head);
+        assert!(!head.to_lowercase().contains("content-length"), "{}", head);
+        // Each item is its own chunk, after the prologue and before the epilogue
+        for word in ["one", "two", "three"] {
+            let item = format!(r#"<Word xmlns="http://example.com/strings">{}</Word>"#, word);
+            assert!(chunks.contains(&format!("\r\n{:x}\r\n{}\r\n", item.len(), item)), "{}", chunks);
+        }
+        assert!(chunks.ends_with("</tns:SplitWordsResponse></soap:Body></soap:Envelope>\r\n0\r\n\r\n"), "{}", chunks);
+    }
+
+    #[tokio::test]
+    async fn split_words_declares_the_item_sequence() {
+        let request = axum::http::Request::get("/soap/strings/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(
+            wsdl.contains(
+                r#"<xsd:complexType name="SplitWordsResponseType">
+                <xsd:sequence>
+                    <xsd:element name="Word" type="xsd:string" minOccurs="0" maxOccurs="unbounded"/>
+                </xsd:sequence>"#
+            ),
+            "{}",
+            wsdl
+        );
+    }
+
+    #[tokio::test]
+    async fn skipped_helper_is_not_an_operation() {
+        let request = axum::http::Request::get("/soap/strings/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(!wsdl.contains("CountWords"), "{}", wsdl);
+
+        let fault = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><CountWords><Text>one two</Text></CountWords></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("Unknown operation: CountWords"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn nested_option_and_vec_fields_declare_their_occurrences() {
+        let request = axum::http::Request::get("/soap/strings/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        // Vec<Option<String>>: a required list with nillable items
+        assert!(
+            wsdl.contains(r#"<xsd:element name="Part" type="xsd:string" maxOccurs="unbounded" nillable="true"/>"#),
+            "{}",
+            wsdl
+        );
+        // Option<Vec<String>>: an optional list
+        assert!(
+            wsdl.contains(r#"<xsd:element name="Tag" type="xsd:string" minOccurs="0" maxOccurs="unbounded"/>"#),
+            "{}",
+            wsdl
+        );
+
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"
+                    xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+                <soap:Body><Join>
+                    <Part>a</Part><Part xsi:nil="true"/><Part>b</Part><Tag>x</Tag><Tag>y</Tag>
+                </Join></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Result>a b [x, y]</Result>"), "{}", response);
+
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Join><Part>a</Part></Join></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Result>a</Result>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn map_fields_round_trip_as_entries() {
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Substitute>
+                    <Text>cats and dogs and cats</Text>
+                    <Replacement><key>cats</key><value>birds</value></Replacement>
+                    <Replacement><key>dogs</key><value>fish</value></Replacement>
+                </Substitute></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Result>birds and fish and birds</Result>"), "{}", response);
+        assert!(
+            response.contains(
+                "<Count><key>cats</key><value>2</value></Count><Count><key>dogs</key><value>1</value></Count>"
+            ),
+            "{}",
+            response
+        );
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn echo_uses_one_type_for_request_and_response() {
+        let request = axum::http::Request::get("/soap/strings/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(wsdl.contains(r#"<xsd:element name="Echo" type="tns:EchoMessageType"/>"#), "{}", wsdl);
+        assert!(wsdl.contains(r#"<xsd:element name="EchoResponse" type="tns:EchoMessageType"/>"#), "{}", wsdl);
+        assert!(wsdl.contains(r#"<part name="parameters" element="tns:Echo"/>"#), "{}", wsdl);
+        assert!(wsdl.contains(r#"<part name="parameters" element="tns:EchoResponse"/>"#), "{}", wsdl);
+
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Echo><Text>Hello</Text></Echo></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<tns:EchoResponse "), "{}", response);
+        assert!(response.contains("<Text>Hello</Text></tns:EchoResponse>"), "{}", response);
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+
+    async fn echo_note(note: &str) -> String {
+        string_service::handle_raw(&format!(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"
+                    xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+                <soap:Body><Echo><Text>Hello</Text>{}</Echo></soap:Body>
+            </soap:Envelope>"#,
+            note
+        ))
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn empty_optional_string_is_some_empty() {
+        for note in ["<Note></Note>", "<Note/>"] {
+            let response = echo_note(note).await;
+
+            // Some("") is echoed as an empty element
+            assert!(response.contains("<Text>Hello</Text><Note/></tns:EchoResponse>"), "{}", response);
+        }
+    }
+
+    #[tokio::test]
+    async fn missing_optional_string_is_none() {
+        let response = echo_note("").await;
+
+        assert!(response.contains("<Text>Hello</Text></tns:EchoResponse>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn nil_optional_string_is_none() {
+        let response = echo_note(r#"<Note xsi:nil="true"/>"#).await;
+
+        assert!(response.contains("<Text>Hello</Text></tns:EchoResponse>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn module_function_named_like_a_runtime_helper_is_used() {
+        let fault = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Concatenate>
+                    <FirstText></FirstText><SecondText></SecondText><UpperCase>false</UpperCase>
+                </Concatenate></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Server</faultcode>"), "{}", fault);
+        assert!(fault.contains("<faultstring>Operation failed: Both texts cannot be empty</faultstring>"), "{}", fault);
+    }
+
+    async fn translate_to(language: &str) -> (axum::http::StatusCode, String) {
+        let request = axum::http::Request::post("/soap/strings")
+            .header("Content-Type", "text/xml")
+            .body(axum::body::Body::from(format!(
+                r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                    <soap:Body><Translate><Word>hello</Word><Language>{}</Language></Translate></soap:Body>
+                </soap:Envelope>"#,
+                language
+            )))
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn service_unavailable_error_is_a_503_fault() {
+        let (status, fault) = translate_to("fr").await;
+
+        assert_eq!(status, axum::http::StatusCode::SERVICE_UNAVAILABLE);
+        assert!(fault.contains("<faultcode>soap:Server</faultcode>"), "{}", fault);
+        assert!(fault.contains("The dictionary is unavailable"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn error_without_a_status_is_a_500_fault() {
+        let (status, fault) = translate_to("de").await;
+
+        assert_eq!(status, axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(fault.contains("Unsupported language: de"), "{}", fault);
+
+        let (status, response) = translate_to("nl").await;
+        assert_eq!(status, axum::http::StatusCode::OK);
+        assert!(response.contains("<Translation>hallo</Translation>"), "{}", response);
+    }
+
+    async fn first_word_of_body(content_type: &str, body: Vec<u8>) -> String {
+        let request = axum::http::Request::post("/soap/strings")
+            .header("Content-Type", content_type)
+            .body(axum::body::Body::from(body))
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), 200);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    fn first_word_envelope(text: &str) -> String {
+        format!(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><FirstWord><Text>{}</Text></FirstWord></soap:Body>
+            </soap:Envelope>"#,
+            text
+        )
+    }
+
+    #[tokio::test]
+    async fn utf8_charset_is_decoded() {
+        let body = first_word_envelope("Café crème").into_bytes();
+        let response = first_word_of_body("text/xml; charset=utf-8", body).await;
+
+        assert!(response.contains("<Word>Café</Word>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn latin1_charset_is_transcoded() {
+        // Every character of the envelope is in Latin-1, where é is the single byte 0xE9
+        let body = first_word_envelope("Café crème").chars().map(|c| c as u8).collect();
+        let response = first_word_of_body("text/xml; charset=ISO-8859-1", body).await;
+
+        assert!(response.contains("<Word>Café</Word>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn missing_charset_defaults_to_utf8() {
+        let body = first_word_envelope("Café crème").into_bytes();
+        let response = first_word_of_body("text/xml", body).await;
+
+        assert!(response.contains("<Word>Café</Word>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn flattened_pagination_is_inlined_into_the_request() {
+        let request = axum::http::Request::get("/soap/strings/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(
+            wsdl.contains(
+                r#"<xsd:complexType name="ListWordsRequestType">
+                <xsd:sequence>
+                    <xsd:element name="Text" type="xsd:string"/>
+                    <xsd:element name="Page" type="xsd:unsignedInt"/>
+                    <xsd:element name="Size" type="xsd:unsignedInt"/>
+                </xsd:sequence>"#
+            ),
+            "{}",
+            wsdl
+        );
+        assert!(!wsdl.contains(r#"name="Pagination""#), "{}", wsdl);
+
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><ListWords><Text>a b c d e</Text><Page>2</Page><Size>2</Size></ListWords></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("<Word>c</Word><Word>d</Word></tns:ListWordsResponse>"), "{}", response);
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+
+    async fn parse_numbers(texts: &[&str]) -> Result<String, String> {
+        let texts: String = texts.iter().map(|text| format!("<Text>{}</Text>", text)).collect();
+        string_service::handle_raw(&format!(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><ParseNumbers>{}</ParseNumbers></soap:Body>
+            </soap:Envelope>"#,
+            texts
+        ))
+        .await
+    }
+
+    #[tokio::test]
+    async fn batch_with_failed_items_reports_each_item() {
+        let response = parse_numbers(&["12", "twelve", "-3"]).await.unwrap();
+
+        assert!(
+            response.contains(concat!(
+                "<Number><Text>12</Text><Value>12</Value></Number>",
+                "<Number><Text>twelve</Text><Error>invalid digit found in string</Error></Number>",
+                "<Number><Text>-3</Text><Value>-3</Value></Number>"
+            )),
+            "{}",
+            response
+        );
+    }
+
+    #[tokio::test]
+    async fn batch_whose_items_all_failed_is_a_fault() {
+        let fault = parse_numbers(&["one", "two"]).await.unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Server</faultcode>"), "{}", fault);
+        assert!(fault.contains("All 2 items failed"), "{}", fault);
+
+        let response = parse_numbers(&[]).await.unwrap();
+        assert!(response.contains("ParseNumbersResponse"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn requests_over_the_concurrency_limit_get_a_503_fault() {
+        let options = string_service::ServeOptions {
+            max_concurrent_requests: 1,
+            ..Default::default()
+        };
+        let router = string_service::with_serve_options(string_service::router(), &options);
+
+        // A body that never ends keeps the first request in flight
+        let pending = futures_util::stream::pending::<Result<axum::body::Bytes, std::io::Error>>();
+        let first = axum::http::Request::post("/soap/strings")
+            .header("Content-Type", "text/xml")
+            .body(axum::body::Body::from_stream(pending))
+            .unwrap();
+        let first = router.clone().oneshot(first);
+        tokio::pin!(first);
+        assert!(tokio::time::timeout(std::time::Duration::from_millis(50), &mut first).await.is_err());
+
+        let second = axum::http::Request::post("/soap/strings")
+            .header("Content-Type", "text/xml")
+            .body(axum::body::Body::from(
+                r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                    <soap:Body><Ping/></soap:Body>
+                </soap:Envelope>"#,
+            ))
+            .unwrap();
+        let response = router.clone().oneshot(second).await.unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::SERVICE_UNAVAILABLE);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let fault = String::from_utf8(body.to_vec()).unwrap();
+        assert!(fault.contains("<faultcode>soap:Server</faultcode>"), "{}", fault);
+        assert!(fault.contains("Too many concurrent requests"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn format_price_declares_its_facets() {
+        let request = axum::http::Request::get("/soap/strings/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(
+            wsdl.contains(
+                r#"<xsd:element name="Currency">
+                        <xsd:simpleType>
+                            <xsd:restriction base="xsd:string">
+                                <xsd:pattern value="[A-Z]{3}"/>
+                            </xsd:restriction>
+                        </xsd:simpleType>
+                    </xsd:element>
+                    <xsd:element name="Label">
+                        <xsd:simpleType>
+                            <xsd:restriction base="xsd:string">
+                                <xsd:minLength value="1"/>
+                                <xsd:maxLength value="20"/>
+                            </xsd:restriction>
+                        </xsd:simpleType>
+                    </xsd:element>
+                    <xsd:element name="Amount">
+                        <xsd:simpleType>
+                            <xsd:restriction base="xsd:int">
+                                <xsd:minInclusive value="0"/>
+                                <xsd:maxInclusive value="1000000"/>
+                            </xsd:restriction>
+                        </xsd:simpleType>
+                    </xsd:element>"#
+            ),
+            "{}",
+            wsdl
+        );
+    }
+
+    async fn format_price(currency: &str, label: &str, amount: i64) -> Result<String, String> {
+        string_service::handle_raw(&format!(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><FormatPrice>
+                    <Currency>{}</Currency><Label>{}</Label><Amount>{}</Amount>
+                </FormatPrice></soap:Body>
+            </soap:Envelope>"#,
+            currency, label, amount
+        ))
+        .await
+    }
+
+    #[tokio::test]
+    async fn format_price_within_its_facets_is_dispatched() {
+        let response = format_price("EUR", "Coffee", 3).await.unwrap();
+
+        assert!(response.contains("<Price>Coffee: 3 EUR</Price>"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn pattern_violation_is_a_client_fault() {
+        let fault = format_price("eur", "Coffee", 3).await.unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Client</faultcode>"), "{}", fault);
+        assert!(fault.contains("Currency violates pattern [A-Z]{3}"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn length_violation_is_a_client_fault() {
+        let fault = format_price("EUR", "A label longer than twenty characters", 3).await.unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Client</faultcode>"), "{}", fault);
+        assert!(fault.contains("Label violates maxLength 20"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn min_violation_is_a_client_fault() {
+        let fault = format_price("EUR", "Coffee", -1).await.unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Client</faultcode>"), "{}", fault);
+        assert!(fault.contains("Amount violates minInclusive 0"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn max_violation_is_a_client_fault() {
+        let fault = format_price("EUR", "Coffee", 1000001).await.unwrap_err();
+
+        assert!(fault.contains("<faultcode>soap:Client</faultcode>"), "{}", fault);
+        assert!(fault.contains("Amount violates maxInclusive 1000000"), "{}", fault);
+    }
+
+    #[tokio::test]
+    async fn ping_accepts_both_empty_element_forms() {
+        for ping in ["<Ping/>", "<Ping></Ping>"] {
+            let response = string_service::handle_raw(&format!(
+                r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                    <soap:Body>{}</soap:Body>
+                </soap:Envelope>"#,
+                ping
+            ))
+            .await
+            .unwrap();
+
+            assert!(response.contains("<Status>OK</Status>"), "{}", response);
+            assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+        }
+    }
+
+    async fn post_json(uri: &str, body: &str) -> (axum::http::StatusCode, serde_json::Value) {
+        let request = axum::http::Request::post(uri)
+            .header("Content-Type", "application/json")
+            .body(axum::body::Body::from(body.to_string()))
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn json_api_calls_the_operation_by_path() {
+        let (status, response) = post_json(
+            "/soap/strings/json/Concatenate",
+            r#"{ "FirstText": "Hello", "SecondText": "World", "UpperCase": true }"#,
+        )
+        .await;
+
+        assert_eq!(status, 200);
+        assert_eq!(response["Result"], "HELLO WORLD");
+        assert_eq!(response["WordCount"], 2);
+    }
+
+    #[tokio::test]
+    async fn json_api_calls_the_operation_in_a_wrapper() {
+        let (status, response) = post_json(
+            "/soap/strings/json",
+            r#"{ "operation": "Concatenate", "data": { "FirstText": "Hello", "SecondText": "World", "Separator": "-", "UpperCase": false } }"#,
+        )
+        .await;
+
+        assert_eq!(status, 200);
+        assert_eq!(response["Result"], "Hello-World");
+    }
+
+    #[tokio::test]
+    async fn json_api_returns_operation_errors() {
+        let (status, response) = post_json(
+            "/soap/strings/json",
+            r#"{ "operation": "Concatenate", "data": { "FirstText": "", "SecondText": "", "UpperCase": false } }"#,
+        )
+        .await;
+
+        assert_eq!(status, 500);
+        assert!(response["error"]["message"].as_str().unwrap().contains("Both texts cannot be empty"), "{}", response);
+    }
+
+    #[tokio::test]
+    async fn json_schema_lists_the_operations() {
+        let request = axum::http::Request::get("/soap/strings/operations.json")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), 200);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let schema: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(schema["service"], "StringService");
+        assert_eq!(schema["endpoint"], "/soap/strings");
+        let operations = schema["operations"].as_array().unwrap();
+        let names: Vec<&str> = operations.iter().map(|operation| operation["name"].as_str().unwrap()).collect();
+        // In module order, without the skipped count_words
+        assert_eq!(
+            names,
+            [
+                "Concatenate", "FirstWord", "Outline", "ResolveLink", "Greet", "Repeat", "Hash", "SplitWords", "Join",
+                "Substitute", "Echo", "Translate", "ListWords", "Ping", "ParseNumbers", "FormatPrice", "Tally", "Reverse",
+            ]
+        );
+
+        let concatenate = &operations[0];
+        assert_eq!(concatenate["soapAction"], "http://example.com/strings/Concatenate");
+        let separator = &concatenate["request"]["fields"][2];
+        assert_eq!(separator["name"], "Separator");
+        assert_eq!(separator["optional"], true);
+        assert_eq!(separator["default"], " ");
+        let ping = operations.iter().find(|operation| operation["name"] == "Ping").unwrap();
+        assert_eq!(ping["request"]["fields"], serde_json::json!([]), "{}", ping);
+    }
+
+    async fn tally_request_type() -> String {
+        let request = axum::http::Request::get("/soap/strings/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        let start = wsdl.find(r#"<xsd:complexType name="TallyRequestType">"#).unwrap();
+        let end = start + wsdl[start..].find("</xsd:complexType>").unwrap();
+        wsdl[start..end].to_string()
+    }
+
+    #[tokio::test]
+    async fn vec_is_a_required_list() {
+        let fragment = tally_request_type().await;
+
+        assert!(
+            fragment.contains(r#"<xsd:element name="Word" type="xsd:string" maxOccurs="unbounded"/>"#),
+            "{}",
+            fragment
+        );
+    }
+
+    #[tokio::test]
+    async fn option_of_vec_is_an_optional_list() {
+        let fragment = tally_request_type().await;
+
+        assert!(
+            fragment.contains(r#"<xsd:element name="OptionalWord" type="xsd:string" minOccurs="0" maxOccurs="unbounded"/>"#),
+            "{}",
+            fragment
+        );
+        // Nested structs are open content, with the same occurrences
+        assert!(
+            fragment.contains(r#"<xsd:element name="OptionalLabel" type="xsd:anyType" minOccurs="0" maxOccurs="unbounded"/>"#),
+            "{}",
+            fragment
+        );
+    }
+
+    #[tokio::test]
+    async fn vec_of_option_is_a_required_list_of_nillable_items() {
+        let fragment = tally_request_type().await;
+
+        assert!(
+            fragment.contains(r#"<xsd:element name="NillableWord" type="xsd:string" maxOccurs="unbounded" nillable="true"/>"#),
+            "{}",
+            fragment
+        );
+        assert!(
+            fragment.contains(r#"<xsd:element name="NillableLabel" type="xsd:anyType" maxOccurs="unbounded" nillable="true"/>"#),
+            "{}",
+            fragment
+        );
+    }
+
+    #[tokio::test]
+    async fn option_of_vec_of_option_is_an_optional_list_of_nillable_items() {
+        let fragment = tally_request_type().await;
+
+        assert!(
+            fragment.contains(
+                r#"<xsd:element name="OptionalNillableWord" type="xsd:string" minOccurs="0" maxOccurs="unbounded" nillable="true"/>"#
+            ),
+            "{}",
+            fragment
+        );
+    }
+
+    #[tokio::test]
+    async fn list_shapes_round_trip() {
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"
+                    xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+                <soap:Body><Tally>
+                    <Word>a</Word>
+                    <NillableWord>b</NillableWord><NillableWord xsi:nil="true"/>
+                    <OptionalNillableWord xsi:nil="true"/>
+                    <NillableLabel><Text>c</Text></NillableLabel><NillableLabel xsi:nil="true"/>
+                </Tally></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        // Nil items are dropped from their lists rather than read as None
+        assert!(response.contains("<Items>3</Items>"), "{}", response);
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn namespaced_operation_is_declared_in_its_own_schema() {
+        let request = axum::http::Request::get("/soap/strings/wsdl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = string_service::router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let wsdl = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(wsdl.contains(r#"xmlns:ns1="http://example.com/strings/v2""#), "{}", wsdl);
+        assert!(wsdl.contains(r#"<xsd:import namespace="http://example.com/strings/v2"/>"#), "{}", wsdl);
+        let schema_start = wsdl
+            .find(r#"<xsd:schema targetNamespace="http://example.com/strings/v2""#)
+            .expect("a schema for the operation namespace");
+        let schema = &wsdl[schema_start..];
+        let schema = &schema[..schema.find("</xsd:schema>").unwrap()];
+        assert!(schema.contains(r#"<xsd:element name="Reverse" type="ns1:ReverseRequestType"/>"#), "{}", schema);
+        assert!(schema.contains(r#"<xsd:element name="ReverseResponse" type="ns1:ReverseResponseType"/>"#), "{}", schema);
+        assert!(schema.contains(r#"<xsd:complexType name="ReverseResponseType">"#), "{}", schema);
+
+        // The service schema no longer declares the wire elements in its own namespace
+        assert!(!wsdl.contains(r#"<xsd:element name="Reverse" type="tns:ReverseRequestType"/>"#), "{}", wsdl);
+        assert!(wsdl.contains(r#"<part name="parameters" element="ns1:Reverse"/>"#), "{}", wsdl);
+        assert!(wsdl.contains(r#"<part name="parameters" element="ns1:ReverseResponse"/>"#), "{}", wsdl);
+    }
+
+    #[tokio::test]
+    async fn namespaced_operation_answers_in_its_namespace() {
+        let response = string_service::handle_raw(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+                <soap:Body><Reverse xmlns="http://example.com/strings/v2"><Text>abc</Text></Reverse></soap:Body>
+            </soap:Envelope>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains(r#"<tns:ReverseResponse xmlns:tns="http://example.com/strings/v2""#), "{}", response);
+        assert!(response.contains("<Reversed>cba</Reversed>"), "{}", response);
+        assert_eq!(string_service::validate_against_schema(&response), Ok(()));
+    }
+}
@@ -0,0 +1,193 @@
+//! Generate HTTP authentication guards for the SOAP routes
+
+use crate::codegen::fault_event;
+use crate::parser::ServiceConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `auth = "basic"` guard and the layer applying it to the operation routes.
+/// 
+/// Requests must carry `Authorization: Basic` credentials accepted by the verifier
+/// registered with `set_basic_authenticator`; until one is registered every request is
+/// refused. Refused requests get a 401 with `WWW-Authenticate: Basic` and a `Client`
+/// fault body. The WSDL and JSON description routes stay public. Returns the router
+/// layer and the guard code, both empty when authentication is not configured.
+/// 
+/// The verified username becomes the request principal. Operations marked
+/// `#[operation(requires_role = "...")]` are only dispatched when the authorizer
+/// registered with `set_role_authorizer` grants the principal that role.
+pub fn generate_auth_guard(config: &ServiceConfig) -> (TokenStream, TokenStream) {
+    if !config.basic_auth {
+        return (TokenStream::new(), TokenStream::new());
+    }
+    
+    let principal_scope = generate_principal_scope();
+
+    let challenge = format!(r#"Basic realm="{}", charset="UTF-8""#, config.service_name);
+    let rejection_event = fault_event(quote! { message });
+    let layer = quote! {
+        .route_layer(axum::middleware::from_fn(require_basic_auth))
+    };
+
+    let code = quote! {
+        type BasicAuthenticator = dyn Fn(&str, &str) -> bool + Send + Sync;
+
+        static BASIC_AUTHENTICATOR: std::sync::RwLock<Option<std::sync::Arc<BasicAuthenticator>>> =
+            std::sync::RwLock::new(None);
+
+        /// Registers the verifier called with the username and password of each request.
+        pub fn set_basic_authenticator<F>(authenticator: F)
+        where
+            F: Fn(&str, &str) -> bool + Send + Sync + 'static,
+        {
+            *BASIC_AUTHENTICATOR
+                .write()
+                .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(std::sync::Arc::new(authenticator));
+        }
+
+        async fn require_basic_auth(
+            request: axum::extract::Request,
+            next: axum::middleware::Next,
+        ) -> axum::response::Response {
+            let credentials = request
+                .headers()
+                .get(axum::http::header::AUTHORIZATION)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_basic_credentials);
+            let authenticator = BASIC_AUTHENTICATOR
+                .read()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .clone();
+
+            let message = match (&credentials, authenticator) {
+                (None, _) => "Missing or malformed Basic credentials",
+                (Some((username, password)), Some(authenticator)) if authenticator(username, password) => {
+                    let principal = username.clone();
+                    return with_request_principal(principal, next.run(request)).await;
+                }
+                (Some(_), _) => "Invalid credentials",
+            };
+
+            current_observer().on_fault("", std::time::Duration::ZERO, "Client");
+            #rejection_event
+            axum::response::Response::builder()
+                .status(401)
+                .header("Content-Type", "text/xml; charset=utf-8")
+                .header(axum::http::header::WWW_AUTHENTICATE, #challenge)
+                .body(create_soap_fault(&ServiceFault::new(FaultCode::Client, message), "", "", SoapVersion::Soap11).into())
+                .unwrap()
+        }
+
+        type RoleAuthorizer = dyn Fn(&str, &str) -> bool + Send + Sync;
+
+        static ROLE_AUTHORIZER: std::sync::RwLock<Option<std::sync::Arc<RoleAuthorizer>>> =
+            std::sync::RwLock::new(None);
+
+        /// Registers the check called with the principal and the role an operation requires.
+        pub fn set_role_authorizer<F>(authorizer: F)
+        where
+            F: Fn(&str, &str) -> bool + Send + Sync + 'static,
+        {
+            *ROLE_AUTHORIZER
+                .write()
+                .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(std::sync::Arc::new(authorizer));
+        }
+
+        fn authorize_role(operation: &str, role: &str) -> Result<(), ServiceFault> {
+            let authorizer = ROLE_AUTHORIZER
+                .read()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .clone();
+            let granted = match (request_principal(), authorizer) {
+                (Some(principal), Some(authorizer)) => authorizer(&principal, role),
+                _ => false,
+            };
+
+            if granted {
+                Ok(())
+            } else {
+                Err(ServiceFault::client(format!("Operation {} requires the {} role", operation, role)))
+            }
+        }
+
+        #principal_scope
+
+        fn parse_basic_credentials(value: &str) -> Option<(String, String)> {
+            let (scheme, encoded) = value.trim().split_once(' ')?;
+            if !scheme.eq_ignore_ascii_case("Basic") {
+                return None;
+            }
+
+            let decoded = String::from_utf8(decode_base64(encoded.trim())?).ok()?;
+            let (username, password) = decoded.split_once(':')?;
+            Some((username.to_string(), password.to_string()))
+        }
+
+        fn decode_base64(input: &str) -> Option<Vec<u8>> {
+            let mut output = Vec::with_capacity(input.len() / 4 * 3);
+            let mut buffer = 0u32;
+            let mut bits = 0;
+
+            for c in input.trim_end_matches('=').bytes() {
+                let value = match c {
+                    b'A'..=b'Z' => c - b'A',
+                    b'a'..=b'z' => c - b'a' + 26,
+                    b'0'..=b'9' => c - b'0' + 52,
+                    b'+' => 62,
+                    b'/' => 63,
+                    _ => return None,
+                };
+                buffer = (buffer << 6) | u32::from(value);
+                bits += 6;
+                if bits >= 8 {
+                    bits -= 8;
+                    output.push((buffer >> bits) as u8);
+                    buffer &= (1 << bits) - 1;
+                }
+            }
+
+            Some(output)
+        }
+    };
+
+    (layer, code)
+}
+
+/// Generates `request_principal()` and the scope it reads from.
+/// 
+/// Like `request_namespace()`, the principal is kept in a tokio task-local with the
+/// `runtime` feature. Without it no principal is available, so role checks deny.
+fn generate_principal_scope() -> TokenStream {
+    if !cfg!(feature = "runtime") {
+        return quote! {
+            fn request_principal() -> Option<String> {
+                None
+            }
+
+            async fn with_request_principal<F>(_principal: String, future: F) -> F::Output
+            where
+                F: std::future::Future,
+            {
+                future.await
+            }
+        };
+    }
+
+    quote! {
+        tokio::task_local! {
+            static REQUEST_PRINCIPAL: String;
+        }
+
+        /// Returns the authenticated username of the request being handled.
+        pub fn request_principal() -> Option<String> {
+            REQUEST_PRINCIPAL.try_with(|principal| principal.clone()).ok()
+        }
+
+        async fn with_request_principal<F>(principal: String, future: F) -> F::Output
+        where
+            F: std::future::Future,
+        {
+            REQUEST_PRINCIPAL.scope(principal, future).await
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Generate support for batch operations with per-item outcomes
+
+use crate::parser::SoapOperation;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `PartialSuccess` trait.
+/// 
+/// Batch response types implement it in the service module to report how many of their
+/// items failed. The response itself carries the status of each item, so a partially
+/// failed batch is a successful response that clients inspect item by item.
+pub fn generate_batch_support() -> TokenStream {
+    quote! {
+        /// Implemented by batch response types that carry a status per item.
+        /// 
+        /// Responses are returned as usual when some or all items fail, and clients read
+        /// the outcome of each item from them. An operation marked
+        /// `#[operation(fault_when_all_fail)]` is answered with a `Server` fault instead
+        /// when every item of a non-empty batch failed.
+        pub trait PartialSuccess {
+            /// Returns the number of items processed.
+            fn item_count(&self) -> usize;
+
+            /// Returns the number of items that failed.
+            fn failed_count(&self) -> usize;
+
+            /// Returns the fault message used when every item failed.
+            fn failure_message(&self) -> String {
+                format!("All {} items failed", self.item_count())
+            }
+        }
+    }
+}
+
+/// Returns the check that turns a batch response whose items all failed into a fault.
+/// 
+/// Expands to nothing unless the operation is marked `fault_when_all_fail`. `fault`
+/// builds the error value from the message expression, since the SOAP and JSON
+/// dispatchers report faults differently.
+pub fn all_failed_check(
+    operation: &SoapOperation,
+    fault: impl FnOnce(TokenStream) -> TokenStream,
+) -> TokenStream {
+    if !operation.fault_when_all_fail {
+        return TokenStream::new();
+    }
+
+    let error = fault(quote! { PartialSuccess::failure_message(&result) });
+    quote! {
+        let item_count = PartialSuccess::item_count(&result);
+        if item_count > 0 && PartialSuccess::failed_count(&result) >= item_count {
+            return Err(#error);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Generate a typed SOAP client for the service
+
+use crate::codegen::{element_declarations, relocate_operations};
+use crate::parser::{is_unit_type, ServiceConfig, SoapOperation};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `client` module when the `generate_client` flag is set.
+/// 
+/// The client exposes one `async fn` per operation that builds the SOAP envelope,
+/// POSTs it with `reqwest`, and deserializes the response or fault. It reuses the
+/// service's request/response types and its envelope parsing and serialization helpers.
+/// Operations with streamed or raw responses are left out, since their response type is
+/// never deserialized as a whole.
+pub fn generate_client(config: &ServiceConfig, operations: &[SoapOperation]) -> TokenStream {
+    if !config.generate_client {
+        return TokenStream::new();
+    }
+
+    let bind_path = &config.bind_path;
+    let namespace = &config.namespace;
+    // The client module nests one level below the runtime code
+    let operations = relocate_operations(operations);
+    let methods = operations
+        .iter()
+        .filter(|operation| !operation.returns_stream && !operation.returns_raw_body)
+        .map(|operation| generate_client_method(operation, namespace, config.element_form_qualified));
+
+    quote! {
+        pub mod client {
+            use super::*;
+
+            #[derive(Debug)]
+            pub enum ClientError {
+                Http(::reqwest::Error),
+                Fault { code: String, message: String },
+                Xml(String),
+            }
+
+            impl std::fmt::Display for ClientError {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        ClientError::Http(error) => write!(f, "HTTP error: {}", error),
+                        ClientError::Fault { code, message } => {
+                            write!(f, "SOAP fault ({}): {}", code, message)
+                        }
+                        ClientError::Xml(error) => write!(f, "XML error: {}", error),
+                    }
+                }
+            }
+
+            impl std::error::Error for ClientError {}
+
+            impl From<::reqwest::Error> for ClientError {
+                fn from(error: ::reqwest::Error) -> Self {
+                    ClientError::Http(error)
+                }
+            }
+
+            #[derive(Debug, Clone)]
+            pub struct Client {
+                http: ::reqwest::Client,
+                endpoint: String,
+            }
+
+            impl Client {
+                /// Creates a client for the service hosted at `base_url`, e.g. `http://localhost:3000`.
+                pub fn new(base_url: &str) -> Self {
+                    Self::with_http_client(::reqwest::Client::new(), base_url)
+                }
+
+                /// Creates a client that sends requests through an existing `reqwest::Client`.
+                pub fn with_http_client(http: ::reqwest::Client, base_url: &str) -> Self {
+                    Client {
+                        http,
+                        endpoint: format!("{}{}", base_url.trim_end_matches('/'), #bind_path),
+                    }
+                }
+
+                #(#methods)*
+
+                async fn call(&self, soap_action: &str, operation_xml: String) -> Result<String, ClientError> {
+                    let envelope = format!(
+                        r#"<?xml version="1.0" encoding="UTF-8"?>
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+    <soap:Body>{}</soap:Body>
+</soap:Envelope>"#,
+                        operation_xml
+                    );
+
+                    // Faults come back with HTTP 500, so the status is not checked here
+                    let response = self
+                        .http
+                        .post(&self.endpoint)
+                        .header("Content-Type", "text/xml; charset=utf-8")
+                        .header("SOAPAction", format!("\"{}\"", soap_action))
+                        .body(envelope)
+                        .send()
+                        .await?
+                        .text()
+                        .await?;
+
+                    let parsed = parse_soap_envelope(&response).map_err(|fault| ClientError::Xml(fault.message))?;
+                    if parsed.operation == "Fault" {
+                        return Err(ClientError::Fault {
+                            code: extract_xml_value(&parsed.body_xml, "faultcode").unwrap_or_default(),
+                            message: extract_xml_value(&parsed.body_xml, "faultstring").unwrap_or_default(),
+                        });
+                    }
+
+                    Ok(parsed.body_xml)
+                }
+            }
+
+            fn parse_response_element<T>(response_xml: &str) -> Result<T, ClientError>
+            where
+                T: for<'de> ::serde::Deserialize<'de>,
+            {
+                // The <tns:{Operation}Response> element declares its own prefix, so it parses standalone
+                ::serde_xml_rs::from_str(response_xml)
+                    .map_err(|e| ClientError::Xml(format!("XML deserialization error: {}", e)))
+            }
+        }
+    }
+}
+
+/// Generates the client method for a single operation, named after the service function.
+fn generate_client_method(operation: &SoapOperation, namespace: &str, qualified: bool) -> TokenStream {
+    let namespace = operation.namespace.as_deref().unwrap_or(namespace);
+    let method_name = &operation.function_name;
+    let op_name = &operation.name;
+    let soap_action = format!("{}/{}", namespace, operation.name);
+    let request_type = &operation.request_type;
+    let response_type = &operation.response_type;
+
+    if is_unit_type(request_type) {
+        let operation_xml = format!(r#"<tns:{} xmlns:tns="{}"/>"#, op_name, namespace);
+        return quote! {
+            pub async fn #method_name(&self) -> Result<#response_type, ClientError> {
+                let response_xml = self.call(#soap_action, #operation_xml.to_string()).await?;
+                parse_response_element(&response_xml)
+            }
+        };
+    }
+
+    let operation_element = format!("tns:{}", op_name);
+    let declarations = element_declarations(&operation_element, namespace, qualified);
+    quote! {
+        pub async fn #method_name(&self, request: #request_type) -> Result<#response_type, ClientError> {
+            let operation_xml = serialize_xml_element(&request, #operation_element, #declarations)
+                .map_err(ClientError::Xml)?;
+            let response_xml = self.call(#soap_action, operation_xml).await?;
+            parse_response_element(&response_xml)
+        }
+    }
+}
@@ -0,0 +1,338 @@
+//! Generate the schema conformance check for tests and mock services
+
+use crate::codegen::{extract_type_name, wsdl_operations};
+use crate::parser::{is_unit_type, ServiceConfig, SoapOperation, TypeInfo};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashMap;
+
+/// Generates `validate_against_schema` with the `test-util` feature, and the check behind
+/// it for `mock` services, which validate every request. Generates nothing otherwise.
+/// 
+/// The check reads the element inside `soap:Body` and validates it against the schema
+/// the WSDL declares for it, built from the same `TypeInfo`: element order and
+/// occurrences, the namespace `element_form` gives the children, `xsi:nil` on
+/// non-nillable elements, attributes, and the lexical form of simple-typed values.
+/// `xsd:anyType` content and map entries are not descended into. With `schema_import`
+/// the types are declared elsewhere, so only the element name is checked.
+pub fn generate_conformance_check(
+    config: &ServiceConfig,
+    operations: &[SoapOperation],
+    types: &HashMap<String, TypeInfo>,
+) -> TokenStream {
+    let test_util = cfg!(feature = "test-util");
+    if !test_util && !config.mock {
+        return TokenStream::new();
+    }
+
+    let mut elements = Vec::new();
+    for operation in wsdl_operations(operations) {
+        let request_element = operation.name.clone();
+        let response_element = format!("{}Response", operation.name);
+        let namespace = operation.namespace.as_deref().unwrap_or(&config.namespace);
+        for (element, ty) in [(request_element, &operation.request_type), (response_element, &operation.response_type)] {
+            let content = if config.schema_import.is_some() {
+                quote! { None }
+            } else if is_unit_type(ty) {
+                quote! { Some(&[]) }
+            } else {
+                let fields = types
+                    .get(&extract_type_name(ty))
+                    .map(|type_info| type_info.fields.as_slice())
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|field| {
+                        let name = &field.xml_name;
+                        let xsd_type = &field.field_type;
+                        let (optional, repeated, nillable, attribute) =
+                            (field.optional, field.repeated, field.nillable, field.attribute);
+                        let complex = field.map_entry.is_some() || field.field_type == "xsd:anyType";
+                        quote! {
+                            SchemaField {
+                                name: #name,
+                                xsd_type: #xsd_type,
+                                optional: #optional,
+                                repeated: #repeated,
+                                nillable: #nillable,
+                                attribute: #attribute,
+                                complex: #complex,
+                            }
+                        }
+                    });
+                quote! { Some(&[#(#fields),*]) }
+            };
+            elements.push(quote! { (#element, #namespace, #content) });
+        }
+    }
+
+    let element_form_qualified = config.element_form_qualified;
+    let validate_against_schema = test_util.then(|| {
+        quote! {
+            /// Validates the element inside the `soap:Body` of an envelope against the schema
+            /// the WSDL declares for it (`test-util` feature).
+            ///
+            /// Meant for tests, to catch drift between the WSDL and the wire format, e.g.
+            /// `validate_against_schema(&handle_raw(request).await?)`. Returns every violation
+            /// found, or an error when the body holds a fault or an undeclared element.
+            pub fn validate_against_schema(envelope: &str) -> Result<(), Vec<String>> {
+                check_against_schema(envelope)
+            }
+        }
+    });
+
+    quote! {
+        #validate_against_schema
+
+        struct SchemaField {
+            name: &'static str,
+            xsd_type: &'static str,
+            optional: bool,
+            repeated: bool,
+            nillable: bool,
+            attribute: bool,
+            complex: bool,
+        }
+
+        // The `{Operation}` and `{Operation}Response` elements with their namespace and content
+        const SCHEMA_ELEMENTS: &[(&str, &str, Option<&[SchemaField]>)] = &[#(#elements),*];
+        const ELEMENT_FORM_QUALIFIED: bool = #element_form_qualified;
+
+        struct SchemaChild {
+            name: String,
+            namespace: Option<String>,
+            text: String,
+            has_children: bool,
+            nil: bool,
+        }
+
+        // Returns every violation of the element inside soap:Body
+        fn check_against_schema(envelope: &str) -> Result<(), Vec<String>> {
+            let mut reader = ::quick_xml::reader::NsReader::from_str(envelope);
+            let mut errors = Vec::new();
+
+            // Skip to the element inside soap:Body
+            let mut in_body = false;
+            let (namespace, start, empty) = loop {
+                match reader.read_resolved_event() {
+                    Ok((namespace, ::quick_xml::events::Event::Start(start))) if !in_body => {
+                        in_body = start.local_name().as_ref() == b"Body"
+                            && matches!(
+                                resolved_namespace(namespace).as_deref(),
+                                Some(SOAP_ENVELOPE_NAMESPACE | SOAP_12_ENVELOPE_NAMESPACE)
+                            );
+                    }
+                    Ok((namespace, ::quick_xml::events::Event::Start(start))) => {
+                        break (resolved_namespace(namespace), start.into_owned(), false)
+                    }
+                    Ok((namespace, ::quick_xml::events::Event::Empty(start))) if in_body => {
+                        break (resolved_namespace(namespace), start.into_owned(), true)
+                    }
+                    Ok((_, ::quick_xml::events::Event::Eof)) => return Err(vec!["No element found in soap:Body".to_string()]),
+                    Ok(_) => {}
+                    Err(e) => return Err(vec![format!("Malformed XML: {}", e)]),
+                }
+            };
+
+            let element = String::from_utf8_lossy(start.local_name().as_ref()).into_owned();
+            if element == "Fault" {
+                return Err(vec!["soap:Body holds a fault".to_string()]);
+            }
+            let (element_namespace, fields) = match SCHEMA_ELEMENTS.iter().find(|(name, _, _)| *name == element) {
+                Some((_, element_namespace, fields)) => (*element_namespace, *fields),
+                None => return Err(vec![format!("Element {} is not declared in the schema", element)]),
+            };
+            if namespace.as_deref() != Some(element_namespace) {
+                errors.push(format!("{} is not in the namespace {}", element, element_namespace));
+            }
+            let fields = match fields {
+                Some(fields) => fields,
+                None => return if errors.is_empty() { Ok(()) } else { Err(errors) },
+            };
+
+            // Attributes are unqualified, so they are matched by their plain name
+            let mut attributes = Vec::new();
+            for attribute in start.attributes().flatten() {
+                let (attribute_namespace, _) = reader.resolve_attribute(attribute.key);
+                let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+                if key == "xmlns" || key.starts_with("xmlns:") || resolved_namespace(attribute_namespace).is_some() {
+                    continue;
+                }
+                let value = attribute.unescape_value().map(|value| value.into_owned()).unwrap_or_default();
+                attributes.push((key, value));
+            }
+            for field in fields.iter().filter(|field| field.attribute) {
+                match attributes.iter().position(|(key, _)| key == field.name) {
+                    Some(position) => {
+                        let (_, value) = attributes.remove(position);
+                        if !is_valid_xsd_value(field.xsd_type, &value) {
+                            errors.push(format!("{}: attribute {} value {:?} is not a valid {}", element, field.name, value, field.xsd_type));
+                        }
+                    }
+                    None if !field.optional => errors.push(format!("{}: missing required attribute {}", element, field.name)),
+                    None => {}
+                }
+            }
+            for (key, _) in attributes {
+                errors.push(format!("{}: unexpected attribute {}", element, key));
+            }
+
+            let children = if empty {
+                Vec::new()
+            } else {
+                match read_schema_children(&mut reader) {
+                    Ok(children) => children,
+                    Err(e) => return Err(vec![format!("Malformed XML: {}", e)]),
+                }
+            };
+
+            // A sequence: each field takes the run of children named after it, in order
+            let expected_namespace = if ELEMENT_FORM_QUALIFIED { Some(element_namespace) } else { None };
+            let mut index = 0;
+            for field in fields.iter().filter(|field| !field.attribute) {
+                let first = index;
+                while index < children.len() && children[index].name == field.name {
+                    index += 1;
+                }
+                let count = index - first;
+                if count == 0 && !field.optional {
+                    errors.push(format!("{}: missing required element {}", element, field.name));
+                }
+                if count > 1 && !field.repeated {
+                    errors.push(format!("{}: element {} occurs {} times but may occur once", element, field.name, count));
+                }
+
+                for child in &children[first..index] {
+                    if child.namespace.as_deref() != expected_namespace {
+                        errors.push(format!(
+                            "{}: element {} is in namespace {:?}, expected {:?}",
+                            element, child.name, child.namespace, expected_namespace
+                        ));
+                    }
+                    if child.nil {
+                        if !field.nillable {
+                            errors.push(format!("{}: element {} is nil but not nillable", element, child.name));
+                        }
+                    } else if field.complex {
+                        continue;
+                    } else if child.has_children {
+                        errors.push(format!("{}: element {} has child elements but is {}", element, child.name, field.xsd_type));
+                    } else if !is_valid_xsd_value(field.xsd_type, &child.text) {
+                        errors.push(format!(
+                            "{}: element {} value {:?} is not a valid {}",
+                            element, child.name, child.text, field.xsd_type
+                        ));
+                    }
+                }
+            }
+            for child in &children[index..] {
+                errors.push(format!("{}: unexpected element {}", element, child.name));
+            }
+
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors)
+            }
+        }
+
+        fn resolved_namespace(namespace: ::quick_xml::name::ResolveResult) -> Option<String> {
+            match namespace {
+                ::quick_xml::name::ResolveResult::Bound(namespace) => {
+                    Some(String::from_utf8_lossy(namespace.as_ref()).into_owned())
+                }
+                _ => None,
+            }
+        }
+
+        // Reads the children of the current element up to its end tag
+        fn read_schema_children(reader: &mut ::quick_xml::reader::NsReader<&[u8]>) -> Result<Vec<SchemaChild>, String> {
+            let mut children: Vec<SchemaChild> = Vec::new();
+            let mut depth = 0usize;
+
+            loop {
+                let (namespace, event) = reader.read_resolved_event().map_err(|e| e.to_string())?;
+                let namespace = resolved_namespace(namespace);
+                let is_empty = matches!(event, ::quick_xml::events::Event::Empty(_));
+                match event {
+                    ::quick_xml::events::Event::Start(start) | ::quick_xml::events::Event::Empty(start) => {
+                        if depth == 0 {
+                            let nil = start.attributes().flatten().any(|attribute| {
+                                let (attribute_namespace, local_name) = reader.resolve_attribute(attribute.key);
+                                resolved_namespace(attribute_namespace).as_deref() == Some(XSI_NAMESPACE)
+                                    && local_name.as_ref() == b"nil"
+                                    && matches!(attribute.value.as_ref(), b"true" | b"1")
+                            });
+                            children.push(SchemaChild {
+                                name: String::from_utf8_lossy(start.local_name().as_ref()).into_owned(),
+                                namespace,
+                                text: String::new(),
+                                has_children: false,
+                                nil,
+                            });
+                        } else if let Some(child) = children.last_mut() {
+                            child.has_children = true;
+                        }
+                        if !is_empty {
+                            depth += 1;
+                        }
+                    }
+                    ::quick_xml::events::Event::End(_) => {
+                        if depth == 0 {
+                            return Ok(children);
+                        }
+                        depth -= 1;
+                    }
+                    ::quick_xml::events::Event::Text(text) if depth == 1 => {
+                        let text = text.unescape().map_err(|e| e.to_string())?;
+                        if let Some(child) = children.last_mut() {
+                            child.text.push_str(&text);
+                        }
+                    }
+                    ::quick_xml::events::Event::CData(data) if depth == 1 => {
+                        if let Some(child) = children.last_mut() {
+                            child.text.push_str(&String::from_utf8_lossy(&data));
+                        }
+                    }
+                    ::quick_xml::events::Event::Eof => return Err("unexpected end of document".to_string()),
+                    _ => {}
+                }
+            }
+        }
+
+        // Checks a value against the lexical space of the simple XSD types the schema uses
+        fn is_valid_xsd_value(xsd_type: &str, value: &str) -> bool {
+            let value = value.trim();
+            let is_integer = |value: &str| {
+                let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+                !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit())
+            };
+
+            match xsd_type {
+                "xsd:boolean" => matches!(value, "true" | "false" | "1" | "0"),
+                "xsd:byte" => value.parse::<i8>().is_ok(),
+                "xsd:unsignedByte" => value.parse::<u8>().is_ok(),
+                "xsd:short" => value.parse::<i16>().is_ok(),
+                "xsd:unsignedShort" => value.parse::<u16>().is_ok(),
+                "xsd:int" => value.parse::<i32>().is_ok(),
+                "xsd:unsignedInt" => value.parse::<u32>().is_ok(),
+                "xsd:long" => value.parse::<i64>().is_ok(),
+                "xsd:unsignedLong" => value.parse::<u64>().is_ok(),
+                "xsd:integer" => is_integer(value),
+                "xsd:decimal" => {
+                    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+                    let digits = whole.strip_prefix(['+', '-']).unwrap_or(whole);
+                    (!digits.is_empty() || !fraction.is_empty())
+                        && digits.bytes().all(|byte| byte.is_ascii_digit())
+                        && fraction.bytes().all(|byte| byte.is_ascii_digit())
+                }
+                // Rust also parses "inf" and "nan", which are not XSD spellings
+                "xsd:float" | "xsd:double" => {
+                    matches!(value, "INF" | "-INF" | "NaN")
+                        || (value.parse::<f64>().is_ok()
+                            && value.bytes().all(|byte| byte.is_ascii_digit() || b"+-.eE".contains(&byte)))
+                }
+                _ => true,
+            }
+        }
+    }
+}
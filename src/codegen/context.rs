@@ -0,0 +1,45 @@
+//! Generate per-request context accessors for operation handlers
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `request_namespace()` and the scope it reads from.
+/// 
+/// With the `runtime` feature, the resolved namespace of the operation element is kept
+/// in a tokio task-local for the duration of the operation call, so handlers can branch
+/// on it. Without it, the namespace is only used for dispatch.
+pub fn generate_request_context() -> TokenStream {
+    if !cfg!(feature = "runtime") {
+        return quote! {
+            async fn with_request_namespace<F>(_namespace: Option<String>, future: F) -> F::Output
+            where
+                F: std::future::Future,
+            {
+                future.await
+            }
+        };
+    }
+
+    quote! {
+        tokio::task_local! {
+            static REQUEST_NAMESPACE: Option<String>;
+        }
+
+        /// Returns the namespace of the operation element in the request being handled.
+        /// 
+        /// `None` outside an operation call or when the element is not namespaced.
+        pub fn request_namespace() -> Option<String> {
+            REQUEST_NAMESPACE
+                .try_with(|namespace| namespace.clone())
+                .ok()
+                .flatten()
+        }
+
+        async fn with_request_namespace<F>(namespace: Option<String>, future: F) -> F::Output
+        where
+            F: std::future::Future,
+        {
+            REQUEST_NAMESPACE.scope(namespace, future).await
+        }
+    }
+}
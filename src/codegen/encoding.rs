@@ -0,0 +1,109 @@
+//! Generate request body decoding by character encoding
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `decode_request_body`, which turns the raw request bytes into the envelope text.
+/// 
+/// The encoding is taken from the `charset` parameter of the request `Content-Type`,
+/// which takes precedence as in RFC 7303, then from the XML declaration, defaulting to
+/// UTF-8. A byte order mark overrides both. UTF-8 bodies are borrowed without a copy;
+/// ISO-8859-1, US-ASCII, and UTF-16 bodies are transcoded. Any other encoding gets a
+/// `Client` fault with HTTP 415 naming it, and bytes that are invalid in the encoding
+/// get a `Client` fault with HTTP 400, instead of being parsed as garbled text.
+pub fn generate_request_decoding() -> TokenStream {
+    quote! {
+        fn decode_request_body<'a>(
+            body: &'a [u8],
+            charset: Option<&str>,
+        ) -> Result<std::borrow::Cow<'a, str>, ServiceFault> {
+            // A UTF-16 declaration is only readable once decoded, so the byte order mark decides
+            if let Some(little_endian) = match body {
+                [0xFF, 0xFE, ..] => Some(true),
+                [0xFE, 0xFF, ..] => Some(false),
+                _ => None,
+            } {
+                return decode_utf16(&body[2..], little_endian).map(std::borrow::Cow::Owned);
+            }
+
+            let encoding = charset.or_else(|| declared_encoding(body)).unwrap_or("UTF-8");
+            match encoding.to_ascii_lowercase().as_str() {
+                "utf-8" | "utf8" => std::str::from_utf8(body)
+                    .map(std::borrow::Cow::Borrowed)
+                    .map_err(|e| invalid_request_encoding("UTF-8", e)),
+                "us-ascii" | "ascii" => match body.iter().position(|byte| !byte.is_ascii()) {
+                    Some(position) => Err(invalid_request_encoding(
+                        "US-ASCII",
+                        format!("non-ASCII byte at offset {}", position),
+                    )),
+                    // ASCII is a subset of UTF-8, so the bytes are valid as they are
+                    None => Ok(std::borrow::Cow::Borrowed(std::str::from_utf8(body).unwrap())),
+                },
+                // Without a byte order mark, UTF-16 is big-endian (RFC 2781)
+                "utf-16" | "utf-16be" => decode_utf16(body, false).map(std::borrow::Cow::Owned),
+                "utf-16le" => decode_utf16(body, true).map(std::borrow::Cow::Owned),
+                "iso-8859-1" | "iso8859-1" | "iso_8859-1" | "latin1" | "latin-1" | "l1" => {
+                    // Every ISO-8859-1 byte is the code point of the same value
+                    Ok(std::borrow::Cow::Owned(body.iter().map(|&byte| char::from(byte)).collect()))
+                }
+                _ => Err(ServiceFault::client(format!(
+                    "Unsupported request encoding {}; send UTF-8, UTF-16, or ISO-8859-1",
+                    encoding
+                ))
+                .with_status(Some(axum::http::StatusCode::UNSUPPORTED_MEDIA_TYPE))),
+            }
+        }
+
+        fn invalid_request_encoding(encoding: &str, error: impl std::fmt::Display) -> ServiceFault {
+            ServiceFault::client(format!("Request body is not valid {}: {}", encoding, error))
+                .with_status(Some(axum::http::StatusCode::BAD_REQUEST))
+        }
+
+        fn decode_utf16(body: &[u8], little_endian: bool) -> Result<String, ServiceFault> {
+            if body.len() % 2 != 0 {
+                return Err(invalid_request_encoding("UTF-16", "odd number of bytes"));
+            }
+
+            let units = body.chunks_exact(2).map(|pair| {
+                if little_endian {
+                    u16::from_le_bytes([pair[0], pair[1]])
+                } else {
+                    u16::from_be_bytes([pair[0], pair[1]])
+                }
+            });
+            char::decode_utf16(units)
+                .collect::<Result<String, _>>()
+                .map_err(|e| invalid_request_encoding("UTF-16", e))
+        }
+
+        // Returns the `charset` parameter of the request Content-Type, without quotes
+        fn request_charset(headers: &axum::http::HeaderMap) -> Option<&str> {
+            let content_type = headers.get(axum::http::header::CONTENT_TYPE)?.to_str().ok()?;
+            content_type.split(';').skip(1).find_map(|parameter| {
+                let (name, value) = parameter.split_once('=')?;
+                name.trim()
+                    .eq_ignore_ascii_case("charset")
+                    .then(|| value.trim().trim_matches('"'))
+                    .filter(|value| !value.is_empty())
+            })
+        }
+
+        // Returns the `encoding` pseudo-attribute of a leading `<?xml ...?>` declaration
+        fn declared_encoding(body: &[u8]) -> Option<&str> {
+            let body = body.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(body);
+            let start = body.iter().position(|byte| !byte.is_ascii_whitespace())?;
+            let body = &body[start..];
+            if !body.starts_with(b"<?xml") {
+                return None;
+            }
+
+            let end = body.windows(2).position(|pair| pair == b"?>")?;
+            let declaration = std::str::from_utf8(&body[5..end]).ok()?;
+            let (_, rest) = declaration.split_once("encoding")?;
+            let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+            let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+            let rest = &rest[1..];
+            rest.find(quote).map(|end| &rest[..end])
+        }
+    }
+}
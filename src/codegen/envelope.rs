@@ -0,0 +1,81 @@
+//! Precompute the envelope text written around response elements
+
+use crate::parser::ServiceConfig;
+
+const SOAP_ENVELOPE_NAMESPACE: &str = "http://schemas.xmlsoap.org/soap/envelope/";
+const SOAP_12_ENVELOPE_NAMESPACE: &str = "http://www.w3.org/2003/05/soap-envelope";
+
+/// The envelope text surrounding an operation's `<tns:{Operation}Response>` element.
+/// 
+/// It only depends on the namespace, the envelope prefix, and `pretty_print`, so it is
+/// computed once at macro expansion and embedded as string literals instead of per
+/// request. The SOAP 1.2 prologue differs in the envelope namespace only, and both
+/// versions share the epilogue, which just closes the elements.
+pub struct ResponseWrapper {
+    pub prefix: String,
+    pub soap_12_prefix: String,
+    pub suffix: String,
+}
+
+/// Builds the response envelope text of an operation answering in `namespace`.
+/// 
+/// The SOAP 1.2 prologue is only built for services accepting SOAP 1.2, and is the SOAP
+/// 1.1 one otherwise.
+pub fn response_wrapper(config: &ServiceConfig, namespace: &str) -> ResponseWrapper {
+    let prefix = envelope_prologue(SOAP_ENVELOPE_NAMESPACE, namespace, &config.envelope_prefix, config.pretty_print);
+    let soap_12_prefix = if config.soap_12 {
+        envelope_prologue(SOAP_12_ENVELOPE_NAMESPACE, namespace, &config.envelope_prefix, config.pretty_print)
+    } else {
+        prefix.clone()
+    };
+    let suffix = if config.pretty_print {
+        format!(
+            r#"
+    </{0}:Body>
+</{0}:Envelope>"#,
+            config.envelope_prefix
+        )
+    } else {
+        format!("</{0}:Body></{0}:Envelope>", config.envelope_prefix)
+    };
+
+    ResponseWrapper { prefix, soap_12_prefix, suffix }
+}
+
+fn envelope_prologue(envelope_namespace: &str, namespace: &str, envelope_prefix: &str, pretty_print: bool) -> String {
+    if !pretty_print {
+        return format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?><{0}:Envelope xmlns:{0}="{1}" xmlns:tns="{2}"><{0}:Body>"#,
+            envelope_prefix, envelope_namespace, namespace
+        );
+    }
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<{0}:Envelope xmlns:{0}="{1}"
+{2}xmlns:tns="{3}">
+    <{0}:Body>
+        "#,
+        envelope_prefix,
+        envelope_namespace,
+        // Aligns the namespace declarations
+        " ".repeat(envelope_prefix.len() + 11),
+        namespace
+    )
+}
+
+/// Returns the namespace declarations written on a serialized element named `root`.
+/// 
+/// A prefixed root declares its prefix, so the fragment stands on its own, and with a
+/// qualified `element_form` the namespace is also declared as the default namespace
+/// for the child elements.
+pub fn element_declarations(root: &str, namespace: &str, qualified: bool) -> String {
+    let mut declarations = String::new();
+    if let Some((prefix, _)) = root.split_once(':') {
+        declarations.push_str(&format!(r#" xmlns:{}="{}""#, prefix, namespace));
+    }
+    if qualified {
+        declarations.push_str(&format!(r#" xmlns="{}""#, namespace));
+    }
+    declarations
+}
@@ -0,0 +1,339 @@
+//! Generate SOAP fault codes and fault envelopes
+
+use crate::codegen::wsdl::escape_xml;
+use crate::parser::{FaultInfo, ServiceConfig, SoapOperation};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `FaultCode` taxonomy, the `SoapFault` trait, the internal fault type,
+/// and `create_soap_fault`.
+/// 
+/// Faults carry one of the standard SOAP 1.1 fault code QNames and, when `fault_actor`
+/// is configured, a `<faultactor>` URI. With `soap_versions = "1.1, 1.2"`, requests in a
+/// SOAP 1.2 envelope get SOAP 1.2 faults, with the code mapped to `Sender`/`Receiver`
+/// and the actor written as `<soap:Node>`. Envelopes declaring another SOAP envelope
+/// namespace are answered with a `VersionMismatch` fault. Faults raised while processing
+/// an operation name it in `<detail>`, after the detail element of the error enum
+/// variant's WSDL fault if there is one, and followed by the escaped operation element when
+/// `echo_request_in_fault` is set. Fault envelopes are indented like responses when
+/// `pretty_print` is set and compact otherwise. Envelope elements and fault codes use the
+/// configured `envelope_prefix`.
+pub fn generate_fault_support(config: &ServiceConfig) -> TokenStream {
+    let prefix = &config.envelope_prefix;
+    let line_break = if config.pretty_print { "\n            " } else { "" };
+    let (fault_actor, fault_node) = match &config.fault_actor {
+        Some(fault_actor) => (
+            format!("{}<faultactor>{}</faultactor>", line_break, escape_xml(fault_actor)),
+            format!("{0}<{1}:Node>{2}</{1}:Node>", line_break, prefix, escape_xml(fault_actor)),
+        ),
+        None => (String::new(), String::new()),
+    };
+    let child_break = if config.pretty_print { "\n                " } else { "" };
+    let detail_entry = format!(
+        r#"{}<{{0}} xmlns="{}">{{1}}</{{0}}>"#,
+        child_break,
+        escape_xml(&config.namespace)
+    );
+    // The fault element's children are qualified as the schema's element_form says
+    let grandchild_break = if config.pretty_print { "\n                    " } else { "" };
+    let variant_entry = if config.element_form_qualified {
+        format!(
+            r#"{}<{{0}} xmlns="{}">{}<Message>{{1}}</Message>{}</{{0}}>"#,
+            child_break,
+            escape_xml(&config.namespace),
+            grandchild_break,
+            child_break
+        )
+    } else {
+        format!(
+            r#"{}<tns:{{0}} xmlns:tns="{}">{}<Message>{{1}}</Message>{}</tns:{{0}}>"#,
+            child_break,
+            escape_xml(&config.namespace),
+            grandchild_break,
+            child_break
+        )
+    };
+    let detail_start = format!("{}<detail>", line_break);
+    let detail_end = format!("{}</detail>", line_break);
+    let soap_12_detail_start = format!("{}<{}:Detail>", line_break, prefix);
+    let soap_12_detail_end = format!("{}</{}:Detail>", line_break, prefix);
+    let echo_request_in_fault = config.echo_request_in_fault;
+    let soap_12 = config.soap_12;
+    let fault_template = rename_envelope_prefix(if config.pretty_print {
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+    <soap:Body>
+        <soap:Fault>
+            <faultcode>soap:{}</faultcode>
+            <faultstring>{}</faultstring>{}{}
+        </soap:Fault>
+    </soap:Body>
+</soap:Envelope>"#
+    } else {
+        r#"<?xml version="1.0" encoding="UTF-8"?><soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"><soap:Body><soap:Fault><faultcode>soap:{}</faultcode><faultstring>{}</faultstring>{}{}</soap:Fault></soap:Body></soap:Envelope>"#
+    }, prefix);
+    let soap_12_fault_template = rename_envelope_prefix(if config.pretty_print {
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<soap:Envelope xmlns:soap="http://www.w3.org/2003/05/soap-envelope">
+    <soap:Body>
+        <soap:Fault>
+            <soap:Code>
+                <soap:Value>soap:{}</soap:Value>
+            </soap:Code>
+            <soap:Reason>
+                <soap:Text xml:lang="en">{}</soap:Text>
+            </soap:Reason>{}{}
+        </soap:Fault>
+    </soap:Body>
+</soap:Envelope>"#
+    } else {
+        r#"<?xml version="1.0" encoding="UTF-8"?><soap:Envelope xmlns:soap="http://www.w3.org/2003/05/soap-envelope"><soap:Body><soap:Fault><soap:Code><soap:Value>soap:{}</soap:Value></soap:Code><soap:Reason><soap:Text xml:lang="en">{}</soap:Text></soap:Reason>{}{}</soap:Fault></soap:Body></soap:Envelope>"#
+    }, prefix);
+
+    quote! {
+        /// The standard SOAP 1.1 fault codes.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum FaultCode {
+            /// The envelope is not in the SOAP 1.1 envelope namespace.
+            VersionMismatch,
+            /// A mandatory header was not understood.
+            MustUnderstand,
+            /// The request was malformed or cannot be dispatched.
+            Client,
+            /// The request was valid but processing it failed.
+            Server,
+        }
+
+        impl FaultCode {
+            /// Returns the local name of the code, e.g. `Client`.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    FaultCode::VersionMismatch => "VersionMismatch",
+                    FaultCode::MustUnderstand => "MustUnderstand",
+                    FaultCode::Client => "Client",
+                    FaultCode::Server => "Server",
+                }
+            }
+
+            // SOAP 1.2 renamed Client and Server to Sender and Receiver
+            fn soap_12_code(&self) -> &'static str {
+                match self {
+                    FaultCode::Client => "Sender",
+                    FaultCode::Server => "Receiver",
+                    other => other.as_str(),
+                }
+            }
+        }
+
+        /// The SOAP version of a request, which its response and faults follow.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum SoapVersion {
+            Soap11,
+            Soap12,
+        }
+
+        /// Implemented in the service module by operation error types to control their fault.
+        /// 
+        /// Faults are answered with HTTP 500 unless `http_status` returns another error
+        /// status, e.g. 503 for an unavailable dependency. The body is still a SOAP fault.
+        pub trait SoapFault {
+            /// Returns the HTTP status of the fault response, or `None` for the default.
+            fn http_status(&self) -> Option<axum::http::StatusCode> {
+                None
+            }
+        }
+
+        // Only error statuses are honored, so a fault never looks like a success
+        fn fault_status(status: Option<axum::http::StatusCode>) -> Option<axum::http::StatusCode> {
+            status.filter(|status| status.is_client_error() || status.is_server_error())
+        }
+
+        #[derive(Debug)]
+        struct ServiceFault {
+            code: FaultCode,
+            message: String,
+            status: Option<axum::http::StatusCode>,
+            // The WSDL fault of an error enum variant, and the error's text for its detail
+            variant: Option<(&'static str, String)>,
+        }
+
+        impl ServiceFault {
+            fn new(code: FaultCode, message: impl Into<String>) -> Self {
+                ServiceFault { code, message: message.into(), status: None, variant: None }
+            }
+
+            fn with_status(mut self, status: Option<axum::http::StatusCode>) -> Self {
+                self.status = fault_status(status);
+                self
+            }
+
+            fn with_variant(mut self, fault_name: &'static str, message: String) -> Self {
+                self.variant = Some((fault_name, message));
+                self
+            }
+
+            fn http_status(&self) -> axum::http::StatusCode {
+                self.status.unwrap_or(axum::http::StatusCode::INTERNAL_SERVER_ERROR)
+            }
+
+            fn client(message: impl Into<String>) -> Self {
+                ServiceFault::new(FaultCode::Client, message)
+            }
+
+            fn server(message: impl Into<String>) -> Self {
+                ServiceFault::new(FaultCode::Server, message)
+            }
+        }
+
+        const SOAP_ENVELOPE_NAMESPACE: &str = "http://schemas.xmlsoap.org/soap/envelope/";
+        const SOAP_12_ENVELOPE_NAMESPACE: &str = "http://www.w3.org/2003/05/soap-envelope";
+        const SOAP_12: bool = #soap_12;
+
+        // Returns the namespace declared for the `Envelope` element, if it is qualified
+        fn envelope_namespace(xml: &str) -> Option<String> {
+            let start_tag = xml.strip_prefix('<').and_then(|rest| rest.find('>').map(|end| &rest[..end]))?;
+            let name = start_tag.split_whitespace().next().unwrap_or("");
+            let declaration = match name.split_once(':') {
+                Some((prefix, "Envelope")) => format!("xmlns:{}=", prefix),
+                None if name == "Envelope" => "xmlns=".to_string(),
+                _ => return None,
+            };
+
+            find_namespace_declaration(start_tag, &declaration, false)
+        }
+
+        // SOAP 1.2 envelopes are only recognized when the service accepts them
+        fn envelope_version(xml: &str) -> SoapVersion {
+            let xml = strip_leading_noise(xml);
+            if SOAP_12 && envelope_namespace(xml).as_deref() == Some(SOAP_12_ENVELOPE_NAMESPACE) {
+                SoapVersion::Soap12
+            } else {
+                SoapVersion::Soap11
+            }
+        }
+
+        /// Rejects envelopes whose `Envelope` element declares a namespace other than SOAP 1.1
+        /// (or SOAP 1.2, when enabled).
+        /// 
+        /// An unqualified envelope is left to the body parser, which accepts it leniently.
+        fn check_envelope_version(xml: &str) -> Result<(), ServiceFault> {
+            match envelope_namespace(xml) {
+                Some(namespace)
+                    if namespace != SOAP_ENVELOPE_NAMESPACE && !(SOAP_12 && namespace == SOAP_12_ENVELOPE_NAMESPACE) =>
+                {
+                    Err(ServiceFault::new(
+                        FaultCode::VersionMismatch,
+                        format!("Unsupported SOAP envelope namespace {}; expected {}", namespace, SOAP_ENVELOPE_NAMESPACE),
+                    ))
+                }
+                _ => Ok(()),
+            }
+        }
+
+        // Both response envelope prologues are computed at expansion, so picking one is free
+        fn envelope_prefix(prefix: &'static str, soap_12_prefix: &'static str, version: SoapVersion) -> &'static str {
+            match version {
+                SoapVersion::Soap11 => prefix,
+                SoapVersion::Soap12 => soap_12_prefix,
+            }
+        }
+
+        // Echoing requests may leak sensitive data, so it is opt-in
+        const ECHO_REQUEST_IN_FAULT: bool = #echo_request_in_fault;
+
+        fn create_soap_fault(fault: &ServiceFault, operation: &str, request_xml: &str, version: SoapVersion) -> String {
+            match version {
+                SoapVersion::Soap11 => format!(
+                    #fault_template,
+                    fault.code.as_str(),
+                    escape_fault_text(&fault.message),
+                    #fault_actor,
+                    fault_detail(fault, operation, request_xml, #detail_start, #detail_end)
+                ),
+                SoapVersion::Soap12 => format!(
+                    #soap_12_fault_template,
+                    fault.code.soap_12_code(),
+                    escape_fault_text(&fault.message),
+                    #fault_node,
+                    fault_detail(fault, operation, request_xml, #soap_12_detail_start, #soap_12_detail_end)
+                ),
+            }
+        }
+
+        // Only faults about the body carry a detail, so header and envelope faults have none
+        fn fault_detail(fault: &ServiceFault, operation: &str, request_xml: &str, start: &str, end: &str) -> String {
+            if operation.is_empty() || matches!(fault.code, FaultCode::VersionMismatch | FaultCode::MustUnderstand) {
+                return String::new();
+            }
+
+            let mut detail = String::from(start);
+            // Clients map the first detail element to the typed fault
+            if let Some((fault_name, message)) = &fault.variant {
+                detail.push_str(&format!(#variant_entry, fault_name, escape_fault_text(message)));
+            }
+            detail.push_str(&format!(#detail_entry, "Operation", escape_fault_text(operation)));
+            if ECHO_REQUEST_IN_FAULT && !request_xml.is_empty() {
+                detail.push_str(&format!(#detail_entry, "Request", escape_fault_text(request_xml)));
+            }
+            detail.push_str(end);
+            detail
+        }
+
+        fn escape_fault_text(text: &str) -> String {
+            text.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+        }
+    }
+}
+
+/// Replaces the `soap` prefix of a fault envelope template with the configured one.
+/// 
+/// Only applies to the templates themselves, before any text is filled in, so values
+/// that happen to contain `soap:` are left alone.
+fn rename_envelope_prefix(template: &str, prefix: &str) -> String {
+    template
+        .replace("xmlns:soap=", &format!("xmlns:{}=", prefix))
+        .replace("soap:", &format!("{}:", prefix))
+}
+
+/// Generates the `FaultVariant` implementations naming the WSDL fault of each variant of
+/// an operation's error enum.
+pub fn generate_fault_variants(operations: &[SoapOperation]) -> TokenStream {
+    let mut error_types: Vec<&syn::Ident> = Vec::new();
+    let mut impls = Vec::new();
+
+    for operation in operations.iter().filter(|operation| !operation.faults.is_empty()) {
+        let error_type = &operation.faults[0].error_type;
+        if error_types.contains(&error_type) {
+            continue;
+        }
+        error_types.push(error_type);
+
+        let arms = operation.faults.iter().map(|FaultInfo { variant, name, .. }| {
+            quote! { Self::#variant { .. } => #name }
+        });
+        impls.push(quote! {
+            impl FaultVariant for super::#error_type {
+                fn fault_name(&self) -> &'static str {
+                    match self {
+                        #(#arms),*
+                    }
+                }
+            }
+        });
+    }
+
+    if impls.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        /// Names the WSDL fault of an error enum's variant.
+        trait FaultVariant {
+            fn fault_name(&self) -> &'static str;
+        }
+
+        #(#impls)*
+    }
+}
@@ -0,0 +1,568 @@
+//! Rewrite request/response structs for SOAP serialization
+
+use crate::codegen::runtime_path;
+use crate::parser::{
+    field_xml_name, flattened_struct, has_serde_key, is_float_field_type, is_lexical_field_type,
+    is_simple_field_type, is_soap_attribute, map_entry_name, option_inner_type,
+    parse_soap_field_attributes, parse_soap_struct_attributes, remove_serde_key,
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::BTreeSet;
+use syn::{Fields, Item, ItemMod, Result};
+
+/// Applies `#[soap(...)]` field attributes to the structs in the service module.
+/// 
+/// The `soap` attributes are stripped (they are not real attributes) and translated
+/// into serde attributes before the struct derives expand. Struct-level attributes
+/// only affect the WSDL, so they are validated and stripped.
+/// 
+/// Attribute fields get an `@`-prefixed serialize name. serde_xml_rs reads attributes
+/// under their plain name, so deserialization keeps it unprefixed.
+/// 
+/// Float fields are routed through an XSD `double`-compatible serializer, and 128-bit
+/// integers and decimals through their exact text form. Map fields are renamed to
+/// their entry element and written as repeated key/value entries. `Option` fields are
+/// left out when `None`.
+/// 
+/// Fields with a `default` get a serde default parsing that value. Simple fields of
+/// `#[serde(flatten)]` structs get a deserializer reading their text from the map
+/// serde buffers them in.
+/// 
+/// Returns the decimal scales used, so a serializer can be generated for each of
+/// them, and the default value functions.
+pub fn rewrite_module_structs(module: &mut ItemMod) -> Result<RewrittenStructs> {
+    let mut scales = BTreeSet::new();
+    let mut default_values = Vec::new();
+    let flattened = flattened_struct_names(module);
+    
+    let items = match &mut module.content {
+        Some((_, items)) => items,
+        None => {
+            return Ok(RewrittenStructs { float_scales: scales, default_values: TokenStream::new() });
+        }
+    };
+    
+    for item in items.iter_mut() {
+        let item_struct = match item {
+            Item::Struct(item_struct) => item_struct,
+            _ => continue,
+        };
+        
+        parse_soap_struct_attributes(item_struct)?;
+        item_struct.attrs.retain(|attr| !is_soap_attribute(attr));
+        let is_flattened = flattened.contains(&item_struct.ident.to_string());
+        
+        let fields = match &mut item_struct.fields {
+            Fields::Named(fields) => &mut fields.named,
+            _ => continue,
+        };
+        
+        for field in fields.iter_mut() {
+            let attributes = parse_soap_field_attributes(field)?;
+            field.attrs.retain(|attr| !is_soap_attribute(attr));
+            
+            if let Some(default) = &attributes.default {
+                let helper = format!("xsd_default_{}", default_values.len());
+                field.attrs.push(serde_with("default", &helper));
+                default_values.push(generate_default_value(&helper, default, option_inner_type(&field.ty).is_some()));
+            }
+            
+            // serde_xml_rs hands flattened fields over as `{"$value": text}` maps
+            if is_flattened
+                && is_simple_field_type(&field.ty)
+                && !attributes.attribute
+                && !has_serde_key(field, "with")
+                && !has_serde_key(field, "deserialize_with")
+            {
+                field.attrs.push(serde_with("deserialize_with", "deserialize_flattened"));
+                if option_inner_type(&field.ty).is_some() && !has_serde_key(field, "default") {
+                    field.attrs.push(syn::parse_quote!(#[serde(default)]));
+                }
+            }
+            
+            // Absent values are omitted rather than written as empty elements, which clients
+            // read as an empty string or reject as an invalid number
+            if option_inner_type(&field.ty).is_some() && !has_serde_key(field, "skip_serializing_if") {
+                field.attrs.push(syn::parse_quote!(#[serde(skip_serializing_if = "Option::is_none")]));
+            }
+            
+            if let Some(entry) = map_entry_name(field, &attributes) {
+                remove_serde_key(field, "rename");
+                field.attrs.push(syn::parse_quote!(#[serde(rename = #entry)]));
+                if !has_serde_key(field, "with") {
+                    if !has_serde_key(field, "serialize_with") {
+                        field.attrs.push(serde_with("serialize_with", "serialize_xsd_map"));
+                    }
+                    if !has_serde_key(field, "deserialize_with") {
+                        field.attrs.push(serde_with("deserialize_with", "deserialize_xsd_map"));
+                    }
+                }
+                // A map without entries has no elements at all
+                if !has_serde_key(field, "default") {
+                    field.attrs.push(syn::parse_quote!(#[serde(default)]));
+                }
+                continue;
+            }
+            
+            if attributes.attribute {
+                let xml_name = field_xml_name(field);
+                let serialize_name = format!("@{}", xml_name);
+                remove_serde_key(field, "rename");
+                field.attrs.push(syn::parse_quote!(
+                    #[serde(rename(serialize = #serialize_name, deserialize = #xml_name))]
+                ));
+                
+                // serde_xml_rs cannot serialize Option values as attributes, so unwrap them
+                if option_inner_type(&field.ty).is_some()
+                    && !is_float_field_type(&field.ty)
+                    && !has_serde_key(field, "serialize_with")
+                    && !has_serde_key(field, "with")
+                {
+                    field.attrs.push(serde_with("serialize_with", "serialize_xsd_attribute"));
+                }
+            }
+            
+            if is_lexical_field_type(&field.ty) && !has_serde_key(field, "with") {
+                if !has_serde_key(field, "serialize_with") {
+                    field.attrs.push(serde_with("serialize_with", "serialize_xsd_lexical"));
+                }
+                if !has_serde_key(field, "deserialize_with") {
+                    field.attrs.push(serde_with("deserialize_with", "deserialize_xsd_lexical"));
+                    // deserialize_with disables the implicit None for missing Option fields
+                    if option_inner_type(&field.ty).is_some() && !has_serde_key(field, "default") {
+                        field.attrs.push(syn::parse_quote!(#[serde(default)]));
+                    }
+                }
+                continue;
+            }
+            
+            // Respect user-provided serializers
+            if !is_float_field_type(&field.ty) || has_serde_key(field, "serialize_with") || has_serde_key(field, "with") {
+                continue;
+            }
+            
+            let serializer = match attributes.scale {
+                Some(scale) => {
+                    scales.insert(scale);
+                    format!("serialize_xsd_float_scale_{}", scale)
+                }
+                None => "serialize_xsd_float".to_string(),
+            };
+            field.attrs.push(serde_with("serialize_with", &serializer));
+        }
+    }
+    
+    let default_values = if default_values.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! {
+            fn parse_xsd_default<T: std::str::FromStr>(value: &str) -> T {
+                match value.parse() {
+                    Ok(value) => value,
+                    Err(_) => panic!("Invalid default value {:?}", value),
+                }
+            }
+            
+            #(#default_values)*
+        }
+    };
+    
+    Ok(RewrittenStructs { float_scales: scales, default_values })
+}
+
+/// Returns the names of the module structs some field flattens with `#[serde(flatten)]`.
+fn flattened_struct_names(module: &ItemMod) -> Vec<String> {
+    let items = match &module.content {
+        Some((_, items)) => items,
+        None => return Vec::new(),
+    };
+    
+    items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Struct(item_struct) => Some(item_struct),
+            _ => None,
+        })
+        .flat_map(|item_struct| item_struct.fields.iter())
+        .filter(|field| has_serde_key(field, "flatten"))
+        .filter_map(|field| flattened_struct(option_inner_type(&field.ty).unwrap_or(&field.ty), module))
+        .map(|item_struct| item_struct.ident.to_string())
+        .collect()
+}
+
+/// The code the struct rewrite needs in the runtime module.
+pub struct RewrittenStructs {
+    /// Decimal scales of `#[soap(scale = N)]` fields.
+    pub float_scales: BTreeSet<usize>,
+    /// Default value functions of `#[soap(default = "...")]` fields.
+    pub default_values: TokenStream,
+}
+
+/// Generates the serde default function of a field, parsing its XSD default value.
+/// 
+/// Built-in types are validated at expansion, so parsing only fails for other types
+/// with an invalid value. An optional field defaults to `Some` of the value.
+fn generate_default_value(name: &str, value: &str, optional: bool) -> TokenStream {
+    let name = format_ident!("{}", name);
+    
+    if optional {
+        quote! {
+            pub(super) fn #name<T: std::str::FromStr>() -> Option<T> {
+                Some(parse_xsd_default(#value))
+            }
+        }
+    } else {
+        quote! {
+            pub(super) fn #name<T: std::str::FromStr>() -> T {
+                parse_xsd_default(#value)
+            }
+        }
+    }
+}
+
+/// Builds a `#[serde(key = "...")]` attribute naming a helper in the runtime module.
+/// 
+/// The rewritten structs stay in the user's module, so the helpers are referenced by path.
+fn serde_with(key: &str, helper: &str) -> syn::Attribute {
+    let key = format_ident!("{}", key);
+    let path = runtime_path(helper);
+    syn::parse_quote!(#[serde(#key = #path)])
+}
+
+/// Generates the serializer for optional attribute fields, which writes the inner value.
+pub fn generate_attribute_serializer() -> TokenStream {
+    quote! {
+        pub(super) fn serialize_xsd_attribute<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: ::serde::Serialize,
+            S: ::serde::Serializer,
+        {
+            match value {
+                Some(value) => value.serialize(serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+    }
+}
+
+/// Generates the serde helpers for map fields, which are written as repeated
+/// `<entry><key>..</key><value>..</value></entry>` elements.
+pub fn generate_map_serializers() -> TokenStream {
+    quote! {
+        #[derive(::serde::Serialize)]
+        struct XsdMapEntryRef<'a, K, V> {
+            key: &'a K,
+            value: &'a V,
+        }
+
+        #[derive(::serde::Deserialize)]
+        struct XsdMapEntry<K, V> {
+            key: K,
+            value: V,
+        }
+
+        pub(super) fn serialize_xsd_map<'a, M, K, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+            K: ::serde::Serialize + 'a,
+            V: ::serde::Serialize + 'a,
+            S: ::serde::Serializer,
+        {
+            serializer.collect_seq(map.into_iter().map(|(key, value)| XsdMapEntryRef { key, value }))
+        }
+
+        pub(super) fn deserialize_xsd_map<'de, M, K, V, D>(deserializer: D) -> Result<M, D::Error>
+        where
+            M: FromIterator<(K, V)>,
+            K: ::serde::Deserialize<'de>,
+            V: ::serde::Deserialize<'de>,
+            D: ::serde::Deserializer<'de>,
+        {
+            let entries: Vec<XsdMapEntry<K, V>> = ::serde::Deserialize::deserialize(deserializer)?;
+            Ok(entries.into_iter().map(|entry| (entry.key, entry.value)).collect())
+        }
+    }
+}
+
+/// Generates the text-based serde helpers for 128-bit integer and decimal fields.
+/// 
+/// serde_xml_rs supports neither `i128` nor `u128`, and going through `f64` would lose
+/// precision for decimals, so these values are written and parsed via `Display`/`FromStr`.
+pub fn generate_lexical_serializers() -> TokenStream {
+    let decimal_impl = if cfg!(feature = "decimal") {
+        quote! { impl XsdLexical for ::rust_decimal::Decimal {} }
+    } else {
+        TokenStream::new()
+    };
+    
+    quote! {
+        trait XsdLexical: Sized + std::fmt::Display + std::str::FromStr
+        where
+            <Self as std::str::FromStr>::Err: std::fmt::Display,
+        {
+        }
+
+        impl XsdLexical for i128 {}
+        impl XsdLexical for u128 {}
+        #decimal_impl
+
+        pub(super) trait XsdLexicalField: Sized {
+            fn to_xsd_text(&self) -> Option<String>;
+            fn from_xsd_text(text: Option<String>) -> Result<Self, String>;
+        }
+
+        impl<T> XsdLexicalField for T
+        where
+            T: XsdLexical,
+            <T as std::str::FromStr>::Err: std::fmt::Display,
+        {
+            fn to_xsd_text(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+
+            fn from_xsd_text(text: Option<String>) -> Result<Self, String> {
+                let text = text.ok_or_else(|| "missing value".to_string())?;
+                text.trim()
+                    .parse()
+                    .map_err(|e| format!("invalid value {:?}: {}", text, e))
+            }
+        }
+
+        impl<T> XsdLexicalField for Option<T>
+        where
+            T: XsdLexical,
+            <T as std::str::FromStr>::Err: std::fmt::Display,
+        {
+            fn to_xsd_text(&self) -> Option<String> {
+                self.as_ref().map(|value| value.to_string())
+            }
+
+            fn from_xsd_text(text: Option<String>) -> Result<Self, String> {
+                match text {
+                    Some(text) if !text.trim().is_empty() => T::from_xsd_text(Some(text)).map(Some),
+                    _ => Ok(None),
+                }
+            }
+        }
+
+        pub(super) fn serialize_xsd_lexical<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: XsdLexicalField,
+            S: ::serde::Serializer,
+        {
+            match value.to_xsd_text() {
+                Some(text) => serializer.serialize_str(&text),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub(super) fn deserialize_xsd_lexical<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+        where
+            T: XsdLexicalField,
+            D: ::serde::Deserializer<'de>,
+        {
+            let text: Option<String> = ::serde::Deserialize::deserialize(deserializer)?;
+            T::from_xsd_text(text).map_err(::serde::de::Error::custom)
+        }
+    }
+}
+
+/// Generates `deserialize_flattened`, referenced by the simple fields of flattened structs.
+/// 
+/// serde buffers the content of a flattened struct before deserializing it, and
+/// serde_xml_rs buffers each element as a map holding its text under `$value`, which
+/// no simple type deserializes from. The helper takes the text from that map (or a
+/// plain string) and parses it with `FromStr`; only strings may be empty.
+pub fn generate_flatten_deserializer() -> TokenStream {
+    let decimal_impl = if cfg!(feature = "decimal") {
+        quote! { impl XsdText for ::rust_decimal::Decimal {} }
+    } else {
+        TokenStream::new()
+    };
+    
+    quote! {
+        trait XsdText: Sized + std::str::FromStr
+        where
+            <Self as std::str::FromStr>::Err: std::fmt::Display,
+        {
+            fn parse_text(text: Option<String>) -> Result<Self, String> {
+                let text = text.ok_or_else(|| "missing value".to_string())?;
+                text.trim()
+                    .parse()
+                    .map_err(|e| format!("invalid value {:?}: {}", text, e))
+            }
+        }
+
+        impl XsdText for String {
+            fn parse_text(text: Option<String>) -> Result<Self, String> {
+                Ok(text.unwrap_or_default())
+            }
+        }
+
+        impl XsdText for bool {}
+        impl XsdText for i8 {}
+        impl XsdText for u8 {}
+        impl XsdText for i16 {}
+        impl XsdText for u16 {}
+        impl XsdText for i32 {}
+        impl XsdText for u32 {}
+        impl XsdText for i64 {}
+        impl XsdText for u64 {}
+        impl XsdText for i128 {}
+        impl XsdText for u128 {}
+        impl XsdText for f32 {}
+        impl XsdText for f64 {}
+        #decimal_impl
+
+        pub(super) trait XsdFlattenedField: Sized {
+            fn from_flattened_text(text: Option<String>) -> Result<Self, String>;
+        }
+
+        impl<T> XsdFlattenedField for T
+        where
+            T: XsdText,
+            <T as std::str::FromStr>::Err: std::fmt::Display,
+        {
+            fn from_flattened_text(text: Option<String>) -> Result<Self, String> {
+                T::parse_text(text)
+            }
+        }
+
+        impl<T> XsdFlattenedField for Option<T>
+        where
+            T: XsdText,
+            <T as std::str::FromStr>::Err: std::fmt::Display,
+        {
+            fn from_flattened_text(text: Option<String>) -> Result<Self, String> {
+                match text {
+                    Some(text) if !text.trim().is_empty() => T::parse_text(Some(text)).map(Some),
+                    _ => Ok(None),
+                }
+            }
+        }
+
+        struct XsdTextVisitor;
+
+        impl<'de> ::serde::de::Visitor<'de> for XsdTextVisitor {
+            type Value = Option<String>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("element text")
+            }
+
+            fn visit_str<E: ::serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(Some(value.to_string()))
+            }
+
+            fn visit_string<E: ::serde::de::Error>(self, value: String) -> Result<Self::Value, E> {
+                Ok(Some(value))
+            }
+
+            fn visit_unit<E: ::serde::de::Error>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            // Attributes of the element are other entries of the map and are ignored
+            fn visit_map<A: ::serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut text = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "$value" {
+                        text = Some(map.next_value::<String>()?);
+                    } else {
+                        map.next_value::<::serde::de::IgnoredAny>()?;
+                    }
+                }
+                Ok(text)
+            }
+        }
+
+        pub(super) fn deserialize_flattened<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+        where
+            T: XsdFlattenedField,
+            D: ::serde::Deserializer<'de>,
+        {
+            let text = deserializer.deserialize_any(XsdTextVisitor)?;
+            T::from_flattened_text(text).map_err(::serde::de::Error::custom)
+        }
+    }
+}
+
+/// Generates the XSD `double` serializers referenced by rewritten float fields.
+/// 
+/// Values use the shortest round-trip representation, switch to exponent notation
+/// for very large or small magnitudes, and map infinities to `INF`/`-INF`.
+pub fn generate_float_serializers(scales: &BTreeSet<usize>) -> TokenStream {
+    let scaled_serializers = scales.iter().map(|scale| {
+        let name = format_ident!("serialize_xsd_float_scale_{}", scale);
+        quote! {
+            pub(super) fn #name<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: XsdFloat,
+                S: ::serde::Serializer,
+            {
+                match value.to_xsd_string(Some(#scale)) {
+                    Some(text) => serializer.serialize_str(&text),
+                    None => serializer.serialize_none(),
+                }
+            }
+        }
+    });
+    
+    quote! {
+        pub(super) trait XsdFloat {
+            fn to_xsd_string(&self, scale: Option<usize>) -> Option<String>;
+        }
+
+        impl XsdFloat for f64 {
+            fn to_xsd_string(&self, scale: Option<usize>) -> Option<String> {
+                Some(format_xsd_double(*self, scale, self.to_string(), format!("{:E}", self)))
+            }
+        }
+
+        impl XsdFloat for f32 {
+            // Formatted as f32 so 0.1f32 stays "0.1" instead of its widened f64 digits
+            fn to_xsd_string(&self, scale: Option<usize>) -> Option<String> {
+                Some(format_xsd_double(*self as f64, scale, self.to_string(), format!("{:E}", self)))
+            }
+        }
+
+        impl<T: XsdFloat> XsdFloat for Option<T> {
+            fn to_xsd_string(&self, scale: Option<usize>) -> Option<String> {
+                self.as_ref().and_then(|value| value.to_xsd_string(scale))
+            }
+        }
+
+        fn format_xsd_double(value: f64, scale: Option<usize>, plain: String, exponent: String) -> String {
+            if value.is_nan() {
+                return "NaN".to_string();
+            }
+            if value.is_infinite() {
+                return if value > 0.0 { "INF" } else { "-INF" }.to_string();
+            }
+            if let Some(scale) = scale {
+                return format!("{:.*}", scale, value);
+            }
+
+            let magnitude = value.abs();
+            if magnitude == 0.0 || (1e-6..1e15).contains(&magnitude) {
+                plain
+            } else {
+                exponent
+            }
+        }
+
+        pub(super) fn serialize_xsd_float<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: XsdFloat,
+            S: ::serde::Serializer,
+        {
+            match value.to_xsd_string(None) {
+                Some(text) => serializer.serialize_str(&text),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        #(#scaled_serializers)*
+    }
+}
@@ -0,0 +1,143 @@
+//! Generate XML hardening checks run before an envelope is parsed
+
+use crate::parser::ServiceConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `check_xml_safety`, which rejects document type and entity declarations,
+/// references to undeclared entities, and envelopes nested deeper than `max_depth`.
+/// 
+/// A DOCTYPE is never needed in SOAP and is how entity-expansion and XXE payloads
+/// are smuggled in. Only the predefined and numeric character references can be
+/// resolved without one, so anything else is refused instead of reaching the
+/// string-based entity decoding. The depth scan is iterative, so pathological
+/// nesting is refused before any recursive parsing starts.
+pub fn generate_xml_guards(config: &ServiceConfig) -> TokenStream {
+    let max_depth = config.max_depth;
+
+    quote! {
+        const MAX_ELEMENT_DEPTH: usize = #max_depth;
+
+        fn check_xml_safety(xml: &str) -> Result<(), String> {
+            check_declarations(xml)?;
+            check_entity_references(xml)?;
+            check_xml_depth(xml, MAX_ELEMENT_DEPTH)
+        }
+
+        fn check_declarations(xml: &str) -> Result<(), String> {
+            for (start, _) in xml.match_indices("<!") {
+                let keyword = xml[start + 2..].get(..7).unwrap_or_default();
+                if keyword.eq_ignore_ascii_case("DOCTYPE") {
+                    return Err("DOCTYPE declarations are not allowed in SOAP messages".to_string());
+                }
+                if keyword.get(..6).is_some_and(|keyword| keyword.eq_ignore_ascii_case("ENTITY")) {
+                    return Err("Entity declarations are not allowed in SOAP messages".to_string());
+                }
+            }
+
+            Ok(())
+        }
+
+        fn check_entity_references(xml: &str) -> Result<(), String> {
+            let mut rest = xml;
+
+            while let Some(position) = rest.find(['&', '<']) {
+                rest = &rest[position..];
+
+                // References inside CDATA sections and comments are plain text
+                let section = [("<![CDATA[", "]]>"), ("<!--", "-->")]
+                    .iter()
+                    .find(|(open, _)| rest.starts_with(open));
+                if let Some((open, close)) = section {
+                    rest = match rest[open.len()..].find(close) {
+                        Some(end) => &rest[open.len() + end + close.len()..],
+                        None => "",
+                    };
+                    continue;
+                }
+
+                if let Some(reference) = rest.strip_prefix('&') {
+                    // A bare '&' is malformed rather than a reference, so the parser reports it
+                    let name_end = reference
+                        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '#' | '_' | '-' | '.' | ':')));
+                    if let Some(end) = name_end.filter(|&end| reference[end..].starts_with(';')) {
+                        let name = &reference[..end];
+                        let predefined = matches!(name, "lt" | "gt" | "amp" | "quot" | "apos");
+                        let numeric = name.strip_prefix('#').is_some_and(|number| {
+                            match number.strip_prefix('x') {
+                                Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+                                None => !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()),
+                            }
+                        });
+                        if !predefined && !numeric {
+                            return Err(format!("Undeclared entity reference &{}; is not allowed", name));
+                        }
+                    }
+                }
+                rest = &rest[1..];
+            }
+
+            Ok(())
+        }
+
+        fn check_xml_depth(xml: &str, max_depth: usize) -> Result<(), String> {
+            let mut depth = 0usize;
+            let mut rest = xml;
+
+            while let Some(start) = rest.find('<') {
+                rest = &rest[start..];
+
+                // Markup that does not open or close an element is skipped whole
+                let skipped = [("<!--", "-->"), ("<![CDATA[", "]]>"), ("<?", "?>"), ("<!", ">")]
+                    .iter()
+                    .find(|(open, _)| rest.starts_with(open))
+                    .map(|(open, close)| match rest[open.len()..].find(close) {
+                        Some(end) => open.len() + end + close.len(),
+                        None => rest.len(),
+                    });
+                if let Some(length) = skipped {
+                    rest = &rest[length..];
+                    continue;
+                }
+
+                let closing = rest.starts_with("</");
+                let (tag_end, self_closing) = find_tag_end(rest);
+                rest = &rest[tag_end..];
+
+                if closing {
+                    depth = depth.saturating_sub(1);
+                } else if !self_closing {
+                    depth += 1;
+                    if depth > max_depth {
+                        return Err(format!(
+                            "XML nesting exceeds the maximum depth of {} elements",
+                            max_depth
+                        ));
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        // Returns the offset just past the tag's '>' (ignoring '>' inside quoted attribute
+        // values) and whether the tag is self-closing
+        fn find_tag_end(tag: &str) -> (usize, bool) {
+            let mut quote = None;
+            let mut previous = '<';
+
+            for (index, c) in tag.char_indices().skip(1) {
+                match quote {
+                    Some(q) if c == q => quote = None,
+                    Some(_) => {}
+                    None if c == '"' || c == '\'' => quote = Some(c),
+                    None if c == '>' => return (index + 1, previous == '/'),
+                    None => {}
+                }
+                previous = c;
+            }
+
+            (tag.len(), false)
+        }
+    }
+}
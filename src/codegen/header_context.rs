@@ -0,0 +1,123 @@
+//! Generate request contexts built from SOAP header entries
+
+use crate::parser::SoapOperation;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `FromSoapHeaders` trait, the `SoapHeaders` it reads from, and
+/// `read_soap_headers`.
+/// 
+/// Context types implement the trait in the service module, e.g. a `RequestContext`
+/// holding an API key and a tenant. An operation taking such a type after its request
+/// (and before a `RawXml`) gets the value built from the request's `soap:Header`
+/// entries. The headers are only read when some operation takes a context.
+pub fn generate_header_context(operations: &[SoapOperation]) -> TokenStream {
+    let reads_soap_headers = operations.iter().any(|operation| operation.context_type.is_some());
+
+    quote! {
+        const READS_SOAP_HEADERS: bool = #reads_soap_headers;
+
+        /// The entries of a request's `soap:Header`, by local name.
+        /// 
+        /// Each entry holds its text content, trimmed; entries with child elements are not
+        /// descended into. Requests on the JSON API carry no SOAP headers, so it is empty there.
+        #[derive(Debug, Clone, Default)]
+        pub struct SoapHeaders {
+            entries: Vec<(String, String)>,
+        }
+
+        impl SoapHeaders {
+            /// Returns the text of the first entry with this local name, whatever its namespace.
+            pub fn get(&self, local_name: &str) -> Option<&str> {
+                self.entries
+                    .iter()
+                    .find(|(name, _)| name == local_name)
+                    .map(|(_, text)| text.as_str())
+            }
+
+            /// Returns the text of an entry that must be present and not empty.
+            pub fn require(&self, local_name: &str) -> Result<&str, String> {
+                self.get(local_name)
+                    .filter(|text| !text.is_empty())
+                    .ok_or_else(|| format!("Missing required SOAP header {}", local_name))
+            }
+        }
+
+        /// Implemented by context types built from the SOAP headers of a request.
+        /// 
+        /// The context is built before the request is parsed, and an error is answered with a
+        /// `Client` fault carrying the message, so the operation is not called.
+        pub trait FromSoapHeaders: Sized {
+            fn from_soap_headers(headers: &SoapHeaders) -> Result<Self, String>;
+        }
+
+        // Reads the text of each direct child of the envelope's Header element
+        fn read_soap_headers(xml: &str) -> SoapHeaders {
+            let mut reader = ::quick_xml::reader::Reader::from_str(xml);
+            let mut entries: Vec<(String, String)> = Vec::new();
+            let mut depth = 0usize;
+            let mut in_header = false;
+
+            loop {
+                match reader.read_event() {
+                    Ok(::quick_xml::events::Event::Start(start)) => {
+                        depth += 1;
+                        let local_name = String::from_utf8_lossy(start.local_name().as_ref()).into_owned();
+                        match depth {
+                            // The header precedes the body, so there is nothing left to read
+                            2 if local_name == "Body" => break,
+                            2 => in_header = local_name == "Header",
+                            3 if in_header => entries.push((local_name, String::new())),
+                            _ => {}
+                        }
+                    }
+                    Ok(::quick_xml::events::Event::Empty(start)) if depth == 2 && in_header => {
+                        entries.push((String::from_utf8_lossy(start.local_name().as_ref()).into_owned(), String::new()));
+                    }
+                    Ok(::quick_xml::events::Event::Text(text)) if depth == 3 && in_header => {
+                        if let (Some((_, value)), Ok(text)) = (entries.last_mut(), text.unescape()) {
+                            value.push_str(&text);
+                        }
+                    }
+                    Ok(::quick_xml::events::Event::CData(data)) if depth == 3 && in_header => {
+                        if let Some((_, value)) = entries.last_mut() {
+                            value.push_str(&String::from_utf8_lossy(&data));
+                        }
+                    }
+                    Ok(::quick_xml::events::Event::End(_)) => depth = depth.saturating_sub(1),
+                    Ok(::quick_xml::events::Event::Eof) | Err(_) => break,
+                    _ => {}
+                }
+            }
+
+            for (_, value) in entries.iter_mut() {
+                *value = value.trim().to_string();
+            }
+            SoapHeaders { entries }
+        }
+    }
+}
+
+/// Returns the statement building the operation's context as `context`.
+/// 
+/// Expands to nothing unless the operation takes a `FromSoapHeaders` context. `fault`
+/// builds the error value from the message expression, since the SOAP and JSON
+/// dispatchers report faults differently.
+pub fn context_construction(
+    operation: &SoapOperation,
+    headers: TokenStream,
+    fault: impl FnOnce(TokenStream) -> TokenStream,
+) -> TokenStream {
+    let context_type = match &operation.context_type {
+        Some(context_type) => context_type,
+        None => return TokenStream::new(),
+    };
+
+    let error = fault(quote! { message });
+    quote! {
+        let context = match <#context_type as FromSoapHeaders>::from_soap_headers(#headers) {
+            Ok(context) => context,
+            Err(message) => return Err(#error),
+        };
+    }
+}
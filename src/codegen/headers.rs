@@ -0,0 +1,51 @@
+//! Generate operation-supplied HTTP response headers
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `WithHeaders<T>` response wrapper and the code merging its headers
+/// into the HTTP response.
+/// 
+/// Operations returning `Result<WithHeaders<T>, E>` are described and serialized as `T`.
+/// The service always sets `Content-Type` and `SOAPAction` itself, so operation headers
+/// with those names are ignored; all other headers are appended.
+pub fn generate_response_headers() -> TokenStream {
+    quote! {
+        /// A response paired with extra HTTP headers, e.g. `Cache-Control`.
+        #[derive(Debug, Clone)]
+        pub struct WithHeaders<T> {
+            pub body: T,
+            pub headers: axum::http::HeaderMap,
+        }
+
+        impl<T> WithHeaders<T> {
+            pub fn new(body: T) -> Self {
+                WithHeaders {
+                    body,
+                    headers: axum::http::HeaderMap::new(),
+                }
+            }
+
+            /// Adds a header, panicking if the name or value is not a valid HTTP header.
+            pub fn header(mut self, name: &str, value: &str) -> Self {
+                let name = axum::http::HeaderName::try_from(name).expect("invalid header name");
+                let value = axum::http::HeaderValue::try_from(value).expect("invalid header value");
+                self.headers.append(name, value);
+                self
+            }
+        }
+
+        fn apply_operation_headers(
+            mut response: axum::response::Response,
+            headers: &axum::http::HeaderMap,
+        ) -> axum::response::Response {
+            for (name, value) in headers {
+                if name == axum::http::header::CONTENT_TYPE || name.as_str() == "soapaction" {
+                    continue;
+                }
+                response.headers_mut().append(name.clone(), value.clone());
+            }
+            response
+        }
+    }
+}
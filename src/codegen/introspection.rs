@@ -0,0 +1,63 @@
+//! Generate the runtime list of operations
+
+use crate::codegen::extract_type_name;
+use crate::parser::{is_unit_type, ServiceConfig, SoapOperation};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `OperationInfo` and `operations()`, which lists every operation in
+/// declaration order.
+/// 
+/// The entries are built at macro expansion from the same `SoapOperation`s as the WSDL,
+/// so admin endpoints and dashboards can enumerate the service without parsing it.
+pub fn generate_operation_info(config: &ServiceConfig, operations: &[SoapOperation]) -> TokenStream {
+    let entries = operations.iter().map(|operation| {
+        let name = &operation.name;
+        let namespace = operation.namespace.as_deref().unwrap_or(&config.namespace);
+        let soap_action = format!("{}/{}", config.namespace, operation.name);
+        let request_type = if is_unit_type(&operation.request_type) {
+            "()".to_string()
+        } else {
+            extract_type_name(&operation.request_type)
+        };
+        let response_type = if is_unit_type(&operation.response_type) {
+            "()".to_string()
+        } else {
+            extract_type_name(&operation.response_type)
+        };
+
+        quote! {
+            OperationInfo {
+                name: #name,
+                namespace: #namespace,
+                request_type: #request_type,
+                response_type: #response_type,
+                soap_action: #soap_action,
+            }
+        }
+    });
+
+    quote! {
+        /// Describes an operation of the service.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct OperationInfo {
+            /// The operation name, which is also the request element name.
+            pub name: &'static str,
+            /// The namespace of the operation element.
+            pub namespace: &'static str,
+            /// The Rust name of the request type, or `()` for operations without parameters.
+            pub request_type: &'static str,
+            /// The Rust name of the response type.
+            pub response_type: &'static str,
+            /// The SOAP action declared in the WSDL.
+            pub soap_action: &'static str,
+        }
+
+        const OPERATIONS: &[OperationInfo] = &[#(#entries),*];
+
+        /// Returns the operations of the service in declaration order.
+        pub fn operations() -> &'static [OperationInfo] {
+            OPERATIONS
+        }
+    }
+}
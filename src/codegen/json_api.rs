@@ -0,0 +1,252 @@
+//! Generate the JSON-in/JSON-out compatibility routes
+
+use crate::codegen::{
+    all_failed_check, await_operation, context_construction, rate_limit_check, restriction_check, validate_check,
+};
+use crate::parser::{is_unit_type, ServiceConfig, SoapOperation};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `{bind_path}/json` routes when the `json_api` flag is set.
+/// 
+/// `POST {bind_path}/json/{Operation}` takes the request type as JSON, and
+/// `POST {bind_path}/json` takes a `{ "operation": ..., "data": ... }` wrapper. Both call
+/// the same operation functions as the SOAP route and answer with the response type
+/// as JSON, or `{ "error": { "code", "message" } }` on failure. Requires `serde_json`
+/// in the using crate.
+pub fn generate_json_api(config: &ServiceConfig, operations: &[SoapOperation]) -> (TokenStream, TokenStream) {
+    if !config.json_api {
+        return (TokenStream::new(), TokenStream::new());
+    }
+    
+    let wrapper_path = format!("{}/json", config.bind_path);
+    let operation_path = format!("{}/json/:operation", config.bind_path);
+    let routes = quote! {
+        .route(
+            #wrapper_path,
+            axum::routing::post(json_wrapper_handler)
+                .layer(axum::extract::DefaultBodyLimit::max(MAX_REQUEST_BYTES)),
+        )
+        .route(
+            #operation_path,
+            axum::routing::post(json_operation_handler)
+                .layer(axum::extract::DefaultBodyLimit::max(MAX_REQUEST_BYTES)),
+        )
+    };
+    
+    // JSON has no namespaces, so namespace-insensitive operations win a name clash
+    let mut operations: Vec<&SoapOperation> = operations.iter().collect();
+    operations.sort_by_key(|operation| operation.namespace.is_some());
+    let handlers = operations.iter().map(|operation| generate_json_operation(operation));
+    
+    let code = quote! {
+        async fn json_operation_handler(
+            axum::extract::Path(operation): axum::extract::Path<String>,
+            headers: axum::http::HeaderMap,
+            body: Result<String, axum::extract::rejection::StringRejection>,
+        ) -> axum::response::Response {
+            let body = match body {
+                Ok(body) => body,
+                Err(rejection) => return reject_json_body(rejection),
+            };
+            let data = if body.trim().is_empty() {
+                ::serde_json::Value::Null
+            } else {
+                match ::serde_json::from_str(&body) {
+                    Ok(data) => data,
+                    Err(e) => return json_error_response(400, "Client", &format!("Invalid JSON: {}", e)),
+                }
+            };
+
+            with_request_client(&headers, process_json_request(&operation, data)).await
+        }
+
+        async fn json_wrapper_handler(
+            headers: axum::http::HeaderMap,
+            body: Result<String, axum::extract::rejection::StringRejection>,
+        ) -> axum::response::Response {
+            let body = match body {
+                Ok(body) => body,
+                Err(rejection) => return reject_json_body(rejection),
+            };
+            let mut wrapper: ::serde_json::Value = match ::serde_json::from_str(&body) {
+                Ok(wrapper) => wrapper,
+                Err(e) => return json_error_response(400, "Client", &format!("Invalid JSON: {}", e)),
+            };
+            let operation = match wrapper.get("operation").and_then(|operation| operation.as_str()) {
+                Some(operation) => operation.to_string(),
+                None => return json_error_response(400, "Client", "Missing \"operation\" string"),
+            };
+            let data = wrapper
+                .get_mut("data")
+                .map(::serde_json::Value::take)
+                .unwrap_or(::serde_json::Value::Null);
+
+            with_request_client(&headers, process_json_request(&operation, data)).await
+        }
+
+        async fn process_json_request(operation: &str, data: ::serde_json::Value) -> axum::response::Response {
+            let started = std::time::Instant::now();
+            let observer = current_observer();
+            observer.on_request(operation);
+
+            match handle_json_request(operation, data).await {
+                Ok((response, headers)) => {
+                    observer.on_success(operation, started.elapsed());
+                    let http_response = axum::response::Response::builder()
+                        .status(200)
+                        .header("Content-Type", "application/json")
+                        .body(axum::body::Body::from(response.to_string()))
+                        .unwrap();
+                    apply_operation_headers(http_response, &headers)
+                }
+                Err((status, code, message)) => {
+                    observer.on_fault(operation, started.elapsed(), code);
+                    json_error_response(status, code, &message)
+                }
+            }
+        }
+
+        async fn handle_json_request(
+            operation: &str,
+            data: ::serde_json::Value,
+        ) -> Result<(::serde_json::Value, axum::http::HeaderMap), (u16, &'static str, String)> {
+            #(#handlers)*
+
+            Err((404, "Client", format!("Unknown operation: {}. Available operations: {}", operation, AVAILABLE_OPERATIONS)))
+        }
+
+        fn reject_json_body(
+            rejection: axum::extract::rejection::StringRejection,
+        ) -> axum::response::Response {
+            let status = rejection.status();
+            let message = if status == axum::http::StatusCode::PAYLOAD_TOO_LARGE {
+                format!("Request body exceeds the maximum of {} bytes", MAX_REQUEST_BYTES)
+            } else {
+                rejection.body_text()
+            };
+
+            current_observer().on_fault("", std::time::Duration::ZERO, "Client");
+            json_error_response(status.as_u16(), "Client", &message)
+        }
+
+        fn json_error_response(status: u16, code: &str, message: &str) -> axum::response::Response {
+            let body = ::serde_json::json!({ "error": { "code": code, "message": message } });
+            axum::response::Response::builder()
+                .status(status)
+                .header("Content-Type", "application/json")
+                .body(axum::body::Body::from(body.to_string()))
+                .unwrap()
+        }
+    };
+    
+    (routes, code)
+}
+
+/// Generates the JSON dispatch branch for a single operation.
+fn generate_json_operation(operation: &SoapOperation) -> TokenStream {
+    let op_name = &operation.name;
+    let func_name = &operation.function_name;
+    let request_type = &operation.request_type;
+    let response_type = &operation.response_type;
+    
+    // Streamed and raw responses only exist as XML
+    if operation.returns_stream || operation.returns_raw_body {
+        let reason = if operation.returns_stream { "streams its response" } else { "returns raw XML" };
+        let message = format!("Operation {} {} and is not available as JSON", op_name, reason);
+        return quote! {
+            if operation == #op_name {
+                let _ = data;
+                return Err((501, "Server", #message.to_string()));
+            }
+        };
+    }
+    
+    let borrow = if operation.borrows_request { quote! { & } } else { quote! {} };
+    
+    let (request_parsing, request_arg) = if is_unit_type(request_type) {
+        let arg = if operation.takes_request { quote! { #borrow () } } else { quote! {} };
+        (quote! { let _ = data; }, arg)
+    } else {
+        let restriction_check = restriction_check(operation, |message| quote! { (400, "Client", #message) });
+        let validate_check = validate_check(operation, |message| quote! { (400, "Client", #message) });
+        let parsing = quote! {
+            let request_data: #request_type = ::serde_json::from_value(data)
+                .map_err(|e| (400, "Client", format!("Failed to parse request: {}", e)))?;
+            #restriction_check
+            #validate_check
+        };
+        (parsing, quote! { #borrow request_data })
+    };
+    
+    let operation_fault = if operation.maps_fault {
+        quote! {
+            |e| {
+                let status = fault_status(SoapFault::http_status(&e)).map_or(500, |status| status.as_u16());
+                (status, "Server", format!("Operation failed: {}", e))
+            }
+        }
+    } else {
+        quote! { |e| (500, "Server", format!("Operation failed: {}", e)) }
+    };
+
+    // JSON requests carry no XML, so a context is built from no headers and a RawXml
+    // parameter gets an empty one
+    let context_construction = context_construction(
+        operation,
+        quote! { &SoapHeaders::default() },
+        |message| quote! { (400, "Client", #message) },
+    );
+    let mut arguments = Vec::new();
+    if !request_arg.is_empty() {
+        arguments.push(request_arg);
+    }
+    if operation.context_type.is_some() {
+        arguments.push(quote! { context });
+    }
+    if operation.takes_raw_xml {
+        arguments.push(quote! { RawXml::default() });
+    }
+
+    let result = await_operation(operation, quote! { #func_name(#(#arguments),*) }, |message| {
+        quote! { (500, "Server", #message) }
+    });
+
+    let call = if operation.returns_headers {
+        quote! {
+            let response: WithHeaders<#response_type> = #result
+                .map_err(#operation_fault)?;
+            let (result, headers) = (response.body, response.headers);
+        }
+    } else {
+        quote! {
+            let result: #response_type = #result
+                .map_err(#operation_fault)?;
+            let headers = axum::http::HeaderMap::new();
+        }
+    };
+    
+    let authorization = match &operation.requires_role {
+        Some(role) => quote! {
+            authorize_role(#op_name, #role).map_err(|fault| (403, "Client", fault.message))?;
+        },
+        None => quote! {},
+    };
+    let rate_limit = rate_limit_check(operation, |message| quote! { (429, "Server", #message) });
+    let all_failed = all_failed_check(operation, |message| quote! { (500, "Server", #message) });
+    
+    quote! {
+        if operation == #op_name {
+            #rate_limit
+            #authorization
+            #context_construction
+            #request_parsing
+            #call
+            #all_failed
+
+            let response = ::serde_json::to_value(&result)
+                .map_err(|e| (500, "Server", format!("Failed to serialize response: {}", e)))?;
+            return Ok((response, headers));
+        }
+    }
+}
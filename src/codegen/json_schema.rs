@@ -0,0 +1,111 @@
+//! Generate a JSON description of the service operations
+
+use crate::codegen::extract_type_name;
+use crate::parser::{is_unit_type, ServiceConfig, SoapOperation, TypeInfo};
+use std::collections::HashMap;
+
+/// Generates the JSON document served at `json_schema_path`.
+/// 
+/// It lists every operation with its SOAP action and the fields of its request and
+/// response types, using the same type analysis as the WSDL. The shape is stable:
+/// `{ service, namespace, endpoint, operations: [{ name, soapAction, namespace, path?, request, response }] }`,
+/// where `path` is only present for operations with their own route, `request` is
+/// `null` for operations without parameters, and each type has
+/// `{ name, fields: [{ name, type, key?, default?, optional, repeated, nillable, attribute }] }`, where
+/// `type` is the item type of repeated fields, and map fields describe their entries
+/// with the `key` type and the value `type`. Fields with a `#[soap(default = "...")]`
+/// carry that value as `default`.
+pub fn generate_json_schema(
+    config: &ServiceConfig,
+    operations: &[SoapOperation],
+    types: &HashMap<String, TypeInfo>,
+) -> String {
+    let operations: Vec<String> = operations
+        .iter()
+        .map(|operation| {
+            let namespace = operation.namespace.as_deref().unwrap_or(&config.namespace);
+            let request = if is_unit_type(&operation.request_type) {
+                "null".to_string()
+            } else {
+                type_json(&extract_type_name(&operation.request_type), types)
+            };
+            let response = type_json(&extract_type_name(&operation.response_type), types);
+            let path = match &operation.path {
+                Some(path) => format!(r#","path":{}"#, json_string(path)),
+                None => String::new(),
+            };
+            
+            format!(
+                r#"{{"name":{},"soapAction":{},"namespace":{}{},"request":{},"response":{}}}"#,
+                json_string(&operation.name),
+                json_string(&format!("{}/{}", config.namespace, operation.name)),
+                json_string(namespace),
+                path,
+                request,
+                response
+            )
+        })
+        .collect();
+    
+    format!(
+        r#"{{"service":{},"namespace":{},"endpoint":{},"operations":[{}]}}"#,
+        json_string(&config.service_name),
+        json_string(&config.namespace),
+        json_string(&config.bind_path),
+        operations.join(",")
+    )
+}
+
+/// Describes a request or response type and its fields.
+fn type_json(type_name: &str, types: &HashMap<String, TypeInfo>) -> String {
+    let type_info = types.get(type_name);
+    let fields: Vec<String> = type_info
+        .map(|type_info| type_info.fields.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .map(|field| {
+            let key = match &field.map_entry {
+                Some((key_type, _)) => format!(r#","key":{}"#, json_string(key_type)),
+                None => String::new(),
+            };
+            let default = match &field.default {
+                Some(default) => format!(r#","default":{}"#, json_string(default)),
+                None => String::new(),
+            };
+            
+            format!(
+                r#"{{"name":{},"type":{}{}{},"optional":{},"repeated":{},"nillable":{},"attribute":{}}}"#,
+                json_string(&field.xml_name),
+                json_string(&field.field_type),
+                key,
+                default,
+                field.optional,
+                field.repeated,
+                field.nillable,
+                field.attribute
+            )
+        })
+        .collect();
+    
+    let name = type_info.map_or(type_name, |type_info| type_info.name.as_str());
+    format!(r#"{{"name":{},"fields":[{}]}}"#, json_string(name), fields.join(","))
+}
+
+/// Encodes a string as a JSON string literal.
+fn json_string(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len() + 2);
+    encoded.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            '\n' => encoded.push_str("\\n"),
+            '\r' => encoded.push_str("\\r"),
+            '\t' => encoded.push_str("\\t"),
+            c if (c as u32) < 0x20 => encoded.push_str(&format!("\\u{:04x}", c as u32)),
+            c => encoded.push(c),
+        }
+    }
+    encoded.push('"');
+    encoded
+}
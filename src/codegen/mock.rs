@@ -0,0 +1,57 @@
+//! Generate the canned responses of mock services
+
+use crate::parser::ServiceConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `set_mock_response` and the lookup of registered responses for `mock = true`.
+/// 
+/// A mock service never calls its operations. Each request is validated against the
+/// schema the WSDL declares, and a valid one is answered with the response XML
+/// registered for its operation, or with the response type's `Default` value when none
+/// is registered. Responses live in memory for the whole process, so tests sharing a
+/// service should register the responses they rely on. Expands to nothing otherwise.
+pub fn generate_mock_support(config: &ServiceConfig) -> TokenStream {
+    if !config.mock {
+        return TokenStream::new();
+    }
+
+    quote! {
+        static MOCK_RESPONSES: std::sync::RwLock<std::collections::BTreeMap<String, String>> =
+            std::sync::RwLock::new(std::collections::BTreeMap::new());
+
+        /// Registers the response element returned by a mock service for an operation.
+        /// 
+        /// `xml` is the `{Operation}Response` element, inserted into the `soap:Body` of the
+        /// response as it is, without being checked. It replaces any response registered
+        /// before for the operation.
+        pub fn set_mock_response(operation: &str, xml: impl Into<String>) {
+            MOCK_RESPONSES.write().unwrap().insert(operation.to_string(), xml.into());
+        }
+
+        fn mock_response(operation: &str) -> Option<String> {
+            MOCK_RESPONSES.read().unwrap().get(operation).cloned()
+        }
+
+        // Requests that do not match the schema are refused before dispatch
+        fn check_mock_request(envelope: &str) -> Result<(), ServiceFault> {
+            check_against_schema(envelope).map_err(|errors| {
+                ServiceFault::client(format!("Request does not match the schema: {}", errors.join("; ")))
+            })
+        }
+    }
+}
+
+/// Returns the dispatch of a parsed request, preceded by the schema check of a mock service.
+pub fn mock_dispatch(config: &ServiceConfig, dispatch: TokenStream) -> TokenStream {
+    if !config.mock {
+        return dispatch;
+    }
+
+    quote! {
+        match check_mock_request(body) {
+            Ok(()) => #dispatch,
+            Err(fault) => Err(fault),
+        }
+    }
+}
@@ -1,3 +1,67 @@
+pub mod attachments;
+pub mod auth;
+pub mod batch;
+pub mod client;
+pub mod conformance;
+pub mod context;
+pub mod encoding;
+pub mod envelope;
+pub mod faults;
+pub mod fields;
+pub mod guards;
+pub mod header_context;
+pub mod headers;
+pub mod introspection;
+pub mod json_api;
+pub mod json_schema;
+pub mod mock;
+pub mod negotiation;
+pub mod nillable;
+pub mod rate_limits;
+pub mod raw_body;
+pub mod reliable_messaging;
+pub mod restrictions;
+pub mod routing;
+pub mod runtime;
+pub mod soap_headers;
+pub mod soap_type;
+pub mod streaming;
+pub mod stylesheet;
+pub mod timeouts;
+pub mod tracing;
+pub mod validation;
 pub mod wsdl;
 
+pub use attachments::*;
+pub use auth::*;
+pub use batch::*;
+pub use client::*;
+pub use conformance::*;
+pub use context::*;
+pub use encoding::*;
+pub use envelope::*;
+pub use faults::*;
+pub use fields::*;
+pub use guards::*;
+pub use header_context::*;
+pub use headers::*;
+pub use introspection::*;
+pub use json_api::*;
+pub use json_schema::*;
+pub use mock::*;
+pub use negotiation::*;
+pub use nillable::*;
+pub use rate_limits::*;
+pub use raw_body::*;
+pub use reliable_messaging::*;
+pub use restrictions::*;
+pub use routing::*;
+pub use runtime::*;
+pub use soap_headers::*;
+pub use soap_type::*;
+pub use streaming::*;
+pub use stylesheet::*;
+pub use timeouts::*;
+pub use tracing::*;
+pub use validation::*;
 pub use wsdl::*;
@@ -0,0 +1,101 @@
+//! Generate content negotiation for SOAP response media types
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `negotiate_content_type` and the helper applying it to a response.
+/// 
+/// The response media type defaults to the one the request was sent with: `text/xml`
+/// for SOAP 1.1, or `application/soap+xml` when the client posted that. An `Accept`
+/// header preferring the other type (by quality value, then specificity) overrides the
+/// default, so clients that only accept `application/soap+xml` are not answered with
+/// `text/xml`. A tie or an `Accept` header matching neither keeps the default.
+pub fn generate_content_negotiation() -> TokenStream {
+    quote! {
+        const SOAP_11_CONTENT_TYPE: &str = "text/xml; charset=utf-8";
+        const SOAP_12_CONTENT_TYPE: &str = "application/soap+xml; charset=utf-8";
+
+        fn negotiate_content_type(headers: &axum::http::HeaderMap) -> &'static str {
+            let requested_soap_12 = headers
+                .get(axum::http::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|content_type| {
+                    content_type
+                        .split(';')
+                        .next()
+                        .unwrap_or_default()
+                        .trim()
+                        .eq_ignore_ascii_case("application/soap+xml")
+                });
+            let (default, alternative) = if requested_soap_12 {
+                (SOAP_12_CONTENT_TYPE, SOAP_11_CONTENT_TYPE)
+            } else {
+                (SOAP_11_CONTENT_TYPE, SOAP_12_CONTENT_TYPE)
+            };
+
+            let accept = match headers.get(axum::http::header::ACCEPT).and_then(|value| value.to_str().ok()) {
+                Some(accept) => accept,
+                None => return default,
+            };
+            let media_type = |content_type: &'static str| content_type.split(';').next().unwrap_or_default();
+            if accept_quality(accept, media_type(alternative)) > accept_quality(accept, media_type(default)) {
+                alternative
+            } else {
+                default
+            }
+        }
+
+        // Returns the quality an Accept header gives a media type, from its most specific
+        // matching range (`type/subtype`, then `type/*`, then `*/*`), or 0 without a match
+        fn accept_quality(accept: &str, media_type: &str) -> (u16, u8) {
+            let (main_type, _) = media_type.split_once('/').unwrap_or((media_type, ""));
+            let mut best: Option<(u8, u16)> = None;
+
+            for range in accept.split(',') {
+                let mut parameters = range.split(';');
+                let range_type = parameters.next().unwrap_or_default().trim();
+                let specificity = if range_type.eq_ignore_ascii_case(media_type) {
+                    2
+                } else if range_type
+                    .strip_suffix("/*")
+                    .is_some_and(|range_main| range_main.eq_ignore_ascii_case(main_type))
+                {
+                    1
+                } else if range_type == "*/*" {
+                    0
+                } else {
+                    continue;
+                };
+
+                let quality = parameters
+                    .filter_map(|parameter| parameter.trim().strip_prefix("q="))
+                    .next()
+                    .and_then(|quality| quality.trim().parse::<f32>().ok())
+                    .map_or(1000, |quality| (quality.clamp(0.0, 1.0) * 1000.0) as u16);
+                if best.map_or(true, |(best_specificity, _)| specificity > best_specificity) {
+                    best = Some((specificity, quality));
+                }
+            }
+
+            match best {
+                Some((specificity, quality)) if quality > 0 => (quality, specificity),
+                _ => (0, 0),
+            }
+        }
+
+        // Gives a SOAP response the negotiated media type; multipart and other bodies are kept
+        fn with_content_type(mut response: axum::response::Response, content_type: &'static str) -> axum::response::Response {
+            let is_soap_11 = response
+                .headers()
+                .get(axum::http::header::CONTENT_TYPE)
+                .is_some_and(|value| value.as_bytes() == SOAP_11_CONTENT_TYPE.as_bytes());
+            if is_soap_11 && content_type != SOAP_11_CONTENT_TYPE {
+                response.headers_mut().insert(
+                    axum::http::header::CONTENT_TYPE,
+                    axum::http::HeaderValue::from_static(content_type),
+                );
+            }
+            response
+        }
+    }
+}
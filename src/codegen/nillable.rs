@@ -0,0 +1,139 @@
+//! Generate `xsi:nil` handling for request payloads
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `strip_nil_elements` and the helpers it uses to find nil elements.
+/// 
+/// serde_xml_rs reads `<Separator xsi:nil="true"/>` as an empty value, which is
+/// `Some("")` for strings and a parse error for other types. Elements inside the
+/// operation element with `xsi:nil="true"` (or `"1"`) are therefore removed before
+/// deserialization, so an `Option` field becomes `None` as if the element was left
+/// out. A nil item of a `Vec<Option<T>>` list is dropped from the list. The `xsi`
+/// prefix is whatever prefix the request binds to the XML Schema instance namespace.
+pub fn generate_nil_handling() -> TokenStream {
+    quote! {
+        const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+        fn strip_nil_elements(body: &str, prefixes: &[String]) -> String {
+            if prefixes.is_empty() || !body.contains(":nil") {
+                return body.to_string();
+            }
+
+            let mut output = String::with_capacity(body.len());
+            let mut rest = body;
+            let mut depth = 0usize;
+            // Depth of the nil element being dropped, whose content goes with it
+            let mut nil_depth = None;
+
+            while let Some(start) = rest.find('<') {
+                let (text, markup) = rest.split_at(start);
+                let end = markup_end(markup);
+                let (tag, remainder) = markup.split_at(end);
+                rest = remainder;
+                if nil_depth.is_none() {
+                    output.push_str(text);
+                }
+
+                if tag.starts_with("</") {
+                    depth = depth.saturating_sub(1);
+                    if nil_depth == Some(depth) {
+                        nil_depth = None;
+                        continue;
+                    }
+                } else if !tag.starts_with("<!") && !tag.starts_with("<?") {
+                    let (_, self_closing) = find_tag_end(tag);
+                    // The operation element itself is never dropped
+                    if nil_depth.is_none() && depth > 0 && is_nil_element(tag, prefixes) {
+                        if !self_closing {
+                            nil_depth = Some(depth);
+                            depth += 1;
+                        }
+                        continue;
+                    }
+                    if !self_closing {
+                        depth += 1;
+                    }
+                }
+
+                if nil_depth.is_none() {
+                    output.push_str(tag);
+                }
+            }
+
+            if nil_depth.is_none() {
+                output.push_str(rest);
+            }
+            output
+        }
+
+        // Returns the length of the markup at the start of `xml`, which may contain `>`
+        // inside comments, CDATA sections, and quoted attribute values
+        fn markup_end(xml: &str) -> usize {
+            for (open, close) in [("<!--", "-->"), ("<![CDATA[", "]]>"), ("<?", "?>")] {
+                if xml.starts_with(open) {
+                    return xml.find(close).map_or(xml.len(), |end| end + close.len());
+                }
+            }
+            find_tag_end(xml).0
+        }
+
+        fn is_nil_element(tag: &str, prefixes: &[String]) -> bool {
+            let mut rest = tag
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .trim_end_matches('/')
+                .trim_start_matches(|c: char| !c.is_whitespace());
+
+            while let Some((name, value)) = rest.split_once('=') {
+                let value = value.trim_start();
+                let quote = match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => quote,
+                    _ => return false,
+                };
+                let end = match value[1..].find(quote) {
+                    Some(end) => end + 1,
+                    None => return false,
+                };
+
+                let is_nil = name.trim().split_once(':').is_some_and(|(prefix, local)| {
+                    local == "nil" && prefixes.iter().any(|xsi| xsi == prefix)
+                });
+                if is_nil && matches!(value[1..end].trim(), "true" | "1") {
+                    return true;
+                }
+                rest = &value[end + 1..];
+            }
+
+            false
+        }
+
+        // Returns the prefixes declared anywhere in the request for the XML Schema
+        // instance namespace
+        fn xsi_prefixes(xml: &str) -> Vec<String> {
+            let mut prefixes = Vec::new();
+            let mut rest = xml;
+
+            while let Some(position) = rest.find("xmlns:") {
+                rest = &rest[position + "xmlns:".len()..];
+                let (prefix, value) = match rest.split_once('=') {
+                    Some(declaration) => declaration,
+                    None => break,
+                };
+                let value = value.trim_start();
+                let quote = match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => quote,
+                    _ => continue,
+                };
+                let declared = value[1..].split(quote).next().unwrap_or_default();
+
+                let prefix = prefix.trim();
+                if declared == XSI_NAMESPACE && !prefixes.iter().any(|known| known == prefix) {
+                    prefixes.push(prefix.to_string());
+                }
+            }
+
+            prefixes
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Generate per-operation rate limiting
+
+use crate::parser::SoapOperation;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Buckets kept before full ones are dropped, bounding memory with per-client limits.
+const MAX_RATE_LIMIT_BUCKETS: usize = 10_000;
+
+/// Generates the token buckets behind `#[operation(rate_limit = "...")]`, and
+/// `with_request_client`, which scopes the client address of a request.
+/// 
+/// Each operation has a bucket holding up to `requests` tokens that refills at
+/// `requests` per period, and a call takes one token. With `rate_limit_per_client`,
+/// the bucket is per client address, read from the first `X-Forwarded-For` entry;
+/// requests without the header share one bucket. The buckets live in memory, so
+/// every process counts on its own. Without rate limits, both are no-ops.
+pub fn generate_rate_limiter(operations: &[SoapOperation]) -> TokenStream {
+    let untracked_client = quote! {
+        async fn with_request_client<F>(_headers: &axum::http::HeaderMap, future: F) -> F::Output
+        where
+            F: std::future::Future,
+        {
+            future.await
+        }
+    };
+    let rate_limits: Vec<_> = operations.iter().filter_map(|operation| operation.rate_limit).collect();
+    if rate_limits.is_empty() {
+        return untracked_client;
+    }
+
+    // The client address is only tracked when some limit is counted per client
+    let client_scope = if rate_limits.iter().any(|rate_limit| rate_limit.per_client) {
+        quote! {
+            tokio::task_local! {
+                static REQUEST_CLIENT: String;
+            }
+
+            fn request_client() -> String {
+                REQUEST_CLIENT.try_with(|client| client.clone()).unwrap_or_default()
+            }
+
+            async fn with_request_client<F>(headers: &axum::http::HeaderMap, future: F) -> F::Output
+            where
+                F: std::future::Future,
+            {
+                let client = headers
+                    .get("X-Forwarded-For")
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.split(',').next())
+                    .map(|client| client.trim().to_string())
+                    .unwrap_or_default();
+                REQUEST_CLIENT.scope(client, future).await
+            }
+        }
+    } else {
+        untracked_client
+    };
+
+    quote! {
+        const MAX_RATE_LIMIT_BUCKETS: usize = #MAX_RATE_LIMIT_BUCKETS;
+
+        struct TokenBucket {
+            tokens: f64,
+            capacity: f64,
+            // Tokens added per second
+            refill: f64,
+            updated: std::time::Instant,
+        }
+
+        impl TokenBucket {
+            fn refilled(&self, now: std::time::Instant) -> f64 {
+                let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+                (self.tokens + elapsed * self.refill).min(self.capacity)
+            }
+        }
+
+        // Buckets per operation and client address, empty when not counted per client
+        static RATE_LIMIT_BUCKETS: std::sync::Mutex<std::collections::BTreeMap<(&'static str, String), TokenBucket>> =
+            std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+        /// Takes a token from the bucket of the operation and client, or returns `false`
+        /// when it is empty.
+        fn take_rate_limit_token(operation: &'static str, client: String, requests: u32, period_secs: u64) -> bool {
+            let now = std::time::Instant::now();
+            let mut buckets = RATE_LIMIT_BUCKETS.lock().unwrap_or_else(|e| e.into_inner());
+            // A full bucket is the same as a new one, so it can be dropped
+            if buckets.len() >= MAX_RATE_LIMIT_BUCKETS {
+                buckets.retain(|_, bucket| bucket.refilled(now) < bucket.capacity);
+            }
+
+            let capacity = f64::from(requests);
+            let bucket = buckets.entry((operation, client)).or_insert_with(|| TokenBucket {
+                tokens: capacity,
+                capacity,
+                refill: capacity / period_secs as f64,
+                updated: now,
+            });
+            bucket.tokens = bucket.refilled(now);
+            bucket.updated = now;
+            if bucket.tokens < 1.0 {
+                return false;
+            }
+            bucket.tokens -= 1.0;
+            true
+        }
+
+        #client_scope
+    }
+}
+
+/// Returns the check taking a token for the operation before the call.
+/// 
+/// Expands to nothing unless the operation has a `rate_limit`. `fault` builds the error
+/// value from the message expression, since the SOAP and JSON dispatchers report faults
+/// differently.
+pub fn rate_limit_check(
+    operation: &SoapOperation,
+    fault: impl FnOnce(TokenStream) -> TokenStream,
+) -> TokenStream {
+    let rate_limit = match operation.rate_limit {
+        Some(rate_limit) => rate_limit,
+        None => return TokenStream::new(),
+    };
+
+    let op_name = &operation.name;
+    let requests = rate_limit.requests;
+    let period_secs = rate_limit.period_secs;
+    let unit = match period_secs {
+        1 => "second",
+        60 => "minute",
+        _ => "hour",
+    };
+    let noun = if requests == 1 { "request" } else { "requests" };
+    let message = format!("Operation {} is limited to {} {} per {}", op_name, requests, noun, unit);
+    let client = if rate_limit.per_client {
+        quote! { request_client() }
+    } else {
+        quote! { String::new() }
+    };
+    let error = fault(quote! { #message.to_string() });
+    quote! {
+        if !take_rate_limit_token(#op_name, #client, #requests, #period_secs) {
+            return Err(#error);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Generate support for requests and responses as raw XML
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `RawSoapBody` trait and the `RawXml` parameter type.
+/// 
+/// Operations whose response type implements the trait in the service module skip
+/// serialization: the XML it returns becomes the content of `soap:Body` as it is.
+/// This is an escape hatch for responses serde cannot produce faithfully. Operations
+/// taking a trailing `RawXml` parameter get the request's operation element as it was
+/// sent, e.g. to verify a signature over it, alongside the parsed request.
+pub fn generate_raw_body_support() -> TokenStream {
+    quote! {
+        /// The operation element of the request as it was sent, passed to operations
+        /// declaring it as their last parameter.
+        /// 
+        /// It is the slice of the `soap:Body` content before any parsing: entities are not
+        /// decoded and `xsi:nil` elements are still present. Requests on the JSON API carry
+        /// no XML, so it is empty there.
+        #[derive(Debug, Clone, Default, PartialEq, Eq)]
+        pub struct RawXml(pub String);
+
+        /// Implemented by response types that provide their own serialized body XML.
+        /// 
+        /// The XML replaces the `{Operation}Response` element the type would otherwise be
+        /// serialized to and is inserted verbatim: it is not escaped, checked, or indented.
+        /// The implementation is responsible for well-formed XML matching the WSDL.
+        pub trait RawSoapBody {
+            fn body_xml(&self) -> std::borrow::Cow<'_, str>;
+        }
+    }
+}
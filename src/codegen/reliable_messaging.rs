@@ -0,0 +1,147 @@
+//! Generate WS-ReliableMessaging sequence acknowledgements
+
+use crate::parser::ServiceConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The WS-RM 1.0 (2005/02) and 1.1 namespaces whose headers are acknowledged.
+pub const WS_RM_NAMESPACES: &[&str] = &[
+    "http://schemas.xmlsoap.org/ws/2005/02/rm",
+    "http://docs.oasis-open.org/ws-rx/wsrm/200702",
+];
+
+/// The WS-RM header entries that are understood with `ws_rm`.
+pub const WS_RM_HEADERS: &[&str] = &["Sequence", "SequenceAcknowledgement", "AckRequested"];
+
+/// Generates `acknowledge_rm_sequence` and `add_rm_acknowledgement`.
+///
+/// With `ws_rm`, the `Identifier` and `MessageNumber` of a `wsrm:Sequence` header are
+/// recorded in memory, and the response envelope gets a `wsrm:SequenceAcknowledgement`
+/// header listing the ranges received so far, in the namespace the client used. Incoming
+/// acknowledgements and ack requests are accepted and ignored. Sequence lifecycle
+/// messages, persistence, and guaranteed delivery are out of scope. Without `ws_rm`,
+/// both functions leave the envelope untouched.
+pub fn generate_reliable_messaging(config: &ServiceConfig) -> TokenStream {
+    if !config.ws_rm {
+        return quote! {
+            fn acknowledge_rm_sequence(_xml: &str) -> Option<String> {
+                None
+            }
+
+            fn add_rm_acknowledgement(envelope: String, _acknowledgement: &Option<String>) -> String {
+                envelope
+            }
+        };
+    }
+
+    let namespaces = WS_RM_NAMESPACES;
+
+    quote! {
+        const WS_RM_NAMESPACES: &[&str] = &[#(#namespaces),*];
+
+        // Received message numbers per sequence identifier, as merged inclusive ranges
+        static RM_SEQUENCES: std::sync::Mutex<std::collections::BTreeMap<String, Vec<(u64, u64)>>> =
+            std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+        struct RmSequence {
+            namespace: String,
+            identifier: String,
+            message_number: u64,
+        }
+
+        /// Records the message of a `wsrm:Sequence` header and returns the acknowledgement
+        /// header entry for the response, or `None` when the request is not in a sequence.
+        fn acknowledge_rm_sequence(xml: &str) -> Option<String> {
+            let sequence = read_rm_sequence(strip_leading_noise(xml))?;
+
+            let mut sequences = RM_SEQUENCES.lock().unwrap_or_else(|e| e.into_inner());
+            let ranges = sequences.entry(sequence.identifier.clone()).or_default();
+            let number = sequence.message_number;
+            if !ranges.iter().any(|(lower, upper)| (*lower..=*upper).contains(&number)) {
+                ranges.push((number, number));
+                ranges.sort_unstable();
+                let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+                for &(lower, upper) in ranges.iter() {
+                    match merged.last_mut() {
+                        Some(last) if lower <= last.1.saturating_add(1) => last.1 = last.1.max(upper),
+                        _ => merged.push((lower, upper)),
+                    }
+                }
+                *ranges = merged;
+            }
+
+            let acknowledged: String = ranges
+                .iter()
+                .map(|(lower, upper)| format!(r#"<wsrm:AcknowledgementRange Upper="{}" Lower="{}"/>"#, upper, lower))
+                .collect();
+            Some(format!(
+                r#"<wsrm:SequenceAcknowledgement xmlns:wsrm="{}"><wsrm:Identifier>{}</wsrm:Identifier>{}</wsrm:SequenceAcknowledgement>"#,
+                sequence.namespace,
+                escape_fault_text(&sequence.identifier),
+                acknowledged
+            ))
+        }
+
+        // Reads the Identifier and MessageNumber of the wsrm:Sequence header entry
+        fn read_rm_sequence(xml: &str) -> Option<RmSequence> {
+            let mut reader = ::quick_xml::reader::NsReader::from_str(xml);
+            let mut path: Vec<String> = Vec::new();
+            let mut namespace = None;
+            let mut identifier = None;
+            let mut message_number = None;
+
+            loop {
+                match reader.read_resolved_event().ok()? {
+                    (resolved, ::quick_xml::events::Event::Start(start)) => {
+                        let local_name = String::from_utf8_lossy(start.local_name().as_ref()).into_owned();
+                        // The header precedes the body, so there is nothing left to read
+                        if path.len() == 1 && local_name == "Body" {
+                            break;
+                        }
+                        if let ::quick_xml::name::ResolveResult::Bound(bound) = resolved {
+                            let bound = String::from_utf8_lossy(bound.as_ref()).into_owned();
+                            if path.len() == 2
+                                && path[1] == "Header"
+                                && local_name == "Sequence"
+                                && WS_RM_NAMESPACES.contains(&bound.as_str())
+                            {
+                                namespace = Some(bound);
+                            }
+                        }
+                        path.push(local_name);
+                    }
+                    (_, ::quick_xml::events::Event::Text(text)) if path.len() == 4 && namespace.is_some() => {
+                        let text = text.unescape().ok()?.trim().to_string();
+                        match path[3].as_str() {
+                            "Identifier" => identifier = Some(text),
+                            "MessageNumber" => message_number = text.parse().ok(),
+                            _ => {}
+                        }
+                    }
+                    (_, ::quick_xml::events::Event::End(_)) => {
+                        path.pop();
+                        if path.len() == 2 && namespace.is_some() {
+                            break;
+                        }
+                    }
+                    (_, ::quick_xml::events::Event::Eof) => break,
+                    _ => {}
+                }
+            }
+
+            Some(RmSequence {
+                namespace: namespace?,
+                identifier: identifier?,
+                message_number: message_number?,
+            })
+        }
+
+        // Adds the acknowledgement as a header of an envelope written by this service
+        fn add_rm_acknowledgement(envelope: String, acknowledgement: &Option<String>) -> String {
+            match acknowledgement {
+                Some(acknowledgement) => add_header_entries(envelope, acknowledgement),
+                None => envelope,
+            }
+        }
+    }
+}
@@ -0,0 +1,367 @@
+//! Generate runtime checks of the XSD restriction facets
+
+use crate::parser::{
+    field_value_type, field_xml_name, flattened_struct, has_serde_key, option_inner_type, parse_pattern,
+    parse_soap_field_attributes, restricted_struct_names, vec_inner_type, PatternChars,
+    PatternNode, ServiceConfig, SoapOperation,
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Field, Item, ItemMod, Result};
+
+/// Generates the `CheckRestrictions` implementations enforcing the restriction facets.
+/// 
+/// With `validate_restrictions`, every module struct with `pattern`, `min_length`,
+/// `max_length`, `min`, or `max` facets (or holding such a struct) checks each of its
+/// values against them, and reports the first violation naming the element and the facet.
+/// Patterns are compiled to a small automaton at expansion, so matching takes linear time.
+/// Must run before the struct rewrite strips the `#[soap(...)]` attributes.
+pub fn generate_restriction_checks(config: &ServiceConfig, module: &ItemMod) -> Result<TokenStream> {
+    if !config.validate_restrictions {
+        return Ok(TokenStream::new());
+    }
+
+    let restricted = restricted_struct_names(module)?;
+    let mut impls = Vec::new();
+    let mut uses_patterns = false;
+    let mut uses_bounds = false;
+
+    for item in module.content.iter().flat_map(|(_, items)| items) {
+        let item_struct = match item {
+            Item::Struct(item_struct) if restricted.contains(&item_struct.ident.to_string()) => item_struct,
+            _ => continue,
+        };
+
+        let mut checks = Vec::new();
+        for field in &item_struct.fields {
+            if has_serde_key(field, "skip") {
+                continue;
+            }
+            let facets = parse_soap_field_attributes(field)?.facets;
+            let values = field_values(field);
+            let name = field_xml_name(field);
+            let violation = |facet: &str, value: &dyn std::fmt::Display| {
+                let message = format!("{} violates {} {}", name, facet, value);
+                quote! { return Err(#message.to_string()); }
+            };
+
+            let mut value_checks = Vec::new();
+            if let Some(pattern) = &facets.pattern {
+                uses_patterns = true;
+                let program = compile_pattern(pattern);
+                let error = violation("pattern", pattern);
+                value_checks.push(quote! {
+                    const PATTERN: &[XsdPatternInstruction] = &[#(#program),*];
+                    if !xsd_pattern_matches(PATTERN, value) {
+                        #error
+                    }
+                });
+            }
+            if let Some(min_length) = facets.min_length {
+                let error = violation("minLength", &min_length);
+                value_checks.push(quote! {
+                    if value.chars().count() < #min_length {
+                        #error
+                    }
+                });
+            }
+            if let Some(max_length) = facets.max_length {
+                let error = violation("maxLength", &max_length);
+                value_checks.push(quote! {
+                    if value.chars().count() > #max_length {
+                        #error
+                    }
+                });
+            }
+            if let Some(min) = &facets.min {
+                uses_bounds = true;
+                let error = violation("minInclusive", min);
+                value_checks.push(quote! {
+                    if xsd_bound_exceeded(value, #min, std::cmp::Ordering::Less) {
+                        #error
+                    }
+                });
+            }
+            if let Some(max) = &facets.max {
+                uses_bounds = true;
+                let error = violation("maxInclusive", max);
+                value_checks.push(quote! {
+                    if xsd_bound_exceeded(value, #max, std::cmp::Ordering::Greater) {
+                        #error
+                    }
+                });
+            }
+
+            let nested = field_value_type(&field.ty);
+            if flattened_struct(nested, module).is_some_and(|nested| restricted.contains(&nested.ident.to_string())) {
+                // Method syntax derefs through a Box or Arc around the nested struct
+                value_checks.push(quote! { value.check_restrictions()?; });
+            }
+
+            if !value_checks.is_empty() {
+                checks.push(quote! {
+                    for value in #values {
+                        #(#value_checks)*
+                    }
+                });
+            }
+        }
+
+        let ident = &item_struct.ident;
+        impls.push(quote! {
+            impl CheckRestrictions for super::#ident {
+                fn check_restrictions(&self) -> Result<(), String> {
+                    #(#checks)*
+                    Ok(())
+                }
+            }
+        });
+    }
+
+    if impls.is_empty() {
+        return Ok(TokenStream::new());
+    }
+
+    let pattern_matcher = if uses_patterns { generate_pattern_matcher() } else { TokenStream::new() };
+    let bound_check = if uses_bounds {
+        quote! {
+            // Bounds were validated for the built-in types at expansion
+            fn xsd_bound_exceeded<T>(value: &T, bound: &str, exceeded: std::cmp::Ordering) -> bool
+            where
+                T: PartialOrd + std::str::FromStr,
+            {
+                bound
+                    .parse::<T>()
+                    .is_ok_and(|bound| value.partial_cmp(&bound) == Some(exceeded))
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    Ok(quote! {
+        /// Checks a request value against the restriction facets of its fields.
+        trait CheckRestrictions {
+            fn check_restrictions(&self) -> Result<(), String>;
+        }
+
+        #(#impls)*
+
+        #pattern_matcher
+
+        #bound_check
+    })
+}
+
+/// Returns the check of the parsed request against its restriction facets.
+/// 
+/// Expands to nothing unless the operation's request has facets and
+/// `validate_restrictions` is set. `fault` builds the error value from the message
+/// expression, since the SOAP and JSON dispatchers report faults differently.
+pub fn restriction_check(
+    operation: &SoapOperation,
+    fault: impl FnOnce(TokenStream) -> TokenStream,
+) -> TokenStream {
+    if !operation.checks_restrictions {
+        return TokenStream::new();
+    }
+
+    let error = fault(quote! { message });
+    quote! {
+        if let Err(message) = CheckRestrictions::check_restrictions(&request_data) {
+            return Err(#error);
+        }
+    }
+}
+
+/// Builds an iterator over references to the values of a field, looking through its
+/// `Option` and `Vec` wrappers.
+fn field_values(field: &Field) -> TokenStream {
+    let ident = &field.ident;
+    let mut ty = &field.ty;
+    let mut depth = 0;
+    while let Some(inner) = option_inner_type(ty).or_else(|| vec_inner_type(ty)) {
+        ty = inner;
+        depth += 1;
+    }
+
+    match depth {
+        0 => quote! { std::iter::once(&self.#ident) },
+        depth => {
+            let flattens = (1..depth).map(|_| quote! { .flatten() });
+            quote! { self.#ident.iter() #(#flattens)* }
+        }
+    }
+}
+
+/// A step of a compiled pattern.
+enum Instruction {
+    Chars(Vec<PatternChars>, bool),
+    Split(usize, usize),
+    Jump(usize),
+    Match,
+}
+
+/// Compiles a validated pattern to the instructions of a Thompson automaton, as tokens.
+fn compile_pattern(pattern: &str) -> Vec<TokenStream> {
+    let node = parse_pattern(pattern).expect("patterns are validated when parsing the attributes");
+    let mut program = Vec::new();
+    compile_node(&node, &mut program);
+    program.push(Instruction::Match);
+
+    program.iter().map(instruction_tokens).collect()
+}
+
+fn compile_node(node: &PatternNode, program: &mut Vec<Instruction>) {
+    match node {
+        PatternNode::Class(sets, negated) => program.push(Instruction::Chars(sets.clone(), *negated)),
+        PatternNode::Group(branches) => {
+            let mut jumps = Vec::new();
+            for (index, branch) in branches.iter().enumerate() {
+                let split = program.len();
+                let last = index + 1 == branches.len();
+                if !last {
+                    program.push(Instruction::Split(split + 1, 0));
+                }
+                branch.iter().for_each(|node| compile_node(node, program));
+                if !last {
+                    jumps.push(program.len());
+                    program.push(Instruction::Jump(0));
+                    program[split] = Instruction::Split(split + 1, program.len());
+                }
+            }
+            let end = program.len();
+            for jump in jumps {
+                program[jump] = Instruction::Jump(end);
+            }
+        }
+        PatternNode::Repeat(node, min, max) => {
+            for _ in 0..*min {
+                compile_node(node, program);
+            }
+            match max {
+                None => {
+                    let split = program.len();
+                    program.push(Instruction::Split(split + 1, 0));
+                    compile_node(node, program);
+                    program.push(Instruction::Jump(split));
+                    program[split] = Instruction::Split(split + 1, program.len());
+                }
+                Some(max) => {
+                    let mut splits = Vec::new();
+                    for _ in *min..*max {
+                        splits.push(program.len());
+                        program.push(Instruction::Split(program.len() + 1, 0));
+                        compile_node(node, program);
+                    }
+                    let end = program.len();
+                    for split in splits {
+                        program[split] = Instruction::Split(split + 1, end);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn instruction_tokens(instruction: &Instruction) -> TokenStream {
+    match instruction {
+        Instruction::Chars(sets, negated) => {
+            let sets = sets.iter().map(chars_tokens);
+            quote! { XsdPatternInstruction::Chars(&[#(#sets),*], #negated) }
+        }
+        Instruction::Split(first, second) => quote! { XsdPatternInstruction::Split(#first, #second) },
+        Instruction::Jump(target) => quote! { XsdPatternInstruction::Jump(#target) },
+        Instruction::Match => quote! { XsdPatternInstruction::Match },
+    }
+}
+
+fn chars_tokens(chars: &PatternChars) -> TokenStream {
+    match chars {
+        PatternChars::Range(start, end) => quote! { XsdPatternChars::Range(#start, #end) },
+        PatternChars::Not(chars) => {
+            let chars = chars_tokens(chars);
+            quote! { XsdPatternChars::Not(&#chars) }
+        }
+        set => {
+            let variant = format_ident!("{}", format!("{:?}", set));
+            quote! { XsdPatternChars::#variant }
+        }
+    }
+}
+
+/// Generates the automaton running compiled patterns against values.
+fn generate_pattern_matcher() -> TokenStream {
+    quote! {
+        enum XsdPatternChars {
+            Range(char, char),
+            Digit,
+            Space,
+            Word,
+            NameStart,
+            NameChar,
+            Not(&'static XsdPatternChars),
+        }
+
+        impl XsdPatternChars {
+            fn contains(&self, c: char) -> bool {
+                match self {
+                    XsdPatternChars::Range(start, end) => (*start..=*end).contains(&c),
+                    XsdPatternChars::Digit => c.is_numeric(),
+                    XsdPatternChars::Space => matches!(c, ' ' | '\t' | '\n' | '\r'),
+                    // Everything but punctuation, separators, and control characters
+                    XsdPatternChars::Word => {
+                        !(c.is_whitespace() || c.is_control() || "!\"#%&'()*,-./:;?@[\\]_{}".contains(c))
+                    }
+                    XsdPatternChars::NameStart => c.is_alphabetic() || matches!(c, '_' | ':'),
+                    XsdPatternChars::NameChar => c.is_alphanumeric() || matches!(c, '_' | ':' | '-' | '.'),
+                    XsdPatternChars::Not(chars) => !chars.contains(c),
+                }
+            }
+        }
+
+        enum XsdPatternInstruction {
+            Chars(&'static [XsdPatternChars], bool),
+            Split(usize, usize),
+            Jump(usize),
+            Match,
+        }
+
+        // Runs all paths through the automaton at once, so matching is linear in the value
+        fn xsd_pattern_matches(program: &[XsdPatternInstruction], value: &str) -> bool {
+            fn add_state(program: &[XsdPatternInstruction], states: &mut Vec<usize>, seen: &mut [bool], pc: usize) {
+                if std::mem::replace(&mut seen[pc], true) {
+                    return;
+                }
+                match program[pc] {
+                    XsdPatternInstruction::Split(first, second) => {
+                        add_state(program, states, seen, first);
+                        add_state(program, states, seen, second);
+                    }
+                    XsdPatternInstruction::Jump(target) => add_state(program, states, seen, target),
+                    _ => states.push(pc),
+                }
+            }
+
+            let mut states = Vec::new();
+            add_state(program, &mut states, &mut vec![false; program.len()], 0);
+            for c in value.chars() {
+                let mut next = Vec::new();
+                let mut seen = vec![false; program.len()];
+                for &pc in &states {
+                    if let XsdPatternInstruction::Chars(sets, negated) = &program[pc] {
+                        if sets.iter().any(|set| set.contains(c)) != *negated {
+                            add_state(program, &mut next, &mut seen, pc + 1);
+                        }
+                    }
+                }
+                if next.is_empty() {
+                    return false;
+                }
+                states = next;
+            }
+
+            states.iter().any(|&pc| matches!(program[pc], XsdPatternInstruction::Match))
+        }
+    }
+}
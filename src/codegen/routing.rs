@@ -0,0 +1,82 @@
+//! Generate the HTTP handlers that hand SOAP request bodies to the dispatcher
+
+use crate::codegen::{generate_correlation_ids, instrument_request, measure_request_body};
+use crate::parser::SoapOperation;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// Returns the name of the handler for an operation's own `#[operation(path = "...")]` route.
+pub fn operation_route_handler(operation: &SoapOperation) -> Ident {
+    format_ident!("soap_{}_handler", operation.function_name)
+}
+
+/// Generates the `soap_handler` entry point for the SOAP route, plus one handler per
+/// operation with its own path.
+/// 
+/// The handlers decode the body by its charset, negotiate the response media type from
+/// the request headers, and delegate to `process_soap_request`, inside a request span
+/// with the `tracing` feature.
+pub fn generate_soap_handler_entry(operations: &[SoapOperation]) -> TokenStream {
+    let route_handlers = operations
+        .iter()
+        .filter(|operation| operation.path.is_some())
+        .map(|operation| {
+            let handler = operation_route_handler(operation);
+            let op_name = &operation.name;
+            let function = operation.function_name.to_string();
+            quote! {
+                async fn #handler(
+                    headers: axum::http::HeaderMap,
+                    body: Result<axum::body::Bytes, axum::extract::rejection::BytesRejection>,
+                ) -> axum::response::Response {
+                    let route = OperationRoute { operation: #op_name, function: #function };
+                    let content_type = negotiate_content_type(&headers);
+                    with_content_type(serve_soap_body(headers, body, Some(route)).await, content_type)
+                }
+            }
+        });
+
+    let body_size = measure_request_body();
+    let serve_request =
+        instrument_request(quote! { with_request_client(&headers, process_soap_request(&body, route)) });
+    let correlation_ids = generate_correlation_ids();
+
+    quote! {
+        async fn soap_handler(
+            headers: axum::http::HeaderMap,
+            body: Result<axum::body::Bytes, axum::extract::rejection::BytesRejection>,
+        ) -> axum::response::Response {
+            let content_type = negotiate_content_type(&headers);
+            with_content_type(serve_soap_body(headers, body, None).await, content_type)
+        }
+
+        #(#route_handlers)*
+
+        /// An operation route that dispatches without looking at the body element name.
+        #[derive(Debug, Clone, Copy)]
+        struct OperationRoute {
+            operation: &'static str,
+            function: &'static str,
+        }
+
+        async fn serve_soap_body(
+            headers: axum::http::HeaderMap,
+            body: Result<axum::body::Bytes, axum::extract::rejection::BytesRejection>,
+            route: Option<OperationRoute>,
+        ) -> axum::response::Response {
+            let body = match body {
+                Ok(body) => body,
+                Err(rejection) => return reject_request_body(rejection),
+            };
+            #body_size
+            let body = match decode_request_body(&body, request_charset(&headers)) {
+                Ok(body) => body,
+                Err(fault) => return reject_request(fault),
+            };
+
+            #serve_request
+        }
+
+        #correlation_ids
+    }
+}
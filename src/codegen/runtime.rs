@@ -0,0 +1,81 @@
+//! Scope generated runtime code to a private submodule
+
+use crate::parser::SoapOperation;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::visit_mut::VisitMut;
+
+/// Name of the submodule holding the generated runtime code.
+const RUNTIME_MODULE: &str = "__soap_runtime";
+
+/// Wraps the generated runtime code in a private submodule of the service module.
+/// 
+/// Helpers such as `parse_soap_envelope` or `create_soap_fault` then cannot clash with
+/// items the user defines in the module. The submodule sees the user's items through
+/// `use super::*`, and its public items (`router`, `register`, `FaultCode`, the
+/// response traits, ...) are re-exported into the service module.
+pub fn generate_runtime_module(code: TokenStream) -> TokenStream {
+    let module = format_ident!("{}", RUNTIME_MODULE);
+    
+    quote! {
+        pub use self::#module::*;
+
+        #[doc(hidden)]
+        mod #module {
+            use super::*;
+
+            #code
+        }
+    }
+}
+
+/// Returns the path of a runtime helper as seen from the service module.
+pub fn runtime_path(name: &str) -> String {
+    format!("{}::{}", RUNTIME_MODULE, name)
+}
+
+/// Rewrites the operation types so they resolve from inside the runtime module.
+/// 
+/// Paths starting with `self::` or `super::` were written relative to the service
+/// module and gain one `super::` level; everything else resolves through the glob import.
+/// Named lifetimes of a response borrowing from the request (`EchoResponse<'a>`) are
+/// elided, since the dispatcher only names the type for a local binding.
+pub fn relocate_operations(operations: &[SoapOperation]) -> Vec<SoapOperation> {
+    operations
+        .iter()
+        .map(|operation| {
+            let mut operation = operation.clone();
+            RelocatePaths.visit_type_mut(&mut operation.request_type);
+            RelocatePaths.visit_type_mut(&mut operation.response_type);
+            ElideLifetimes.visit_type_mut(&mut operation.response_type);
+            operation
+        })
+        .collect()
+}
+
+struct RelocatePaths;
+
+impl VisitMut for RelocatePaths {
+    fn visit_path_mut(&mut self, path: &mut syn::Path) {
+        if path.leading_colon.is_none() {
+            if let Some(first) = path.segments.first_mut() {
+                if first.ident == "self" {
+                    first.ident = format_ident!("super");
+                } else if first.ident == "super" {
+                    path.segments.insert(0, syn::PathSegment::from(format_ident!("super")));
+                }
+            }
+        }
+        syn::visit_mut::visit_path_mut(self, path);
+    }
+}
+
+struct ElideLifetimes;
+
+impl VisitMut for ElideLifetimes {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut syn::Lifetime) {
+        if lifetime.ident != "static" {
+            *lifetime = syn::Lifetime::new("'_", lifetime.span());
+        }
+    }
+}
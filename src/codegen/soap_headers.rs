@@ -0,0 +1,253 @@
+//! Generate SOAP header processing for incoming and outgoing envelopes
+
+use crate::codegen::{WS_RM_HEADERS, WS_RM_NAMESPACES};
+use crate::parser::ServiceConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `check_must_understand`, which faults on mandatory headers the service
+/// does not understand.
+/// 
+/// A header entry with `mustUnderstand="1"` addressed to this node (no `actor`, the
+/// `next` actor, the SOAP 1.2 `next` or `ultimateReceiver` role, or the configured
+/// `fault_actor`) must be listed in `understood_headers`,
+/// either by local name or as `{namespace}LocalName`, or be one of the WS-RM headers
+/// acknowledged with `ws_rm`. Otherwise the request is answered
+/// with a `MustUnderstand` fault instead of being dispatched, as SOAP 1.1 requires.
+pub fn generate_soap_header_checks(config: &ServiceConfig) -> TokenStream {
+    let mut understood_headers = config.understood_headers.clone();
+    if config.ws_rm {
+        for namespace in WS_RM_NAMESPACES {
+            for local_name in WS_RM_HEADERS {
+                understood_headers.push((Some(namespace.to_string()), local_name.to_string()));
+            }
+        }
+    }
+    let understood = understood_headers.iter().map(|(namespace, local_name)| {
+        let namespace = match namespace {
+            Some(namespace) => quote! { Some(#namespace) },
+            None => quote! { None },
+        };
+        quote! { (#namespace, #local_name) }
+    });
+    let fault_actor = match &config.fault_actor {
+        Some(fault_actor) => quote! { Some(#fault_actor) },
+        None => quote! { None },
+    };
+    let header_start_patterns = envelope_tags(config, "Header", false);
+
+    quote! {
+        const UNDERSTOOD_HEADERS: &[(Option<&str>, &str)] = &[#(#understood),*];
+        const SERVICE_ACTOR: Option<&str> = #fault_actor;
+        const NEXT_ACTOR: &str = "http://schemas.xmlsoap.org/soap/actor/next";
+        const SOAP_12_ROLES: &[&str] = &[
+            "http://www.w3.org/2003/05/soap-envelope/role/next",
+            "http://www.w3.org/2003/05/soap-envelope/role/ultimateReceiver",
+        ];
+
+        fn check_must_understand(xml: &str) -> Result<(), ServiceFault> {
+            let header_start_patterns = [#(#header_start_patterns),*];
+            let (header_start, tag_len) = match header_start_patterns
+                .iter()
+                .find_map(|pattern| xml.find(pattern).map(|pos| (pos, pattern.len())))
+            {
+                Some(found) => found,
+                None => return Ok(()),
+            };
+            let envelope_head = &xml[..header_start];
+            let mut rest = &xml[header_start + tag_len..];
+            let mut depth = 0usize;
+
+            // Only the direct children of the Header element are header entries
+            while let Some(start) = rest.find('<') {
+                rest = &rest[start..];
+
+                let skipped = [("<!--", "-->"), ("<![CDATA[", "]]>"), ("<?", "?>")]
+                    .iter()
+                    .find(|(open, _)| rest.starts_with(open))
+                    .map(|(open, close)| match rest[open.len()..].find(close) {
+                        Some(end) => open.len() + end + close.len(),
+                        None => rest.len(),
+                    });
+                if let Some(length) = skipped {
+                    rest = &rest[length..];
+                    continue;
+                }
+
+                let (tag_end, self_closing) = find_tag_end(rest);
+                if rest.starts_with("</") {
+                    if depth == 0 {
+                        break;
+                    }
+                    depth -= 1;
+                } else {
+                    if depth == 0 {
+                        let start_tag = rest[1..tag_end].trim_end_matches('>').trim_end_matches('/');
+                        check_header_entry(envelope_head, start_tag)?;
+                    }
+                    if !self_closing {
+                        depth += 1;
+                    }
+                }
+                rest = &rest[tag_end..];
+            }
+
+            Ok(())
+        }
+
+        fn check_header_entry(envelope_head: &str, start_tag: &str) -> Result<(), ServiceFault> {
+            let mandatory = matches!(header_attribute(start_tag, "mustUnderstand").as_deref(), Some("1" | "true"));
+            if !mandatory {
+                return Ok(());
+            }
+
+            // Entries addressed to another node are not processed here (SOAP 1.2 calls actors roles)
+            if let Some(actor) = header_attribute(start_tag, "actor").or_else(|| header_attribute(start_tag, "role")) {
+                if actor != NEXT_ACTOR && !SOAP_12_ROLES.contains(&actor.as_str()) && Some(actor.as_str()) != SERVICE_ACTOR {
+                    return Ok(());
+                }
+            }
+
+            let name = start_tag.split_whitespace().next().unwrap_or_default();
+            let (declaration, local_name) = match name.split_once(':') {
+                Some((prefix, local_name)) => (format!("xmlns:{}=", prefix), local_name),
+                None => ("xmlns=".to_string(), name),
+            };
+            let namespace = find_namespace_declaration(start_tag, &declaration, false)
+                .or_else(|| find_namespace_declaration(envelope_head, &declaration, true));
+
+            let understood = UNDERSTOOD_HEADERS.iter().any(|(understood_namespace, understood_name)| {
+                *understood_name == local_name
+                    && understood_namespace.map_or(true, |understood_namespace| {
+                        namespace.as_deref() == Some(understood_namespace)
+                    })
+            });
+            if understood {
+                return Ok(());
+            }
+
+            Err(ServiceFault::new(
+                FaultCode::MustUnderstand,
+                format!("Header {} must be understood but is not supported by this service", name),
+            ))
+        }
+
+        // Returns the value of an attribute by local name, ignoring its prefix (but not
+        // namespace declarations that happen to bind a prefix of the same name)
+        fn header_attribute(start_tag: &str, local_name: &str) -> Option<String> {
+            let pattern = format!("{}=", local_name);
+            let mut offset = 0;
+
+            while let Some(pos) = start_tag[offset..].find(&pattern) {
+                let start = offset + pos;
+                offset = start + pattern.len();
+
+                let name_start = start_tag[..start]
+                    .rfind(char::is_whitespace)
+                    .map_or(0, |index| index + 1);
+                let qualified_name = &start_tag[name_start..start + local_name.len()];
+                let matches_name = qualified_name == local_name
+                    || (qualified_name.ends_with(&format!(":{}", local_name))
+                        && !qualified_name.starts_with("xmlns:"));
+                if name_start == 0 || !matches_name {
+                    continue;
+                }
+
+                let rest = &start_tag[offset..];
+                let quote = match rest.chars().next() {
+                    Some(quote @ ('"' | '\'')) => quote,
+                    _ => continue,
+                };
+                if let Some(end) = rest[1..].find(quote) {
+                    return Some(rest[1..end + 1].trim().to_string());
+                }
+            }
+
+            None
+        }
+    }
+}
+
+/// Returns the tags of an envelope element that requests are searched for, e.g.
+/// `<soap:Body>`.
+/// 
+/// The configured `envelope_prefix` comes first, followed by `soap`, `SOAP-ENV`, and the
+/// unprefixed element.
+pub fn envelope_tags(config: &ServiceConfig, element: &str, closing: bool) -> Vec<String> {
+    let slash = if closing { "/" } else { "" };
+    let mut prefixes = vec![config.envelope_prefix.as_str()];
+    for prefix in ["soap", "SOAP-ENV"] {
+        if !prefixes.contains(&prefix) {
+            prefixes.push(prefix);
+        }
+    }
+
+    prefixes
+        .iter()
+        .map(|prefix| format!("<{}{}:{}>", slash, prefix, element))
+        .chain(std::iter::once(format!("<{}{}>", slash, element)))
+        .collect()
+}
+
+/// Generates `set_response_header` and the helpers adding header entries to responses.
+/// 
+/// The registered function is called for every response envelope, faults included,
+/// with the operation name (empty when the request could not be parsed), and returns
+/// header entries as XML, e.g. a server timestamp or response ID. Its entries share the
+/// `soap:Header` with any WS-RM acknowledgement. Responses stay without a header until
+/// a function is registered, and when it returns an empty string.
+pub fn generate_response_header(config: &ServiceConfig) -> TokenStream {
+    let header_break = if config.pretty_print { "\n        " } else { "" };
+    let body_break = if config.pretty_print { "\n    " } else { "" };
+    let prefix = &config.envelope_prefix;
+    let header_start = format!("<{}:Header>", prefix);
+    let header_end = format!("</{}:Header>", prefix);
+    let body_start = format!("<{}:Body>", prefix);
+
+    quote! {
+        type ResponseHeader = dyn Fn(&str) -> String + Send + Sync;
+
+        static RESPONSE_HEADER: std::sync::RwLock<Option<std::sync::Arc<ResponseHeader>>> =
+            std::sync::RwLock::new(None);
+
+        /// Registers the function producing the header entries of every response envelope.
+        /// 
+        /// It is called with the operation name, which is empty when the request could not
+        /// be parsed, and returns the entries as XML with their namespace declarations.
+        pub fn set_response_header<F>(header: F)
+        where
+            F: Fn(&str) -> String + Send + Sync + 'static,
+        {
+            *RESPONSE_HEADER.write().unwrap() = Some(std::sync::Arc::new(header));
+        }
+
+        fn add_response_header(envelope: String, operation: &str) -> String {
+            let header = RESPONSE_HEADER.read().unwrap().clone();
+            match header.map(|header| header(operation)) {
+                Some(entries) if !entries.is_empty() => add_header_entries(envelope, &entries),
+                _ => envelope,
+            }
+        }
+
+        // Appends entries to the soap:Header of an envelope written by this service,
+        // creating the header before the body when there is none yet
+        fn add_header_entries(mut envelope: String, entries: &str) -> String {
+            if let Some(header_end) = envelope.find(#header_end) {
+                let last_entry_end = header_end - #body_break.len();
+                envelope.insert_str(last_entry_end, &format!("{}{}", #header_break, entries));
+            } else if let Some(body_start) = envelope.find(#body_start) {
+                let header = format!(
+                    "{}{}{}{}{}{}",
+                    #header_start,
+                    #header_break,
+                    entries,
+                    #body_break,
+                    #header_end,
+                    #body_break
+                );
+                envelope.insert_str(body_start, &header);
+            }
+            envelope
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Generate and read schema metadata of `#[derive(SoapType)]` structs
+
+use crate::codegen::generate_complex_type_content;
+use crate::parser::{analyze_type, parse_soap_field_attributes};
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, ItemMod, Result};
+
+/// Generates the schema metadata of a struct deriving `SoapType`.
+/// 
+/// The struct is analyzed like a struct of a service module (serde renames, `Option` and
+/// `Vec` fields, and the restriction facets), and the content of its complex type is
+/// recorded in a hidden `__soap_type` function, so a service can describe the type without
+/// its source. The service names the type's element and complex type itself. The derive does not add serde
+/// attributes; `Serialize` and `Deserialize` are derived as usual. `#[soap(...)]` field
+/// attributes that change serialization (`scale`, `attribute`, `entry`, `default`) are
+/// applied by the `#[service]` rewrite and only allowed on structs in a service module.
+pub fn generate_soap_type(input: &DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "SoapType cannot be derived for generic structs"));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) if matches!(data.fields, Fields::Named(_)) => &data.fields,
+        _ => return Err(Error::new_spanned(ident, "SoapType can only be derived for structs with named fields")),
+    };
+
+    for field in fields {
+        let attributes = parse_soap_field_attributes(field)?;
+        if attributes.scale.is_some()
+            || attributes.attribute
+            || attributes.entry.is_some()
+            || attributes.default.is_some()
+        {
+            return Err(Error::new_spanned(
+                field,
+                "scale, attribute, entry, and default are only supported on structs in a service module",
+            ));
+        }
+    }
+
+    // Analyze the struct as the only item of a module, the way the service macro would
+    let item_struct = syn::ItemStruct {
+        attrs: input.attrs.clone(),
+        vis: input.vis.clone(),
+        struct_token: Default::default(),
+        ident: ident.clone(),
+        generics: input.generics.clone(),
+        fields: fields.clone(),
+        semi_token: None,
+    };
+    let module: ItemMod = syn::parse_quote! {
+        mod soap_type {
+            #item_struct
+        }
+    };
+    let content = generate_complex_type_content(&analyze_type(&syn::parse_quote!(#ident), &module)?);
+
+    Ok(quote! {
+        impl #ident {
+            /// Returns the sequence and attribute declarations of this type's complex type.
+            #[doc(hidden)]
+            pub fn __soap_type() -> Option<&'static str> {
+                Some(#content)
+            }
+        }
+    })
+}
+
+/// Returns the expression reading the complex type content of an external type.
+/// 
+/// Types declared outside the service module are described by their `SoapType` metadata,
+/// looked up as an inherent function of the type. Types without it fall back to a trait
+/// method answering an open `xsd:any` sequence, whose deprecation warns at compile time
+/// that the WSDL describes the type as `xsd:anyType`.
+pub fn external_type_content(ty: &syn::Type) -> TokenStream {
+    // Spanned at the type, so a missing derive is reported where the type is used
+    let metadata = quote_spanned! {ty.span()=> <#ty>::__soap_type() };
+    quote! { #metadata.unwrap_or(OPAQUE_TYPE_CONTENT) }
+}
+
+/// Generates the fallback content and trait read by `external_type_content`.
+pub fn generate_external_type_support() -> TokenStream {
+    let opaque = "                <xsd:sequence>\n                    <xsd:any processContents=\"lax\" minOccurs=\"0\" maxOccurs=\"unbounded\"/>\n                </xsd:sequence>\n";
+    quote! {
+        const OPAQUE_TYPE_CONTENT: &str = #opaque;
+
+        trait SoapTypeFallback {
+            #[deprecated(note = "this request or response type does not derive SoapType, so the WSDL describes it as xsd:anyType")]
+            fn __soap_type() -> Option<&'static str> {
+                None
+            }
+        }
+
+        impl<T: ?Sized> SoapTypeFallback for T {}
+    }
+}
@@ -0,0 +1,97 @@
+//! Generate chunked streaming responses for large payloads
+
+use crate::parser::SoapOperation;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `SoapStreamingResponse` trait and the chunked response body.
+/// 
+/// Operations whose response type implements the trait in the service module hand
+/// over a stream of items instead of a complete value. Each item is serialized as
+/// it arrives and written between the envelope prologue and epilogue, so the
+/// response is never buffered as a whole. The support code refers to
+/// `futures_core::Stream`, so it is only generated when an operation streams and
+/// only then requires `futures-core` in the using crate.
+pub fn generate_streaming_support(operations: &[SoapOperation]) -> TokenStream {
+    if !operations.iter().any(|operation| operation.returns_stream) {
+        return TokenStream::new();
+    }
+
+    quote! {
+        /// Implemented by response types that are streamed item by item.
+        /// 
+        /// Each item is written as an `ITEM_ELEMENT` child of the operation's response
+        /// element, which the WSDL declares as a sequence of them. An error ends the stream and aborts the connection, since the status
+        /// has already been sent by then.
+        pub trait SoapStreamingResponse {
+            type Item: ::serde::Serialize + Send + 'static;
+
+            const ITEM_ELEMENT: &'static str;
+
+            fn into_stream(
+                self,
+            ) -> std::pin::Pin<Box<dyn ::futures_core::Stream<Item = Result<Self::Item, String>> + Send>>;
+        }
+
+        struct SoapResponseStream<T> {
+            prologue: Option<String>,
+            items: std::pin::Pin<Box<dyn ::futures_core::Stream<Item = Result<T, String>> + Send>>,
+            item_element: &'static str,
+            item_declarations: &'static str,
+            epilogue: Option<String>,
+        }
+
+        impl<T: ::serde::Serialize> ::futures_core::Stream for SoapResponseStream<T> {
+            type Item = Result<String, std::io::Error>;
+
+            fn poll_next(
+                self: std::pin::Pin<&mut Self>,
+                cx: &mut std::task::Context<'_>,
+            ) -> std::task::Poll<Option<Self::Item>> {
+                let this = self.get_mut();
+                if let Some(prologue) = this.prologue.take() {
+                    return std::task::Poll::Ready(Some(Ok(prologue)));
+                }
+                if this.epilogue.is_none() {
+                    return std::task::Poll::Ready(None);
+                }
+
+                match this.items.as_mut().poll_next(cx) {
+                    std::task::Poll::Ready(Some(Ok(item))) => std::task::Poll::Ready(Some(
+                        serialize_xml_element(&item, this.item_element, this.item_declarations)
+                            .map_err(std::io::Error::other),
+                    )),
+                    std::task::Poll::Ready(Some(Err(error))) => {
+                        this.epilogue = None;
+                        std::task::Poll::Ready(Some(Err(std::io::Error::other(error))))
+                    }
+                    std::task::Poll::Ready(None) => std::task::Poll::Ready(this.epilogue.take().map(Ok)),
+                    std::task::Poll::Pending => std::task::Poll::Pending,
+                }
+            }
+        }
+
+        // The response element's tags and the items' declarations are computed at expansion
+        fn stream_soap_response<R>(
+            response: R,
+            open_element: &'static str,
+            close_element: &'static str,
+            item_declarations: &'static str,
+            mut prologue: String,
+            suffix: &'static str,
+        ) -> axum::body::Body
+        where
+            R: SoapStreamingResponse,
+        {
+            prologue.push_str(open_element);
+
+            axum::body::Body::from_stream(SoapResponseStream {
+                prologue: Some(prologue),
+                items: response.into_stream(),
+                item_element: R::ITEM_ELEMENT,
+                item_declarations,
+                epilogue: Some(format!("{}{}", close_element, suffix)),
+            })
+        }
+    }
+}
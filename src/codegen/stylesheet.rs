@@ -0,0 +1,158 @@
+//! Generate the XSLT stylesheet rendering the WSDL in a browser
+
+use crate::parser::ServiceConfig;
+
+/// Returns the route serving the stylesheet when `wsdl_stylesheet` is set.
+pub fn stylesheet_path(config: &ServiceConfig) -> String {
+    format!("{}/wsdl.xsl", config.bind_path)
+}
+
+/// Returns the `<?xml-stylesheet?>` processing instruction referencing the stylesheet.
+///
+/// Browsers apply it when the WSDL is opened directly, while SOAP tooling ignores it.
+pub fn stylesheet_instruction(config: &ServiceConfig) -> String {
+    format!(
+        r#"<?xml-stylesheet type="text/xsl" href="{}"?>"#,
+        stylesheet_path(config)
+    )
+}
+
+/// Generates the XSLT 1.0 stylesheet rendering a WSDL of this crate as HTML.
+///
+/// The page lists the endpoints and, per operation, the documentation, the SOAP
+/// action, and the fields of its input and output elements and faults. Fields are read
+/// from the inlined schema, so with `split_schema` only the element names are shown. The
+/// stylesheet is the same for every service.
+pub fn generate_wsdl_stylesheet() -> &'static str {
+    r##"<?xml version="1.0" encoding="UTF-8"?>
+<xsl:stylesheet version="1.0"
+                xmlns:xsl="http://www.w3.org/1999/XSL/Transform"
+                xmlns:wsdl="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"
+                xmlns:soap12="http://schemas.xmlsoap.org/wsdl/soap12/"
+                xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+    <xsl:output method="html" encoding="UTF-8" indent="yes"/>
+
+    <xsl:key name="message" match="wsdl:message" use="@name"/>
+    <xsl:key name="element" match="wsdl:types/xsd:schema/xsd:element" use="@name"/>
+    <xsl:key name="complexType" match="wsdl:types/xsd:schema/xsd:complexType" use="@name"/>
+    <xsl:key name="binding" match="wsdl:binding/wsdl:operation" use="@name"/>
+
+    <xsl:template match="/wsdl:definitions">
+        <html>
+            <head>
+                <title><xsl:value-of select="wsdl:service/@name"/></title>
+                <style>
+                    body { font-family: sans-serif; margin: 2em; color: #222; }
+                    code { background: #f3f3f3; padding: 0 0.2em; }
+                    table { border-collapse: collapse; margin: 0.5em 0 1em; }
+                    th, td { border: 1px solid #ccc; padding: 0.2em 0.6em; text-align: left; vertical-align: top; }
+                    th { background: #f3f3f3; }
+                    h3 { margin-top: 1.5em; }
+                </style>
+            </head>
+            <body>
+                <h1><xsl:value-of select="wsdl:service/@name"/></h1>
+                <xsl:for-each select="wsdl:documentation | wsdl:service/wsdl:documentation">
+                    <p><xsl:value-of select="."/></p>
+                </xsl:for-each>
+                <p>Namespace: <code><xsl:value-of select="@targetNamespace"/></code></p>
+                <h2>Endpoints</h2>
+                <ul>
+                    <xsl:for-each select="wsdl:service/wsdl:port">
+                        <li>
+                            <xsl:value-of select="@name"/>:
+                            <code><xsl:value-of select="soap:address/@location | soap12:address/@location"/></code>
+                        </li>
+                    </xsl:for-each>
+                </ul>
+                <h2>Operations</h2>
+                <ul>
+                    <xsl:for-each select="wsdl:portType/wsdl:operation">
+                        <li><a href="#{@name}"><xsl:value-of select="@name"/></a></li>
+                    </xsl:for-each>
+                </ul>
+                <xsl:apply-templates select="wsdl:portType/wsdl:operation"/>
+            </body>
+        </html>
+    </xsl:template>
+
+    <xsl:template match="wsdl:portType/wsdl:operation">
+        <h3 id="{@name}"><xsl:value-of select="@name"/></h3>
+        <xsl:for-each select="wsdl:documentation">
+            <p><xsl:value-of select="."/></p>
+        </xsl:for-each>
+        <p>SOAPAction: <code><xsl:value-of select="key('binding', @name)[1]/soap:operation/@soapAction"/></code></p>
+        <xsl:call-template name="message">
+            <xsl:with-param name="title" select="'Input'"/>
+            <xsl:with-param name="reference" select="wsdl:input/@message"/>
+        </xsl:call-template>
+        <xsl:call-template name="message">
+            <xsl:with-param name="title" select="'Output'"/>
+            <xsl:with-param name="reference" select="wsdl:output/@message"/>
+        </xsl:call-template>
+        <xsl:for-each select="wsdl:fault">
+            <xsl:call-template name="message">
+                <xsl:with-param name="title" select="concat('Fault ', @name)"/>
+                <xsl:with-param name="reference" select="@message"/>
+            </xsl:call-template>
+        </xsl:for-each>
+    </xsl:template>
+
+    <!-- Shows the element of a message and the fields of its type -->
+    <xsl:template name="message">
+        <xsl:param name="title"/>
+        <xsl:param name="reference"/>
+        <xsl:variable name="part" select="key('message', substring-after($reference, ':'))/wsdl:part"/>
+        <xsl:variable name="element" select="key('element', substring-after($part/@element, ':'))"/>
+        <xsl:variable name="type" select="$element/xsd:complexType | key('complexType', substring-after($element/@type, ':'))"/>
+        <h4><xsl:value-of select="$title"/>: <code><xsl:value-of select="$part/@element"/></code></h4>
+        <xsl:if test="$type/xsd:sequence/xsd:element | $type/xsd:attribute">
+            <table>
+                <tr><th>Name</th><th>Type</th><th>Occurs</th></tr>
+                <xsl:for-each select="$type/xsd:attribute">
+                    <tr>
+                        <td>@<xsl:value-of select="@name"/></td>
+                        <td><xsl:call-template name="type"/></td>
+                        <td><xsl:value-of select="@use"/></td>
+                    </tr>
+                </xsl:for-each>
+                <xsl:for-each select="$type/xsd:sequence/xsd:element">
+                    <tr>
+                        <td><xsl:value-of select="@name"/></td>
+                        <td><xsl:call-template name="type"/></td>
+                        <td>
+                            <xsl:choose>
+                                <xsl:when test="@minOccurs"><xsl:value-of select="@minOccurs"/></xsl:when>
+                                <xsl:otherwise>1</xsl:otherwise>
+                            </xsl:choose>
+                            <xsl:text>..</xsl:text>
+                            <xsl:choose>
+                                <xsl:when test="@maxOccurs"><xsl:value-of select="@maxOccurs"/></xsl:when>
+                                <xsl:otherwise>1</xsl:otherwise>
+                            </xsl:choose>
+                            <xsl:if test="@nillable = 'true'"> (nillable)</xsl:if>
+                        </td>
+                    </tr>
+                </xsl:for-each>
+            </table>
+        </xsl:if>
+    </xsl:template>
+
+    <!-- Names the type of a field, including the base and facets of a restriction -->
+    <xsl:template name="type">
+        <xsl:choose>
+            <xsl:when test="@type"><code><xsl:value-of select="@type"/></code></xsl:when>
+            <xsl:when test="xsd:simpleType/xsd:restriction">
+                <code><xsl:value-of select="xsd:simpleType/xsd:restriction/@base"/></code>
+                <xsl:for-each select="xsd:simpleType/xsd:restriction/*">
+                    <xsl:text> </xsl:text>
+                    <xsl:value-of select="local-name()"/>=<code><xsl:value-of select="@value"/></code>
+                </xsl:for-each>
+            </xsl:when>
+            <xsl:otherwise>inline</xsl:otherwise>
+        </xsl:choose>
+    </xsl:template>
+</xsl:stylesheet>
+"##
+}
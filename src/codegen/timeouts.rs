@@ -0,0 +1,31 @@
+//! Generate operation timeouts
+
+use crate::parser::SoapOperation;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Awaits an operation call, returning `timed_out(message)` from the handler when the
+/// operation has a `timeout_ms` and runs longer.
+/// 
+/// Cancellation is cooperative: `tokio::time::timeout` drops the operation's future,
+/// which stops it at its next `.await`. Blocking code between awaits is not interrupted,
+/// and work the operation spawned (e.g. `spawn_blocking`) keeps running.
+pub fn await_operation(
+    operation: &SoapOperation,
+    call: TokenStream,
+    timed_out: impl FnOnce(TokenStream) -> TokenStream,
+) -> TokenStream {
+    let timeout_ms = match operation.timeout_ms {
+        Some(timeout_ms) => timeout_ms,
+        None => return quote! { #call.await },
+    };
+
+    let message = format!("Operation {} timed out after {} ms", operation.name, timeout_ms);
+    let error = timed_out(quote! { #message.to_string() });
+    quote! {
+        match tokio::time::timeout(std::time::Duration::from_millis(#timeout_ms), #call).await {
+            Ok(result) => result,
+            Err(_) => return Err(#error),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Generate tracing instrumentation for SOAP requests
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Binds `body_size` to the size of the received `body` bytes, for the request span.
+/// 
+/// Expands to nothing without the `tracing` feature.
+pub fn measure_request_body() -> TokenStream {
+    if !cfg!(feature = "tracing") {
+        return TokenStream::new();
+    }
+
+    quote! {
+        let body_size = body.len();
+    }
+}
+
+/// Awaits a request future, inside a span carrying the operation, body size, status,
+/// and a correlation ID with the `tracing` feature.
+pub fn instrument_request(request: TokenStream) -> TokenStream {
+    if !cfg!(feature = "tracing") {
+        return quote! { #request.await };
+    }
+
+    quote! {
+        {
+            let correlation_id = extract_correlation_id(&headers, &body);
+            let span = ::tracing::info_span!(
+                "soap_request",
+                correlation_id = %correlation_id,
+                operation = ::tracing::field::Empty,
+                body_size = body_size,
+                status = ::tracing::field::Empty,
+            );
+
+            ::tracing::Instrument::instrument(#request, span).await
+        }
+    }
+}
+
+/// Generates the correlation ID helpers used by the request span.
+/// 
+/// Expands to nothing without the `tracing` feature.
+pub fn generate_correlation_ids() -> TokenStream {
+    if !cfg!(feature = "tracing") {
+        return TokenStream::new();
+    }
+
+    quote! {
+        fn extract_correlation_id(headers: &axum::http::HeaderMap, xml: &str) -> String {
+            if let Some(id) = headers
+                .get("X-Correlation-ID")
+                .and_then(|value| value.to_str().ok())
+                .filter(|value| !value.is_empty())
+            {
+                return id.to_string();
+            }
+
+            extract_message_id(xml).unwrap_or_else(generate_correlation_id)
+        }
+
+        fn extract_message_id(xml: &str) -> Option<String> {
+            // Only look at the envelope header, before the body starts
+            let header = &xml[..xml.find("Body").unwrap_or(xml.len())];
+            let mut offset = 0;
+
+            while let Some(pos) = header[offset..].find("MessageID") {
+                let start = offset + pos;
+                let before = &header[..start];
+                let after = &header[start + "MessageID".len()..];
+                offset = start + "MessageID".len();
+
+                // Accept <MessageID> and <wsa:MessageID>, but not closing tags
+                let tag_prefix = match before.rfind('<') {
+                    Some(lt) => &before[lt + 1..],
+                    None => continue,
+                };
+                let is_open_tag = tag_prefix.is_empty()
+                    || (tag_prefix.ends_with(':')
+                        && !tag_prefix.starts_with('/')
+                        && !tag_prefix.contains(|c: char| c.is_whitespace() || c == '>'));
+                if !is_open_tag {
+                    continue;
+                }
+
+                if let Some(close) = after.find('>') {
+                    let content = &after[close + 1..];
+                    if let Some(end) = content.find('<') {
+                        let id = content[..end].trim();
+                        if !id.is_empty() {
+                            return Some(id.to_string());
+                        }
+                    }
+                }
+            }
+
+            None
+        }
+
+        fn generate_correlation_id() -> String {
+            use std::hash::{BuildHasher, Hasher};
+
+            static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+            let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+            hasher.write_u64(COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed));
+            let high = hasher.finish();
+            hasher.write_u64(high);
+            let low = hasher.finish();
+
+            // Format as a version 4 UUID
+            format!(
+                "{:08x}-{:04x}-4{:03x}-{:04x}-{:012x}",
+                high >> 32,
+                (high >> 16) & 0xffff,
+                high & 0x0fff,
+                ((low >> 48) & 0x3fff) | 0x8000,
+                low & 0xffff_ffff_ffff,
+            )
+        }
+    }
+}
+
+/// Records a field on the current request span.
+/// 
+/// Expands to nothing without the `tracing` feature.
+pub fn record_span_field(field: &str, value: TokenStream) -> TokenStream {
+    if !cfg!(feature = "tracing") {
+        return TokenStream::new();
+    }
+
+    quote! {
+        ::tracing::Span::current().record(#field, #value);
+    }
+}
+
+/// Emits a warning event carrying the fault string.
+/// 
+/// Expands to nothing without the `tracing` feature.
+pub fn fault_event(fault: TokenStream) -> TokenStream {
+    if !cfg!(feature = "tracing") {
+        return TokenStream::new();
+    }
+
+    quote! {
+        ::tracing::warn!(fault = %#fault, "SOAP fault");
+    }
+}
@@ -0,0 +1,45 @@
+//! Generate request validation by the service's own rules
+
+use crate::parser::SoapOperation;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `Validate` trait.
+/// 
+/// Request types implement it in the service module to reject requests that deserialize
+/// but break the service's rules, e.g. two fields that must differ, so the operation
+/// itself only sees valid requests.
+pub fn generate_validate_trait() -> TokenStream {
+    quote! {
+        /// Implemented by request types to validate requests before the operation runs.
+        /// 
+        /// The request is checked after it is parsed (and after its restriction facets,
+        /// with `validate_restrictions`), and an error is answered with a `Client` fault
+        /// carrying the message. Request types without an implementation are not checked.
+        pub trait Validate {
+            /// Returns the reason the request is invalid, if it is.
+            fn validate(&self) -> Result<(), String>;
+        }
+    }
+}
+
+/// Returns the call of the request type's `Validate` implementation.
+/// 
+/// Expands to nothing unless the module implements `Validate` for the operation's request
+/// type. `fault` builds the error value from the message expression, since the SOAP and
+/// JSON dispatchers report faults differently.
+pub fn validate_check(
+    operation: &SoapOperation,
+    fault: impl FnOnce(TokenStream) -> TokenStream,
+) -> TokenStream {
+    if !operation.validates_request {
+        return TokenStream::new();
+    }
+
+    let error = fault(quote! { message });
+    quote! {
+        if let Err(message) = Validate::validate(&request_data) {
+            return Err(#error);
+        }
+    }
+}
@@ -1,191 +1,906 @@
 //! Generate WSDL document and endpoint
 
-use crate::parser::{ServiceConfig, SoapOperation, TypeInfo};
+use crate::codegen::{external_type_content, generate_external_type_support, stylesheet_instruction};
+use crate::parser::{is_unit_type, FaultInfo, FieldInfo, ServiceConfig, SoapOperation, TypeInfo};
+use proc_macro2::TokenStream;
+use quote::quote;
 use std::collections::HashMap;
+use std::fmt::Write;
 
-/// Generates a complete WSDL document for the SOAP service.
+/// Generates `wsdl_document`, which builds the WSDL of the service on first use.
 /// 
-/// Creates all WSDL sections including types, messages, port types, bindings,
-/// and service definitions based on the service configuration and operations.
-pub fn generate_wsdl(
+/// Only compact metadata is embedded: the names and message elements of the operations,
+/// their faults, and the content of each complex type. The sections that are the same for
+/// every operation (the `definitions` start tag, the port type and binding start tags, and
+/// the service) are written at macro expansion; the messages, port type operations,
+/// bindings, and schema declarations are written from the metadata when the document is
+/// first requested, and cached. The served document is the same as one written in full
+/// at macro expansion. With `split_schema`, `schema_document` is generated as well.
+/// 
+/// Operations bound to another namespace with `#[operation(namespace = "...")]` declare
+/// their wire elements, and the complex types they hold, in an inline schema for that
+/// namespace, which the service schema imports.
+pub fn generate_wsdl_document(
     config: &ServiceConfig,
     operations: &[SoapOperation],
     types: &HashMap<String, TypeInfo>,
-) -> String {
-    let schema_types = generate_schema_types(types);
-    let messages = generate_messages(operations);
-    let port_type = generate_port_type(config, operations);
-    let binding = generate_binding(config, operations);
-    let service = generate_service(config);
-    
-    format!(
-        r#"<?xml version="1.0" encoding="UTF-8"?>
-<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
-             xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"
-             xmlns:tns="{namespace}"
-             xmlns:xsd="http://www.w3.org/2001/XMLSchema"
-             targetNamespace="{namespace}"
-             elementFormDefault="qualified">
+) -> TokenStream {
+    let operations = &wsdl_operations(operations);
+    let namespaces = &operation_namespaces(config, operations);
+    let head = generate_wsdl_head(config, namespaces);
+    let schema_contents = generate_schema_contents(config, operations, namespaces, types);
+    let operation_schemas = generate_operation_schemas(config, operations, namespaces, types);
+    let inline_schema = (!config.split_schema).then(|| {
+        quote! {
+            write_schema_contents(&mut wsdl);
+            wsdl.push_str("\n        </xsd:schema>");
+        }
+    });
+    let schema_document = config.split_schema.then(|| {
+        let schema_head = generate_schema_head(config);
+        quote! {
+            fn schema_document() -> &'static str {
+                static DOCUMENT: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+                DOCUMENT.get_or_init(|| {
+                    let mut schema = String::from(#schema_head);
+                    write_schema_contents(&mut schema);
+                    schema.push_str("\n</xsd:schema>");
+                    schema
+                })
+            }
+        }
+    });
+
+    let wsdl_operations = operations.iter().map(|operation| {
+        let name = &operation.name;
+        let (request_element, response_element) = message_elements(config, operation, namespaces, types);
+        let faults = operation.faults.iter().map(|fault| &fault.name);
+        quote! {
+            WsdlOperation {
+                name: #name,
+                request_element: #request_element,
+                response_element: #response_element,
+                faults: &[#(#faults),*],
+            }
+        }
+    });
+    let faults = wsdl_faults(operations).into_iter().map(|fault| &fault.name);
+    let port_type_start = generate_port_type_start(config);
+    let soap_binding_start = generate_binding_start(config, "soap");
+    let soap_12_binding = config.soap_12.then(|| {
+        let binding_start = generate_binding_start(config, "soap12");
+        quote! {
+            wsdl.push('\n');
+            write_binding(&mut wsdl, #binding_start, "soap12");
+        }
+    });
+    let soap_action_base = format!("{}/", config.namespace);
+    let mut service = String::new();
+    write_service(&mut service, config);
+    service.push_str("\n\n</definitions>");
+
+    quote! {
+        // An operation of the port type, with the elements of its messages
+        struct WsdlOperation {
+            name: &'static str,
+            request_element: &'static str,
+            response_element: &'static str,
+            faults: &'static [&'static str],
+        }
+
+        const WSDL_OPERATIONS: &[WsdlOperation] = &[#(#wsdl_operations),*];
 
-    <types>
-        <xsd:schema targetNamespace="{namespace}" elementFormDefault="qualified">
-{schema_types}
-        </xsd:schema>
-    </types>
+        // The faults of all operations, each once, in the order they are first declared
+        const WSDL_FAULTS: &[&str] = &[#(#faults),*];
 
-{messages}
+        #schema_contents
 
-{port_type}
+        fn wsdl_document() -> &'static str {
+            static DOCUMENT: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+            DOCUMENT.get_or_init(|| {
+                let mut wsdl = String::from(#head);
+                #inline_schema
+                #(#operation_schemas)*
+                wsdl.push_str("\n    </types>\n\n");
+                write_messages(&mut wsdl);
+                wsdl.push_str("\n\n");
+                wsdl.push_str(#port_type_start);
+                write_port_type_operations(&mut wsdl);
+                wsdl.push_str("    </portType>\n\n\n");
+                write_binding(&mut wsdl, #soap_binding_start, "soap");
+                #soap_12_binding
+                wsdl.push_str("\n\n");
+                wsdl.push_str(#service);
+                wsdl
+            })
+        }
+
+        #schema_document
+
+        // Each fault of an error enum variant gets a message of the same name
+        fn write_messages(out: &mut String) {
+            use std::fmt::Write;
 
-{binding}
+            for operation in WSDL_OPERATIONS {
+                write!(
+                    out,
+                    r#"    <message name="{0}Request">
+        <part name="parameters" element="{1}"/>
+    </message>
+    
+    <message name="{0}Response">
+        <part name="parameters" element="{2}"/>
+    </message>
+    
+"#,
+                    operation.name, operation.request_element, operation.response_element
+                )
+                .unwrap();
+            }
 
-{service}
+            for fault in WSDL_FAULTS {
+                write!(
+                    out,
+                    r#"    <message name="{0}">
+        <part name="fault" element="tns:{0}"/>
+    </message>
+    
+"#,
+                    fault
+                )
+                .unwrap();
+            }
+        }
 
-</definitions>"#,
+        fn write_port_type_operations(out: &mut String) {
+            use std::fmt::Write;
+
+            for operation in WSDL_OPERATIONS {
+                write!(
+                    out,
+                    r#"        <operation name="{0}">
+            <input message="tns:{0}Request"/>
+            <output message="tns:{0}Response"/>
+"#,
+                    operation.name
+                )
+                .unwrap();
+                for fault in operation.faults {
+                    writeln!(out, r#"            <fault name="{0}" message="tns:{0}"/>"#, fault).unwrap();
+                }
+                out.push_str("        </operation>\n");
+            }
+        }
+
+        // `prefix` names the WSDL SOAP extension of the binding, `soap` or `soap12`
+        fn write_binding(out: &mut String, binding_start: &str, prefix: &str) {
+            use std::fmt::Write;
+
+            out.push_str(binding_start);
+            for operation in WSDL_OPERATIONS {
+                write!(
+                    out,
+                    r#"        <operation name="{0}">
+            <{1}:operation soapAction="{2}{0}"/>
+            <input>
+                <{1}:body use="literal"/>
+            </input>
+            <output>
+                <{1}:body use="literal"/>
+            </output>
+"#,
+                    operation.name, prefix, #soap_action_base
+                )
+                .unwrap();
+                for fault in operation.faults {
+                    write!(
+                        out,
+                        r#"            <fault name="{0}">
+                <{1}:fault name="{0}" use="literal"/>
+            </fault>
+"#,
+                        fault, prefix
+                    )
+                    .unwrap();
+                }
+                out.push_str("        </operation>\n");
+            }
+            out.push_str("    </binding>\n");
+        }
+    }
+}
+
+/// Returns the start of the WSDL through the opening of `<types>`.
+/// 
+/// With `split_schema`, the schema is imported from its own route instead of being
+/// inlined, so the whole `<xsd:schema>` element is included.
+fn generate_wsdl_head(config: &ServiceConfig, namespaces: &[OperationNamespace]) -> String {
+    let element_form = if config.element_form_qualified { "qualified" } else { "unqualified" };
+    let operation_namespace_declarations: String = namespaces
+        .iter()
+        .map(|namespace| format!("\n             xmlns:{}=\"{}\"", namespace.prefix, escape_xml(&namespace.uri)))
+        .collect();
+    let types_namespace_declaration = match &config.types_namespace {
+        Some(types_namespace) => format!("\n             xmlns:types=\"{}\"", escape_xml(types_namespace)),
+        None => String::new(),
+    };
+    let soap_12_declaration = if config.soap_12 {
+        "\n             xmlns:soap12=\"http://schemas.xmlsoap.org/wsdl/soap12/\""
+    } else {
+        ""
+    };
+    let policy_declaration = if config.policy_url.is_some() {
+        "\n             xmlns:wsp=\"http://www.w3.org/ns/ws-policy\""
+    } else {
+        ""
+    };
+    let stylesheet = if config.wsdl_stylesheet {
+        format!("\n{}", stylesheet_instruction(config))
+    } else {
+        String::new()
+    };
+    
+    let mut wsdl = String::new();
+    write!(
+        wsdl,
+        r#"<?xml version="1.0" encoding="UTF-8"?>{stylesheet}
+<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+             xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"{soap_12_declaration}{policy_declaration}
+             xmlns:tns="{namespace}"{operation_namespace_declarations}
+             xmlns:xsd="http://www.w3.org/2001/XMLSchema"{types_namespace_declaration}
+             targetNamespace="{namespace}"
+             elementFormDefault="{element_form}">
+"#,
+        stylesheet = stylesheet,
         namespace = config.namespace,
-        schema_types = schema_types,
-        messages = messages,
-        port_type = port_type,
-        binding = binding,
-        service = service,
+        operation_namespace_declarations = operation_namespace_declarations,
+        element_form = element_form,
+        types_namespace_declaration = types_namespace_declaration,
+        soap_12_declaration = soap_12_declaration,
+        policy_declaration = policy_declaration,
     )
+    .unwrap();
+    write_build_documentation(&mut wsdl, config);
+    wsdl.push_str("\n    <types>\n");
+    if config.split_schema {
+        write!(
+            wsdl,
+            r#"        <xsd:schema>
+            <xsd:import namespace="{}" schemaLocation="{}"/>
+        </xsd:schema>"#,
+            config.namespace,
+            schema_path(config)
+        )
+        .unwrap();
+    } else {
+        writeln!(
+            wsdl,
+            r#"        <xsd:schema targetNamespace="{}" elementFormDefault="{}">"#,
+            config.namespace, element_form
+        )
+        .unwrap();
+    }
+    wsdl
+}
+
+/// Returns the route serving the schema when `split_schema` is set.
+pub fn schema_path(config: &ServiceConfig) -> String {
+    format!("{}/schema.xsd", config.bind_path)
 }
 
-/// Generates XSD schema type definitions for all request/response types.
-fn generate_schema_types(types: &HashMap<String, TypeInfo>) -> String {
+/// Returns the start of the standalone XSD document served at `{bind_path}/schema.xsd`.
+/// 
+/// The document holds exactly what the WSDL would otherwise inline under `<types>`, as a
+/// schema whose target namespace is the service namespace.
+fn generate_schema_head(config: &ServiceConfig) -> String {
+    let types_namespace_declaration = match &config.types_namespace {
+        Some(types_namespace) => format!("\n            xmlns:types=\"{}\"", escape_xml(types_namespace)),
+        None => String::new(),
+    };
+    
     let mut schema = String::new();
+    writeln!(
+        schema,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<xsd:schema xmlns:xsd="http://www.w3.org/2001/XMLSchema"
+            xmlns:tns="{namespace}"{types_namespace_declaration}
+            targetNamespace="{namespace}"
+            elementFormDefault="{element_form}">"#,
+        namespace = config.namespace,
+        types_namespace_declaration = types_namespace_declaration,
+        element_form = if config.element_form_qualified { "qualified" } else { "unqualified" },
+    )
+    .unwrap();
+    schema
+}
+
+/// Generates `write_schema_contents`, which writes the schema imports and element
+/// declarations of the service namespace.
+/// 
+/// Types are declared in the order the operations first use them, requests before
+/// responses, so the schema follows the module's declaration order. Distinct Rust types
+/// sharing an element name have identical fields (anything else is rejected by the
+/// parser), so such an element is declared once. The content of an external type's
+/// complex type is read from its `SoapType` metadata.
+fn generate_schema_contents(
+    config: &ServiceConfig,
+    operations: &[SoapOperation],
+    namespaces: &[OperationNamespace],
+    types: &HashMap<String, TypeInfo>,
+) -> TokenStream {
+    let mut schema_import = String::new();
+    write_schema_import(&mut schema_import, config);
+    for namespace in namespaces {
+        writeln!(schema_import, r#"            <xsd:import namespace="{}"/>"#, escape_xml(&namespace.uri)).unwrap();
+    }
+    // The wire elements of operations bound to another namespace are declared in its schema
+    let service_operations: Vec<SoapOperation> = operations
+        .iter()
+        .filter(|operation| operation_namespace(operation, namespaces).is_none())
+        .cloned()
+        .collect();
+    
+    let mut declared: Vec<&str> = Vec::new();
+    let mut schema_types = Vec::new();
+    let mut wrappers = Vec::new();
+    let mut external = false;
+    // Request and response types live in the imported schema when one is configured
+    if config.schema_import.is_none() {
+        let operation_types = operations
+            .iter()
+            .flat_map(|operation| [&operation.request_type, &operation.response_type]);
+        for ty in operation_types {
+            let type_info = match types.get(&extract_type_name(ty)) {
+                Some(type_info) => type_info,
+                None => continue,
+            };
+            let type_name = &type_info.name;
+            if declared.contains(&type_name.as_str()) {
+                continue;
+            }
+            declared.push(type_name);
+            let content = if type_info.external {
+                external = true;
+                external_type_content(ty)
+            } else {
+                let content = generate_complex_type_content(type_info);
+                quote! { #content }
+            };
+            schema_types.push(quote! { (#type_name, #content) });
+        }
+        wrappers = wrapper_elements(&service_operations, types);
+    }
+    let type_count = schema_types.len();
+    let external_type_support = external.then(generate_external_type_support);
+    let wrappers = wrappers.iter().map(|(wrapper, type_name)| quote! { (#wrapper, #type_name) });
+    let empty_requests = service_operations
+        .iter()
+        .filter(|operation| is_unit_type(&operation.request_type))
+        .map(|operation| &operation.name);
     
-    for (type_name, type_info) in types {
-        schema.push_str(&format!(
-            r#"            <xsd:element name="{}" type="tns:{}Type"/>
-            <xsd:complexType name="{}Type">
-                <xsd:sequence>
+    quote! {
+        #external_type_support
+
+        fn write_schema_contents(out: &mut String) {
+            use std::fmt::Write;
+
+            // Element names and the content of their complex types
+            let schema_types: [(&str, &str); #type_count] = [#(#schema_types),*];
+            // Wire elements named after an operation, and the type they hold
+            let wrappers: &[(&str, &str)] = &[#(#wrappers),*];
+            // Operations taking the unit type are invoked with an empty element, e.g. `<Ping/>`
+            let empty_requests: &[&str] = &[#(#empty_requests),*];
+
+            out.push_str(#schema_import);
+            for (type_name, content) in schema_types {
+                write!(
+                    out,
+                    r#"            <xsd:element name="{0}" type="tns:{0}Type"/>
+            <xsd:complexType name="{0}Type">
+"#,
+                    type_name
+                )
+                .unwrap();
+                out.push_str(content);
+                out.push_str("            </xsd:complexType>\n");
+            }
+            for (wrapper, type_name) in wrappers {
+                writeln!(out, r#"            <xsd:element name="{}" type="tns:{}Type"/>"#, wrapper, type_name).unwrap();
+            }
+            for name in empty_requests {
+                write!(
+                    out,
+                    r#"            <xsd:element name="{}">
+                <xsd:complexType>
+                    <xsd:sequence/>
+                </xsd:complexType>
+            </xsd:element>
+"#,
+                    name
+                )
+                .unwrap();
+            }
+            // The detail holds the error's `Display` text as `Message`, whatever the variant's fields
+            for fault in WSDL_FAULTS {
+                write!(
+                    out,
+                    r#"            <xsd:element name="{}">
+                <xsd:complexType>
+                    <xsd:sequence>
+                        <xsd:element name="Message" type="xsd:string"/>
+                    </xsd:sequence>
+                </xsd:complexType>
+            </xsd:element>
 "#,
-            type_name, type_name, type_name
-        ));
+                    fault
+                )
+                .unwrap();
+            }
+        }
+    }
+}
+
+/// A namespace operations are bound to with `#[operation(namespace = "...")]`, other than
+/// the service namespace, and the prefix the WSDL declares for it.
+pub struct OperationNamespace {
+    pub uri: String,
+    pub prefix: String,
+}
+
+/// Returns the namespaces of the WSDL operations other than the service namespace, each
+/// once, in the order they are first used, prefixed `ns1`, `ns2`, and so on.
+/// 
+/// With `schema_import` the wire elements are those of the shared types schema, so no
+/// namespace is added.
+fn operation_namespaces(config: &ServiceConfig, operations: &[SoapOperation]) -> Vec<OperationNamespace> {
+    let mut namespaces: Vec<OperationNamespace> = Vec::new();
+    if config.schema_import.is_some() {
+        return namespaces;
+    }
+    
+    for uri in operations.iter().filter_map(|operation| operation.namespace.as_ref()) {
+        if *uri != config.namespace && !namespaces.iter().any(|namespace| namespace.uri == *uri) {
+            let prefix = format!("ns{}", namespaces.len() + 1);
+            namespaces.push(OperationNamespace { uri: uri.clone(), prefix });
+        }
+    }
+    
+    namespaces
+}
+
+/// Returns the namespace an operation's wire elements are declared in, unless it is the
+/// service namespace.
+fn operation_namespace<'a>(
+    operation: &SoapOperation,
+    namespaces: &'a [OperationNamespace],
+) -> Option<&'a OperationNamespace> {
+    let uri = operation.namespace.as_ref()?;
+    namespaces.iter().find(|namespace| namespace.uri == *uri)
+}
+
+/// Generates the statements writing one inline schema per operation namespace.
+/// 
+/// Each declares the `{Operation}` and `{Operation}Response` elements of the operations
+/// bound to it, and the complex types they hold, so their children are in that namespace
+/// with a qualified `element_form`, as the dispatcher reads and writes them.
+fn generate_operation_schemas(
+    config: &ServiceConfig,
+    operations: &[SoapOperation],
+    namespaces: &[OperationNamespace],
+    types: &HashMap<String, TypeInfo>,
+) -> Vec<TokenStream> {
+    let element_form = if config.element_form_qualified { "qualified" } else { "unqualified" };
+    let mut statements = Vec::new();
+    
+    for namespace in namespaces {
+        let prefix = &namespace.prefix;
+        // The complex types may refer to types of the service schema
+        let schema_start = format!(
+            "\n        <xsd:schema targetNamespace=\"{}\" elementFormDefault=\"{}\">\n            <xsd:import namespace=\"{}\"/>\n",
+            escape_xml(&namespace.uri),
+            element_form,
+            escape_xml(&config.namespace)
+        );
+        statements.push(quote! { wsdl.push_str(#schema_start); });
         
-        for field in &type_info.fields {
-            let xsd_type = &field.field_type;
-            let min_occurs = if field.optional { " minOccurs=\"0\"" } else { "" };
+        let mut declared: Vec<String> = Vec::new();
+        let bound = operations
+            .iter()
+            .filter(|operation| operation_namespace(operation, namespaces).is_some_and(|other| other.uri == namespace.uri));
+        for operation in bound {
+            let mut elements = vec![(format!("{}Response", operation.name), &operation.response_type)];
+            if is_unit_type(&operation.request_type) {
+                let empty_element = format!(
+                    r#"            <xsd:element name="{}">
+                <xsd:complexType>
+                    <xsd:sequence/>
+                </xsd:complexType>
+            </xsd:element>
+"#,
+                    operation.name
+                );
+                statements.push(quote! { wsdl.push_str(#empty_element); });
+            } else {
+                elements.insert(0, (operation.name.clone(), &operation.request_type));
+            }
             
-            schema.push_str(&format!(
-                r#"                    <xsd:element name="{}" type="{}"{}/>"#,
-                field.xml_name, xsd_type, min_occurs
-            ));
-            schema.push('\n');
+            for (element, ty) in elements {
+                let type_name = element_name(ty, types);
+                let element_declaration =
+                    format!("            <xsd:element name=\"{}\" type=\"{}:{}Type\"/>\n", element, prefix, type_name);
+                statements.push(quote! { wsdl.push_str(#element_declaration); });
+                if declared.contains(&type_name) {
+                    continue;
+                }
+                declared.push(type_name.clone());
+                
+                let content = match types.get(&extract_type_name(ty)) {
+                    Some(type_info) if type_info.external => external_type_content(ty),
+                    Some(type_info) => {
+                        let content = generate_complex_type_content(type_info);
+                        quote! { #content }
+                    }
+                    None => continue,
+                };
+                let complex_type_start = format!("            <xsd:complexType name=\"{}Type\">\n", type_name);
+                statements.push(quote! {
+                    wsdl.push_str(#complex_type_start);
+                    wsdl.push_str(#content);
+                    wsdl.push_str("            </xsd:complexType>\n");
+                });
+            }
         }
         
-        schema.push_str(
-            r#"                </xsd:sequence>
-            </xsd:complexType>
-"#,
-        );
+        statements.push(quote! { wsdl.push_str("        </xsd:schema>"); });
     }
     
-    schema
+    statements
 }
 
-/// Generates WSDL message definitions for all SOAP operations.
-/// 
-/// Creates request and response message elements for each operation.
-fn generate_messages(operations: &[SoapOperation]) -> String {
-    let mut messages = String::new();
+/// Writes the `xsd:import` for a shared types schema, if one is configured.
+fn write_schema_import(out: &mut String, config: &ServiceConfig) {
+    if let (Some(types_namespace), Some(schema_import)) = (&config.types_namespace, &config.schema_import) {
+        writeln!(
+            out,
+            r#"            <xsd:import namespace="{}" schemaLocation="{}"/>"#,
+            escape_xml(types_namespace),
+            escape_xml(schema_import)
+        )
+        .unwrap();
+    }
+}
+
+/// Generates the sequence and attribute declarations inside a type's complex type.
+pub fn generate_complex_type_content(type_info: &TypeInfo) -> String {
+    let mut content = String::new();
+    write_complex_type_content(&mut content, type_info);
+    content
+}
+
+/// Writes the sequence and attribute declarations inside a type's complex type.
+fn write_complex_type_content(out: &mut String, type_info: &TypeInfo) {
+    out.push_str("                <xsd:sequence>\n");
     
-    for operation in operations {
-        let request_type = extract_type_name(&operation.request_type);
-        let response_type = extract_type_name(&operation.response_type);
+    for field in type_info.fields.iter().filter(|field| !field.attribute) {
+        if let Some((key_type, value_type)) = &field.map_entry {
+            write!(
+                out,
+                r#"                    <xsd:element name="{}" minOccurs="0" maxOccurs="unbounded">
+                        <xsd:complexType>
+                            <xsd:sequence>
+                                <xsd:element name="key" type="{}"/>
+                                <xsd:element name="value" type="{}"/>
+                            </xsd:sequence>
+                        </xsd:complexType>
+                    </xsd:element>
+"#,
+                field.xml_name, key_type, value_type
+            )
+            .unwrap();
+            continue;
+        }
         
-        messages.push_str(&format!(
-            r#"    <message name="{}Request">
-        <part name="parameters" element="tns:{}"/>
-    </message>
+        let xsd_type = &field.field_type;
+        let min_occurs = if field.optional { " minOccurs=\"0\"" } else { "" };
+        let max_occurs = if field.repeated { " maxOccurs=\"unbounded\"" } else { "" };
+        let nillable = if field.nillable { " nillable=\"true\"" } else { "" };
+        
+        if field.facets.is_empty() {
+            writeln!(
+                out,
+                r#"                    <xsd:element name="{}" type="{}"{}{}{}/>"#,
+                field.xml_name, xsd_type, min_occurs, max_occurs, nillable
+            )
+            .unwrap();
+        } else {
+            writeln!(
+                out,
+                r#"                    <xsd:element name="{}"{}{}{}>"#,
+                field.xml_name, min_occurs, max_occurs, nillable
+            )
+            .unwrap();
+            write_simple_type_restriction(out, field, 24);
+            out.push_str("                    </xsd:element>\n");
+        }
+    }
     
-    <message name="{}Response">
-        <part name="parameters" element="tns:{}"/>
-    </message>
+    out.push_str("                </xsd:sequence>\n");
     
-"#,
-            operation.name, request_type, operation.name, response_type
-        ));
+    // Attributes follow the sequence in a complex type
+    for field in type_info.fields.iter().filter(|field| field.attribute) {
+        let usage = if field.optional { "optional" } else { "required" };
+        if field.facets.is_empty() {
+            writeln!(
+                out,
+                r#"                <xsd:attribute name="{}" type="{}" use="{}"{}/>"#,
+                field.xml_name, field.field_type, usage, default_attribute(field)
+            )
+            .unwrap();
+        } else {
+            writeln!(
+                out,
+                r#"                <xsd:attribute name="{}" use="{}"{}>"#,
+                field.xml_name, usage, default_attribute(field)
+            )
+            .unwrap();
+            write_simple_type_restriction(out, field, 20);
+            out.push_str("                </xsd:attribute>\n");
+        }
+    }
+}
+
+/// Returns the `default="..."` declaration of an attribute with a soap default, or nothing.
+/// 
+/// Elements never declare theirs: an XSD element default applies to an empty element,
+/// while the soap default replaces a missing one, so the schema would promise otherwise.
+fn default_attribute(field: &FieldInfo) -> String {
+    match &field.default {
+        Some(default) => format!(r#" default="{}""#, escape_xml(default)),
+        None => String::new(),
+    }
+}
+
+/// Writes an anonymous `xsd:simpleType` restricting the field's type by its facets.
+fn write_simple_type_restriction(out: &mut String, field: &FieldInfo, indent: usize) {
+    let facets = &field.facets;
+    let mut restrictions = Vec::new();
+    if let Some(pattern) = &facets.pattern {
+        restrictions.push(format!(r#"<xsd:pattern value="{}"/>"#, escape_xml(pattern)));
+    }
+    if let Some(min_length) = facets.min_length {
+        restrictions.push(format!(r#"<xsd:minLength value="{}"/>"#, min_length));
+    }
+    if let Some(max_length) = facets.max_length {
+        restrictions.push(format!(r#"<xsd:maxLength value="{}"/>"#, max_length));
+    }
+    if let Some(min) = &facets.min {
+        restrictions.push(format!(r#"<xsd:minInclusive value="{}"/>"#, min));
+    }
+    if let Some(max) = &facets.max {
+        restrictions.push(format!(r#"<xsd:maxInclusive value="{}"/>"#, max));
     }
     
-    messages
+    let pad = " ".repeat(indent);
+    write!(out, "{}<xsd:simpleType>\n{}    <xsd:restriction base=\"{}\">\n", pad, pad, field.field_type).unwrap();
+    for restriction in restrictions {
+        writeln!(out, "{}        {}", pad, restriction).unwrap();
+    }
+    write!(out, "{}    </xsd:restriction>\n{}</xsd:simpleType>\n", pad, pad).unwrap();
 }
 
-/// Generates the WSDL port type defining the service interface.
+/// Selects the operations described by the WSDL, one per operation name.
 /// 
-/// Lists all operations with their input and output message types.
-fn generate_port_type(config: &ServiceConfig, operations: &[SoapOperation]) -> String {
-    let mut port_type = format!(
-        r#"    <portType name="{}">
-"#,
-        config.port_name
-    );
+/// Operations bound to another namespace with `#[operation(namespace = "...")]` can share a
+/// name, which a port type cannot express, so the namespace-insensitive one is listed.
+pub fn wsdl_operations(operations: &[SoapOperation]) -> Vec<SoapOperation> {
+    let mut selected: Vec<SoapOperation> = Vec::new();
     
     for operation in operations {
-        port_type.push_str(&format!(
-            r#"        <operation name="{}">
-            <input message="tns:{}Request"/>
-            <output message="tns:{}Response"/>
-        </operation>
-"#,
-            operation.name, operation.name, operation.name
-        ));
+        match selected.iter_mut().find(|other| other.name == operation.name) {
+            Some(other) if other.namespace.is_some() && operation.namespace.is_none() => {
+                *other = operation.clone();
+            }
+            Some(_) => {}
+            None => selected.push(operation.clone()),
+        }
     }
     
-    port_type.push_str("    </portType>\n");
-    port_type
+    selected
 }
 
-/// Generates SOAP binding configuration for the service.
+/// Returns the faults of all operations, each once, in the order they are first declared.
+fn wsdl_faults(operations: &[SoapOperation]) -> Vec<&FaultInfo> {
+    let mut faults: Vec<&FaultInfo> = Vec::new();
+    
+    for fault in operations.iter().flat_map(|operation| &operation.faults) {
+        if !faults.iter().any(|other| other.name == fault.name) {
+            faults.push(fault);
+        }
+    }
+    
+    faults
+}
+
+/// Returns the `{Operation}` and `{Operation}Response` elements sent on the wire, with the
+/// element name of the type each holds.
 /// 
-/// Defines the SOAP transport and message format for each operation.
-fn generate_binding(config: &ServiceConfig, operations: &[SoapOperation]) -> String {
-    let binding_name = format!("{}Binding", config.service_name);
-    let mut binding = format!(
-        r#"    <binding name="{}" type="tns:{}">
-        <soap:binding style="document" transport="http://schemas.xmlsoap.org/soap/http"/>
-"#,
-        binding_name, config.port_name
-    );
+/// Requests are dispatched on an element named after the operation and responses are
+/// written as `{Operation}Response`. Where a type's own element has another name (for
+/// instance when one type is both the request and the response), a wrapper element of
+/// the type's complex type is declared so the messages can reference the wire names.
+fn wrapper_elements(operations: &[SoapOperation], types: &HashMap<String, TypeInfo>) -> Vec<(String, String)> {
+    let mut declared: Vec<String> = types.values().map(|type_info| type_info.name.clone()).collect();
+    let mut wrappers = Vec::new();
     
     for operation in operations {
-        let soap_action = format!("{}/{}", config.namespace, operation.name);
-        binding.push_str(&format!(
-            r#"        <operation name="{}">
-            <soap:operation soapAction="{}"/>
-            <input>
-                <soap:body use="literal"/>
-            </input>
-            <output>
-                <soap:body use="literal"/>
-            </output>
-        </operation>
-"#,
-            operation.name, soap_action
-        ));
+        let mut operation_wrappers = vec![(format!("{}Response", operation.name), &operation.response_type)];
+        if !is_unit_type(&operation.request_type) {
+            operation_wrappers.insert(0, (operation.name.clone(), &operation.request_type));
+        }
+        
+        for (wrapper, ty) in operation_wrappers {
+            if declared.contains(&wrapper) {
+                continue;
+            }
+            declared.push(wrapper.clone());
+            wrappers.push((wrapper, element_name(ty, types)));
+        }
     }
     
-    binding.push_str("    </binding>\n");
+    wrappers
+}
+
+/// Returns the elements referenced by the request and response messages of an operation.
+/// 
+/// These are the `{Operation}` and `{Operation}Response` wire elements, with the prefix of
+/// the operation's namespace. Elements of an imported types schema are referenced by their
+/// own names with the `types:` prefix.
+fn message_elements(
+    config: &ServiceConfig,
+    operation: &SoapOperation,
+    namespaces: &[OperationNamespace],
+    types: &HashMap<String, TypeInfo>,
+) -> (String, String) {
+    match config.schema_import {
+        None => {
+            let prefix = operation_namespace(operation, namespaces).map_or("tns", |namespace| &namespace.prefix);
+            (format!("{}:{}", prefix, operation.name), format!("{}:{}Response", prefix, operation.name))
+        }
+        Some(_) => {
+            let request_element = if is_unit_type(&operation.request_type) {
+                format!("tns:{}", operation.name)
+            } else {
+                format!("types:{}", element_name(&operation.request_type, types))
+            };
+            (request_element, format!("types:{}", element_name(&operation.response_type, types)))
+        }
+    }
+}
+
+/// Returns the start of the port type defining the service interface, up to its operations.
+fn generate_port_type_start(config: &ServiceConfig) -> String {
+    let mut port_type = String::new();
+    writeln!(port_type, r#"    <portType name="{}">"#, config.port_name).unwrap();
+    write_documentation(&mut port_type, &config.port_documentation, "        ");
+    port_type
+}
+
+/// Returns the start of a binding, up to its operations, with `prefix` naming the WSDL
+/// SOAP extension (`soap` or `soap12`).
+/// 
+/// With `policy_url`, the binding references the WS-Policy document with a
+/// `<wsp:PolicyReference>`. The policy is only advertised, not enforced.
+fn generate_binding_start(config: &ServiceConfig, prefix: &str) -> String {
+    let mut binding = String::new();
+    writeln!(
+        binding,
+        r#"    <binding name="{}" type="tns:{}">"#,
+        binding_name(config, prefix),
+        config.port_name
+    )
+    .unwrap();
+    if let Some(policy_url) = &config.policy_url {
+        writeln!(binding, r#"        <wsp:PolicyReference URI="{}"/>"#, escape_xml(policy_url)).unwrap();
+    }
+    writeln!(
+        binding,
+        r#"        <{}:binding style="document" transport="http://schemas.xmlsoap.org/soap/http"/>"#,
+        prefix
+    )
+    .unwrap();
     binding
 }
 
-/// Generates the WSDL service definition with endpoint location.
-fn generate_service(config: &ServiceConfig) -> String {
-    let binding_name = format!("{}Binding", config.service_name);
-    
-    format!(
-        r#"    <service name="{}">
-        <port name="{}" binding="tns:{}">
-            <soap:address location="http://localhost:8080{}"/>
+/// Returns the name of the binding using the `soap` or `soap12` extension elements.
+fn binding_name(config: &ServiceConfig, prefix: &str) -> String {
+    match prefix {
+        "soap12" => format!("{}Soap12Binding", config.service_name),
+        _ => format!("{}Binding", config.service_name),
+    }
+}
+
+/// Writes the WSDL service definition with endpoint location.
+/// 
+/// With SOAP 1.2 enabled, a `{port_name}Soap12` port at the same address uses the
+/// SOAP 1.2 binding.
+fn write_service(out: &mut String, config: &ServiceConfig) {
+    writeln!(out, r#"    <service name="{}">"#, config.service_name).unwrap();
+    write_documentation(out, &config.service_documentation, "        ");
+    write_port(out, config, &config.port_name, "soap");
+    if config.soap_12 {
+        write_port(out, config, &format!("{}Soap12", config.port_name), "soap12");
+    }
+    out.push_str("    </service>");
+}
+
+/// Writes a `<port>` for the binding using the `soap` or `soap12` extension elements.
+fn write_port(out: &mut String, config: &ServiceConfig, port_name: &str, prefix: &str) {
+    write!(
+        out,
+        r#"        <port name="{}" binding="tns:{}">
+            <{}:address location="{}{}"/>
         </port>
-    </service>"#,
-        config.service_name, config.port_name, binding_name, config.bind_path
+"#,
+        port_name,
+        binding_name(config, prefix),
+        prefix,
+        default_address_origin(config),
+        config.bind_path
     )
+    .unwrap();
+}
+
+/// Returns the scheme and authority of the address in the generated WSDL.
+/// 
+/// The WSDL handler replaces it with the scheme and host of the request being answered.
+pub fn default_address_origin(config: &ServiceConfig) -> String {
+    format!("{}://localhost:8080", config.address_scheme)
+}
+
+/// Writes the `<documentation>` line identifying the deployed build, or nothing if
+/// neither `version` nor `build_info` is set.
+/// 
+/// It is the first child of `definitions`, so operators can compare it against the
+/// expected build without parsing the rest of the WSDL.
+fn write_build_documentation(out: &mut String, config: &ServiceConfig) {
+    let metadata: Vec<String> = [("Version", &config.version), ("Build", &config.build_info)]
+        .into_iter()
+        .filter_map(|(label, value)| value.as_ref().map(|value| format!("{}: {}", label, value)))
+        .collect();
+    if metadata.is_empty() {
+        return;
+    }
+
+    write_documentation(out, &Some(metadata.join("; ")), "    ");
+}
+
+/// Writes an escaped `<documentation>` element line, or nothing if unset.
+fn write_documentation(out: &mut String, documentation: &Option<String>, indent: &str) {
+    if let Some(text) = documentation {
+        writeln!(out, "{}<documentation>{}</documentation>", indent, escape_xml(text)).unwrap();
+    }
+}
+
+/// Escapes XML special characters in text content and attribute values.
+pub(crate) fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Returns the XSD element name of a request or response type.
+pub fn element_name(ty: &syn::Type, types: &HashMap<String, TypeInfo>) -> String {
+    let type_name = extract_type_name(ty);
+    match types.get(&type_name) {
+        Some(type_info) => type_info.name.clone(),
+        None => type_name,
+    }
 }
 
 /// Extracts the type name from a syn::Type for WSDL generation.
-fn extract_type_name(ty: &syn::Type) -> String {
+pub fn extract_type_name(ty: &syn::Type) -> String {
     match ty {
         syn::Type::Path(type_path) => type_path
             .path
@@ -4,7 +4,7 @@ mod parser;
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{parse_macro_input, ItemMod};
+use syn::{parse_macro_input, DeriveInput, ItemMod};
 
 /// Transforms a module into a SOAP web service with automatic router and WSDL generation.
 /// 
@@ -17,17 +17,55 @@ pub fn service(args: TokenStream, input: TokenStream) -> TokenStream {
         Err(e) => return e.to_compile_error().into(),
     };
 
-    let module = parse_macro_input!(input as ItemMod);
+    let mut module = parse_macro_input!(input as ItemMod);
 
-    let operations = match parser::extract_soap_operations(&module) {
+    let operations = match parser::extract_soap_operations(&config, &module) {
         Ok(ops) => ops,
         Err(e) => return e.to_compile_error().into(),
     };
 
-    let enhanced_module = generate_enhanced_module(module, config, operations);
+    if let Err(e) = parser::validate_operations(&config, &operations) {
+        return e.to_compile_error().into();
+    }
+
+    parser::strip_operation_attributes(&mut module);
+
+    // Analyze types before the rewrite below strips the #[soap(...)] field attributes
+    let types = match parser::collect_types_from_operations(&operations, &module) {
+        Ok(types) => types,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let restriction_checks = match codegen::generate_restriction_checks(&config, &module) {
+        Ok(restriction_checks) => restriction_checks,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let rewritten_structs = match codegen::rewrite_module_structs(&mut module) {
+        Ok(rewritten_structs) => rewritten_structs,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let enhanced_module =
+        generate_enhanced_module(module, config, operations, types, rewritten_structs, restriction_checks);
     enhanced_module.into()
 }
 
+/// Records the schema of a request or response struct declared outside a service module.
+/// 
+/// The struct's fields are analyzed like those of a service module struct, honoring serde
+/// renames and restriction facets, and services using the type in an operation describe
+/// it from this metadata. `Serialize` and `Deserialize` are still derived separately.
+#[proc_macro_derive(SoapType, attributes(soap))]
+pub fn derive_soap_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match codegen::generate_soap_type(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 /// Generates the enhanced module with SOAP service functionality.
 /// 
 /// Creates router functions, SOAP handlers, WSDL endpoints, and operation dispatchers
@@ -36,46 +74,405 @@ fn generate_enhanced_module(
     mut module: ItemMod,
     config: parser::ServiceConfig,
     operations: Vec<parser::SoapOperation>,
+    types: std::collections::HashMap<String, parser::TypeInfo>,
+    rewritten_structs: codegen::RewrittenStructs,
+    restriction_checks: TokenStream2,
 ) -> TokenStream2 {
+    // The generated code lives in a submodule, so relative type paths need adjusting
+    let operations = codegen::relocate_operations(&operations);
     let bind_path = &config.bind_path;
-    let wsdl_path = format!("{}/wsdl", bind_path);
-    let namespace = &config.namespace;
+    let wsdl_path = &config.wsdl_path;
+    let max_request_bytes = config.max_request_bytes;
+    let pretty_print = config.pretty_print;
+    let body_start_patterns = codegen::envelope_tags(&config, "Body", false);
+    let body_end_patterns = codegen::envelope_tags(&config, "Body", true);
+    let keeps_raw_xml = operations.iter().any(|operation| operation.takes_raw_xml);
+    // Listed in unknown operation faults, so integrators can find the right element name
+    let available_operations = codegen::wsdl_operations(&operations)
+        .iter()
+        .map(|operation| operation.name.clone())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let service_name = &config.service_name;
 
-    // Collect type information
-    let types = match parser::collect_types_from_operations(&operations) {
-        Ok(types) => types,
-        Err(_) => std::collections::HashMap::new(),
+    // Generate the WSDL metadata and the functions building the documents on first use
+    let wsdl_document = codegen::generate_wsdl_document(&config, &operations, &types);
+    let address_origin = codegen::default_address_origin(&config);
+    let address_scheme = &config.address_scheme;
+
+    // Generate the optional standalone schema route, also served at {bind_path}?xsd
+    let (split_schema_route, schema_support) = if config.split_schema {
+        let schema_path = codegen::schema_path(&config);
+        (
+            quote! { .route(#schema_path, axum::routing::get(schema_handler)) },
+            quote! {
+                async fn schema_handler() -> axum::response::Response {
+                    axum::response::Response::builder()
+                        .status(200)
+                        .header("Content-Type", "text/xml; charset=utf-8")
+                        .body(axum::body::Body::from(schema_document()))
+                        .unwrap()
+                }
+            },
+        )
+    } else {
+        (TokenStream2::new(), TokenStream2::new())
     };
+    let xsd_query = config.split_schema.then(|| {
+        quote! {
+            if has_parameter("xsd") {
+                return schema_handler().await;
+            }
+        }
+    });
+    let discovery_message = format!(
+        "{} accepts SOAP requests via POST. The WSDL is available at {} and {}?wsdl.",
+        config.service_name, wsdl_path, bind_path
+    );
+
+    // Generate the optional JSON description route
+    let json_schema_route = config.json_schema_path.as_ref().map(|path| {
+        let json_schema = codegen::generate_json_schema(&config, &operations, &types);
+        quote! {
+            .route(
+                #path,
+                axum::routing::get(|| async {
+                    axum::response::Response::builder()
+                        .status(200)
+                        .header("Content-Type", "application/json")
+                        .body(axum::body::Body::from(#json_schema))
+                        .unwrap()
+                }),
+            )
+        }
+    });
+
+    // Generate the optional stylesheet route for viewing the WSDL in a browser
+    let stylesheet_route = config.wsdl_stylesheet.then(|| {
+        let path = codegen::stylesheet_path(&config);
+        let stylesheet = codegen::generate_wsdl_stylesheet();
+        quote! {
+            .route(
+                #path,
+                axum::routing::get(|| async {
+                    axum::response::Response::builder()
+                        .status(200)
+                        .header("Content-Type", "text/xsl; charset=utf-8")
+                        .body(axum::body::Body::from(#stylesheet))
+                        .unwrap()
+                }),
+            )
+        }
+    });
+
+    // Generate the optional JSON compatibility routes
+    let (json_api_routes, json_api) = codegen::generate_json_api(&config, &operations);
+
+    // Generate XSD-compatible float serializers for rewritten struct fields
+    let float_serializers = codegen::generate_float_serializers(&rewritten_structs.float_scales);
+    let default_values = rewritten_structs.default_values;
+    let attribute_serializer = codegen::generate_attribute_serializer();
+    let lexical_serializers = codegen::generate_lexical_serializers();
+    let flatten_deserializer = codegen::generate_flatten_deserializer();
+    let map_serializers = codegen::generate_map_serializers();
 
-    // Generate WSDL content
-    let wsdl_content = codegen::generate_wsdl(&config, &operations, &types);
+    // Generate the schema conformance check for tests (test-util feature) and mock services
+    let conformance_check = codegen::generate_conformance_check(&config, &operations, &types);
+    let mock_support = codegen::generate_mock_support(&config);
+    let dispatch = codegen::mock_dispatch(
+        &config,
+        quote! { with_request_namespace(request.namespace.clone(), handle_soap_request(&request, route)).await },
+    );
+    // Mock services do not read the request after the schema check
+    let body_content = (!config.mock).then(|| quote! { let body_content = &request.body_xml; });
+
+    // Generate the optional typed client
+    let client = codegen::generate_client(&config, &operations);
+
+    // Generate XML hardening checks
+    let xml_guards = codegen::generate_xml_guards(&config);
+
+    // Generate the fault code taxonomy and fault envelopes
+    let fault_support = codegen::generate_fault_support(&config);
+    let fault_variants = codegen::generate_fault_variants(&operations);
+
+    // Generate the optional HTTP authentication guard
+    let (auth_layer, auth_guard) = codegen::generate_auth_guard(&config);
+
+    // Generate mustUnderstand header checks
+    let soap_header_checks = codegen::generate_soap_header_checks(&config);
+
+    // Generate WS-ReliableMessaging acknowledgements
+    let reliable_messaging = codegen::generate_reliable_messaging(&config);
+
+    // Generate the hook adding header entries to every response
+    let response_header = codegen::generate_response_header(&config);
+
+    // Generate multipart/related support for operations returning attachments
+    let attachment_support = codegen::generate_attachment_support();
+
+    // Generate chunked responses for operations returning a stream
+    let streaming_support = codegen::generate_streaming_support(&operations);
+
+    // Generate the WithHeaders response wrapper
+    let response_headers = codegen::generate_response_headers();
 
     // Generate operation dispatcher
-    let operation_handlers = generate_operation_handlers(&operations, namespace);
+    let operation_handlers = generate_operation_handlers(&config, &operations);
+    let namespace_validation = generate_namespace_validation(&config, &operations);
+
+    // Generate server lifecycle helpers
+    let serve_helper = generate_serve_helper();
+
+    // Generate the runtime operation list
+    let operation_info = codegen::generate_operation_info(&config, &operations);
+
+    // Generate metrics observer hooks
+    let observer = generate_observer();
+
+    // Generate per-request context accessors
+    let request_context = codegen::generate_request_context();
+
+    // Generate request entry point and tracing instrumentation
+    let soap_handler_entry = codegen::generate_soap_handler_entry(&operations);
+    let content_negotiation = codegen::generate_content_negotiation();
+    let operation_routes = operations.iter().filter_map(|operation| {
+        let path = operation.path.as_ref()?;
+        let handler = codegen::operation_route_handler(operation);
+        Some(quote! {
+            .route(
+                #path,
+                axum::routing::post(#handler)
+                    .layer(axum::extract::DefaultBodyLimit::max(MAX_REQUEST_BYTES)),
+            )
+        })
+    });
+    let record_operation =
+        codegen::record_span_field("operation", quote! { request.operation.as_str() });
+    let record_success_status = codegen::record_span_field("status", quote! { 200u16 });
+    let record_fault_status = codegen::record_span_field("status", quote! { fault.http_status().as_u16() });
+    let fault_event = codegen::fault_event(quote! { fault.message });
+    let request_decoding = codegen::generate_request_decoding();
+    let nil_handling = codegen::generate_nil_handling();
+    let raw_body_support = codegen::generate_raw_body_support();
+    let batch_support = codegen::generate_batch_support();
+    let validate_trait = codegen::generate_validate_trait();
+    let header_context = codegen::generate_header_context(&operations);
+    let rate_limiter = codegen::generate_rate_limiter(&operations);
 
     let router_code = quote! {
         use std::collections::HashMap;
 
+        /// Returns the complete service: the operation routes and the description routes.
+        /// 
+        /// To apply middleware to SOAP requests only, compose the two route sets instead,
+        /// e.g. `routes().layer(timeout).merge(description_routes())`.
         pub fn router() -> axum::Router {
+            routes().merge(description_routes())
+        }
+
+        /// Returns the POST routes dispatching operations, with the request size limit and
+        /// the `auth` guard applied, but without the WSDL and description routes.
+        pub fn routes() -> axum::Router {
+            axum::Router::new()
+                .route(
+                    #bind_path,
+                    axum::routing::post(soap_handler)
+                        .layer(axum::extract::DefaultBodyLimit::max(MAX_REQUEST_BYTES)),
+                )
+                #(#operation_routes)*
+                #json_api_routes
+                #auth_layer
+        }
+
+        /// Returns the GET routes serving the WSDL and, when configured, the schema, the
+        /// JSON description, and the WSDL stylesheet.
+        pub fn description_routes() -> axum::Router {
             axum::Router::new()
-                .route(#bind_path, axum::routing::post(soap_handler))
                 .route(#wsdl_path, axum::routing::get(wsdl_handler))
+                .route(#bind_path, axum::routing::get(wsdl_query_handler))
+                #split_schema_route
+                #json_schema_route
+                #stylesheet_route
         }
 
-        async fn soap_handler(body: String) -> axum::response::Response {
-            match handle_soap_request(&body).await {
+        /// Merges this service's routes into `app`, panicking with the service name
+        /// and its paths if another service already registered the same routes.
+        pub fn register(app: axum::Router) -> axum::Router {
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| app.merge(router())))
+                .unwrap_or_else(|_| {
+                    panic!(
+                        "{} routes ({} and {}) conflict with routes already registered on the app",
+                        #service_name, #bind_path, #wsdl_path
+                    )
+                })
+        }
+
+        #serve_helper
+
+        #operation_info
+
+        #observer
+
+        #request_context
+
+        #client
+
+        #float_serializers
+        #attribute_serializer
+        #lexical_serializers
+        #flatten_deserializer
+        #map_serializers
+        #default_values
+
+        #restriction_checks
+
+        #fault_support
+
+        #fault_variants
+        #soap_header_checks
+        #auth_guard
+
+        #attachment_support
+        #streaming_support
+        #reliable_messaging
+
+        #response_header
+        #raw_body_support
+        #batch_support
+
+        #validate_trait
+
+        #header_context
+
+        #rate_limiter
+
+        #response_headers
+
+        const MAX_REQUEST_BYTES: usize = #max_request_bytes;
+        const PRETTY_PRINT: bool = #pretty_print;
+        const KEEPS_RAW_XML: bool = #keeps_raw_xml;
+        const AVAILABLE_OPERATIONS: &str = #available_operations;
+
+        #xml_guards
+        #conformance_check
+
+        #mock_support
+
+        #soap_handler_entry
+        #content_negotiation
+
+        #json_api
+
+        fn reject_request_body(
+            rejection: axum::extract::rejection::BytesRejection,
+        ) -> axum::response::Response {
+            let status = rejection.status();
+            let message = if status == axum::http::StatusCode::PAYLOAD_TOO_LARGE {
+                format!("Request body exceeds the maximum of {} bytes", MAX_REQUEST_BYTES)
+            } else {
+                rejection.body_text()
+            };
+
+            reject_request(ServiceFault::client(message).with_status(Some(status)))
+        }
+
+        // Answers a request whose body could not be read, before any operation is known
+        fn reject_request(fault: ServiceFault) -> axum::response::Response {
+            current_observer().on_fault("", std::time::Duration::ZERO, fault.code.as_str());
+            #fault_event
+            let status = fault.http_status();
+            axum::response::Response::builder()
+                .status(status)
+                .header("Content-Type", "text/xml; charset=utf-8")
+                .body(add_response_header(create_soap_fault(&fault, "", "", SoapVersion::Soap11), "").into())
+                .unwrap()
+        }
+
+        #request_decoding
+
+        /// Feeds a raw SOAP envelope through the dispatcher without HTTP, for tests.
+        /// 
+        /// Returns the response envelope, or the fault envelope as the error. The
+        /// request is handled like a POST to `bind_path`, but without the HTTP layers,
+        /// so the `auth` guard does not run and no principal is set.
+        pub async fn handle_raw(xml: &str) -> Result<String, String> {
+            let response = process_soap_request(xml, None).await;
+            let success = response.status().is_success();
+            let body = match axum::body::to_bytes(response.into_body(), usize::MAX).await {
+                Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                Err(e) => return Err(format!("Failed to read response: {}", e)),
+            };
+
+            if success {
+                Ok(body)
+            } else {
+                Err(body)
+            }
+        }
+
+        async fn process_soap_request(body: &str, route: Option<OperationRoute>) -> axum::response::Response {
+            let started = std::time::Instant::now();
+            let observer = current_observer();
+            let rm_acknowledgement = acknowledge_rm_sequence(body);
+            let version = envelope_version(body);
+
+            // The operation name stays empty when the envelope itself is malformed
+            let (operation, request_xml, result) = match parse_soap_envelope(body) {
+                Ok(mut request) => {
+                    // Operation routes skip body element detection
+                    if let Some(route) = route {
+                        request.operation = route.operation.to_string();
+                    }
+
+                    #record_operation
+                    observer.on_request(&request.operation);
+                    let result = #dispatch;
+                    (request.operation, request.body_xml, result)
+                }
+                Err(fault) => (String::new(), String::new(), Err(fault)),
+            };
+
+            match result {
                 Ok(response) => {
-                    axum::response::Response::builder()
-                        .status(200)
-                        .header("Content-Type", "text/xml; charset=utf-8")
-                        .header("SOAPAction", "")
-                        .body(response.into())
-                        .unwrap()
+                    observer.on_success(&operation, started.elapsed());
+                    #record_success_status
+                    let SoapResponseBody { envelope, attachments, headers, stream } = response;
+                    let http_response = match stream {
+                        // Without a known length the body is sent with chunked encoding
+                        Some(body) => axum::response::Response::builder()
+                            .status(200)
+                            .header("Content-Type", "text/xml; charset=utf-8")
+                            .header("SOAPAction", "")
+                            .body(body)
+                            .unwrap(),
+                        None if attachments.is_empty() => axum::response::Response::builder()
+                            .status(200)
+                            .header("Content-Type", "text/xml; charset=utf-8")
+                            .header("SOAPAction", "")
+                            .body(add_rm_acknowledgement(add_response_header(envelope, &operation), &rm_acknowledgement).into())
+                            .unwrap(),
+                        None => create_multipart_response(
+                            &add_rm_acknowledgement(add_response_header(envelope, &operation), &rm_acknowledgement),
+                            &attachments,
+                        ),
+                    };
+                    apply_operation_headers(http_response, &headers)
                 }
-                Err(error) => {
-                    let fault = create_soap_fault(&error);
+                Err(fault) => {
+                    observer.on_fault(&operation, started.elapsed(), fault.code.as_str());
+                    #record_fault_status
+                    #fault_event
+                    let status = fault.http_status();
+                    let fault = add_rm_acknowledgement(
+                        add_response_header(
+                            create_soap_fault(&fault, &operation, &request_xml, version),
+                            &operation,
+                        ),
+                        &rm_acknowledgement,
+                    );
                     axum::response::Response::builder()
-                        .status(500)
+                        .status(status)
                         .header("Content-Type", "text/xml; charset=utf-8")
                         .body(fault.into())
                         .unwrap()
@@ -83,28 +480,52 @@ fn generate_enhanced_module(
             }
         }
 
-        async fn handle_soap_request(xml: &str) -> Result<String, String> {
-            // Parse SOAP envelope using proper XML parsing
-            let parsed_request = parse_soap_envelope(xml)?;
-            let operation = &parsed_request.operation;
-            let body_content = &parsed_request.body_xml;
+        async fn handle_soap_request(
+            request: &ParsedSoapRequest,
+            route: Option<OperationRoute>,
+        ) -> Result<SoapResponseBody, ServiceFault> {
+            let operation = &request.operation;
+            let namespace = request.namespace.as_deref();
+            #body_content
+
+            #namespace_validation
 
             #operation_handlers
 
-            Err(format!("Unknown operation: {}", operation))
+            Err(ServiceFault::client(format!(
+                "Unknown operation: {}. Available operations: {}",
+                operation, AVAILABLE_OPERATIONS
+            ))
+            .with_status(Some(axum::http::StatusCode::BAD_REQUEST)))
         }
 
         #[derive(Debug)]
         struct ParsedSoapRequest {
             operation: String,
             body_xml: String,
+            // The operation element as sent, only kept when an operation takes RawXml
+            raw_xml: String,
+            // The header entries, only read when an operation takes a FromSoapHeaders context
+            soap_headers: SoapHeaders,
             namespace: Option<String>,
+            version: SoapVersion,
+        }
+
+        fn parse_soap_envelope(xml: &str) -> Result<ParsedSoapRequest, ServiceFault> {
+            let xml = strip_leading_noise(xml);
+            check_envelope_version(xml)?;
+            let mut request = parse_envelope_body(xml).map_err(ServiceFault::client)?;
+            request.version = envelope_version(xml);
+            check_must_understand(xml)?;
+            Ok(request)
         }
 
-        fn parse_soap_envelope(xml: &str) -> Result<ParsedSoapRequest, String> {
+        fn parse_envelope_body(xml: &str) -> Result<ParsedSoapRequest, String> {
+            check_xml_safety(xml)?;
+
             // Handle different SOAP Body variations
-            let body_start_patterns = ["<soap:Body>", "<SOAP-ENV:Body>", "<Body>"];
-            let body_end_patterns = ["</soap:Body>", "</SOAP-ENV:Body>", "</Body>"];
+            let body_start_patterns = [#(#body_start_patterns),*];
+            let body_end_patterns = [#(#body_end_patterns),*];
 
             let mut body_start_pos = None;
             let mut body_end_pos = None;
@@ -140,31 +561,44 @@ fn generate_enhanced_module(
             // Extract operation name from first element in body
             let operation = extract_first_element_name(trimmed_body)?;
 
+            // Document/literal bodies hold exactly one operation element; dispatching the
+            // first of several would silently drop the others
+            let children = body_element_names(trimmed_body);
+            if children.len() > 1 {
+                return Err(format!(
+                    "SOAP Body must contain a single operation element, found {}: {}",
+                    children.len(),
+                    children.join(", ")
+                ));
+            }
+
             Ok(ParsedSoapRequest {
                 operation,
-                body_xml: trimmed_body.to_string(),
-                namespace: extract_target_namespace(xml),
+                body_xml: strip_nil_elements(trimmed_body, &xsi_prefixes(xml)),
+                raw_xml: if KEEPS_RAW_XML { trimmed_body.to_string() } else { String::new() },
+                soap_headers: if READS_SOAP_HEADERS { read_soap_headers(xml) } else { SoapHeaders::default() },
+                namespace: extract_operation_namespace(xml),
+                version: SoapVersion::Soap11,
             })
         }
 
-        fn extract_first_element_name(xml: &str) -> Result<String, String> {
-            let xml = xml.trim();
-            if !xml.starts_with('<') {
-                return Err("No XML element found".to_string());
-            }
+        #nil_handling
 
-            let after_bracket = &xml[1..];
+        fn strip_leading_noise(xml: &str) -> &str {
+            // Some clients prepend a UTF-8 byte order mark and/or whitespace before <?xml
+            xml.trim_start().trim_start_matches('\u{feff}').trim_start()
+        }
+
+        fn extract_first_element_name(xml: &str) -> Result<String, String> {
+            let xml = strip_leading_noise(xml).trim_end();
+            let after_bracket = xml.strip_prefix('<').ok_or("No XML element found")?;
             let tag_end = after_bracket.find('>')
                 .ok_or("Invalid XML: no closing bracket found")?;
 
             let tag_content = &after_bracket[..tag_end];
 
             // Handle self-closing tags
-            let tag_name = if tag_content.ends_with('/') {
-                &tag_content[..tag_content.len() - 1]
-            } else {
-                tag_content
-            };
+            let tag_name = tag_content.strip_suffix('/').unwrap_or(tag_content);
 
             // Remove namespace prefix and attributes
             let clean_name = tag_name.split_whitespace().next().unwrap_or(tag_name);
@@ -177,94 +611,165 @@ fn generate_enhanced_module(
             Ok(operation.to_string())
         }
 
-        fn extract_target_namespace(xml: &str) -> Option<String> {
-            // Look for targetNamespace or xmlns attributes
-            if let Some(start) = xml.find("targetNamespace=\"") {
-                let after_start = &xml[start + 17..];
-                if let Some(end) = after_start.find('"') {
-                    return Some(after_start[..end].to_string());
+        // Lists the local names of the top-level elements in the body
+        fn body_element_names(body: &str) -> Vec<String> {
+            let mut reader = ::quick_xml::reader::Reader::from_str(body);
+            let mut names = Vec::new();
+            let mut depth = 0usize;
+
+            loop {
+                match reader.read_event() {
+                    Ok(::quick_xml::events::Event::Start(start)) => {
+                        if depth == 0 {
+                            names.push(String::from_utf8_lossy(start.local_name().as_ref()).into_owned());
+                        }
+                        depth += 1;
+                    }
+                    Ok(::quick_xml::events::Event::Empty(empty)) if depth == 0 => {
+                        names.push(String::from_utf8_lossy(empty.local_name().as_ref()).into_owned());
+                    }
+                    Ok(::quick_xml::events::Event::End(_)) => depth = depth.saturating_sub(1),
+                    // Malformed bodies are reported when the request is deserialized
+                    Ok(::quick_xml::events::Event::Eof) | Err(_) => return names,
+                    _ => {}
                 }
             }
+        }
 
-            // Fallback to default xmlns
-            if let Some(start) = xml.find("xmlns=\"") {
-                let after_start = &xml[start + 7..];
-                if let Some(end) = after_start.find('"') {
-                    return Some(after_start[..end].to_string());
-                }
+        fn is_empty_element(xml: &str) -> bool {
+            let xml = xml.trim();
+
+            // Self-closing element, e.g. <Ping/> or <tns:Ping xmlns:tns="..."/>
+            if xml.ends_with("/>") && xml.matches('<').count() == 1 {
+                return true;
             }
 
-            None
-        }
-
-        fn create_simple_soap_response(content: &str, operation: &str, namespace: &str) -> String {
-            format!(
-                r#"<?xml version="1.0" encoding="UTF-8"?>
-<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"
-               xmlns:tns="{}">
-    <soap:Body>
-        <tns:{}Response>
-            {}
-        </tns:{}Response>
-    </soap:Body>
-</soap:Envelope>"#,
-                namespace, operation, content, operation
-            )
+            // Open and close tags with only whitespace between, e.g. <Ping></Ping>
+            match (xml.find('>'), xml.rfind("</")) {
+                (Some(open_end), Some(close_start)) if open_end < close_start => {
+                    xml[open_end + 1..close_start].trim().is_empty()
+                }
+                _ => false,
+            }
         }
 
-        fn extract_xml_value(xml: &str, tag_name: &str) -> Option<String> {
-            // Try multiple patterns to handle namespaces and variations
-            let patterns = [
-                format!("<{}>", tag_name),
-                format!("<{}:", tag_name),  // Handle namespace prefixes
-                format!("<tns:{}>", tag_name),
-                format!("<ns1:{}>", tag_name),
-            ];
-
-            for start_pattern in &patterns {
-                if let Some(start_pos) = xml.find(start_pattern) {
-                    // Find the actual end of the opening tag
-                    let tag_start = start_pos + start_pattern.len();
-                    let remaining = &xml[start_pos..];
-
-                    if let Some(close_bracket) = remaining.find('>') {
-                        let content_start = start_pos + close_bracket + 1;
-
-                        // Look for the closing tag
-                        let end_patterns = [
-                            format!("</{}>", tag_name),
-                            format!("</{}:", tag_name),
-                            format!("</tns:{}>", tag_name),
-                            format!("</ns1:{}>", tag_name),
-                        ];
-
-                        for end_pattern in &end_patterns {
-                            if let Some(end_pos) = xml[content_start..].find(end_pattern) {
-                                let actual_end = content_start + end_pos;
-                                if content_start <= actual_end {
-                                    let content = &xml[content_start..actual_end];
-                                    return Some(decode_xml_content(content.trim()));
-                                }
+        fn extract_operation_namespace(xml: &str) -> Option<String> {
+            // Resolve the operation element's prefix (or the default namespace) against the
+            // declarations on the element itself and on its ancestors, innermost first
+            let mut reader = ::quick_xml::reader::NsReader::from_str(xml);
+            let mut depth = 0usize;
+            let mut in_body = false;
+
+            loop {
+                let (namespace, event) = reader.read_resolved_event().ok()?;
+                match event {
+                    ::quick_xml::events::Event::Start(_) | ::quick_xml::events::Event::Empty(_)
+                        if depth == 2 && in_body =>
+                    {
+                        return match namespace {
+                            ::quick_xml::name::ResolveResult::Bound(namespace) => {
+                                Some(String::from_utf8_lossy(namespace.as_ref()).into_owned())
                             }
+                            _ => None,
+                        };
+                    }
+                    ::quick_xml::events::Event::Start(start) => {
+                        depth += 1;
+                        if depth == 2 {
+                            in_body = start.local_name().as_ref() == b"Body";
                         }
+                    }
+                    ::quick_xml::events::Event::End(_) => depth = depth.saturating_sub(1),
+                    ::quick_xml::events::Event::Eof => return None,
+                    _ => {}
+                }
+            }
+        }
 
-                        // Handle self-closing tags like <tag/>
-                        if remaining[..close_bracket].ends_with('/') {
-                            return Some(String::new());
-                        }
+        fn find_namespace_declaration(xml: &str, declaration: &str, last: bool) -> Option<String> {
+            let mut found = None;
+            let mut offset = 0;
+
+            while let Some(pos) = xml[offset..].find(declaration) {
+                let start = offset + pos;
+                offset = start + declaration.len();
+
+                // Skip matches inside a longer attribute name, e.g. "axmlns="
+                let preceded_by_space = xml[..start].ends_with(char::is_whitespace);
+                let rest = &xml[offset..];
+                let quote = match rest.chars().next() {
+                    Some(quote @ ('"' | '\'')) if preceded_by_space => quote,
+                    _ => continue,
+                };
+                if let Some(end) = rest[1..].find(quote) {
+                    found = Some(rest[1..end + 1].to_string());
+                    if !last {
+                        break;
                     }
                 }
             }
-            None
+
+            found
         }
 
-        fn decode_xml_content(content: &str) -> String {
-            content
-                .replace("&lt;", "<")
-                .replace("&gt;", ">")
-                .replace("&amp;", "&")
-                .replace("&quot;", "\"")
-                .replace("&apos;", "'")
+        // The response element is written by quick_xml's serializer, and the envelope text around
+        // it is fixed at expansion (codegen/envelope.rs), so concatenating them adds no whitespace
+        // to the element content. Only pretty_print indents, and only between tags.
+        fn create_simple_soap_response(content: &str, prefix: &str, suffix: &str) -> String {
+            let mut response = String::with_capacity(prefix.len() + content.len() + suffix.len());
+            response.push_str(prefix);
+            response.push_str(content);
+            response.push_str(suffix);
+            response
+        }
+
+        // Reads the text of the first element with the local name, with the semantics of
+        // request parsing: an empty element is Some(""), and a missing element or one with
+        // xsi:nil="true" is None. The text is trimmed, and child elements are skipped.
+        fn extract_xml_value(xml: &str, tag_name: &str) -> Option<String> {
+            let mut reader = ::quick_xml::reader::NsReader::from_str(xml);
+
+            let is_empty = loop {
+                let (start, is_empty) = match reader.read_event().ok()? {
+                    ::quick_xml::events::Event::Start(start) => (start, false),
+                    ::quick_xml::events::Event::Empty(start) => (start, true),
+                    ::quick_xml::events::Event::Eof => return None,
+                    _ => continue,
+                };
+                if start.local_name().as_ref() != tag_name.as_bytes() {
+                    continue;
+                }
+                let nil = start.attributes().flatten().any(|attribute| {
+                    let (namespace, local_name) = reader.resolve_attribute(attribute.key);
+                    matches!(namespace, ::quick_xml::name::ResolveResult::Bound(bound) if bound.as_ref() == XSI_NAMESPACE.as_bytes())
+                        && local_name.as_ref() == b"nil"
+                        && matches!(attribute.value.as_ref(), b"true" | b"1")
+                });
+                if nil {
+                    return None;
+                }
+                break is_empty;
+            };
+            if is_empty {
+                return Some(String::new());
+            }
+
+            let mut value = String::new();
+            let mut depth = 0usize;
+            loop {
+                match reader.read_event().ok()? {
+                    ::quick_xml::events::Event::Start(_) => depth += 1,
+                    ::quick_xml::events::Event::End(_) if depth == 0 => return Some(value.trim().to_string()),
+                    ::quick_xml::events::Event::End(_) => depth -= 1,
+                    ::quick_xml::events::Event::Text(text) if depth == 0 => value.push_str(&text.unescape().ok()?),
+                    // CDATA sections are copied verbatim
+                    ::quick_xml::events::Event::CData(data) if depth == 0 => {
+                        value.push_str(&String::from_utf8_lossy(&data))
+                    }
+                    ::quick_xml::events::Event::Eof => return None,
+                    _ => {}
+                }
+            }
         }
 
         // Generic request parsing using serde_xml_rs directly on operation XML
@@ -279,47 +784,209 @@ fn generate_enhanced_module(
         }
 
 
-        // Generic response serialization using serde_xml_rs
-        fn serialize_response_to_xml<T>(response: &T) -> Result<String, String>
+        // Serializes a value in a single pass as one element named `root` (e.g. "tns:AddResponse"),
+        // carrying the namespace declarations computed for it at expansion
+        fn serialize_xml_element<T>(value: &T, root: &str, declarations: &str) -> Result<String, String>
         where
             T: ::serde::Serialize,
         {
-            // Use serde_xml_rs for serialization
-            ::serde_xml_rs::to_string(response)
-                .map_err(|e| format!("XML serialization error: {}", e))
-        }
-
-
-        fn create_soap_fault(error: &str) -> String {
-            format!(
-                r#"<?xml version="1.0" encoding="UTF-8"?>
-<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
-    <soap:Body>
-        <soap:Fault>
-            <faultcode>Server</faultcode>
-            <faultstring>{}</faultstring>
-        </soap:Fault>
-    </soap:Body>
-</soap:Envelope>"#,
-                error
-            )
+            let mut xml = String::new();
+            let serializer = ::quick_xml::se::Serializer::with_root(&mut xml, Some(root))
+                .map_err(|e| format!("XML serialization error: {}", e))?;
+            value
+                .serialize(serializer)
+                .map_err(|e| format!("XML serialization error: {}", e))?;
+
+            xml.insert_str(1 + root.len(), declarations);
+            Ok(xml)
         }
 
-        async fn wsdl_handler() -> axum::response::Response {
-            let wsdl = #wsdl_content;
+        // Serializes an operation response, indented to its place in the envelope with pretty_print
+        fn serialize_response_element<T>(value: &T, root: &str, declarations: &str) -> Result<String, String>
+        where
+            T: ::serde::Serialize,
+        {
+            let xml = serialize_xml_element(value, root, declarations)?;
+            if PRETTY_PRINT {
+                Ok(indent_xml_fragment(&xml, "        "))
+            } else {
+                Ok(xml)
+            }
+        }
+
+        // Puts each child of an element-only element on its own line, prefixed with `offset`.
+        // Whitespace is only added between adjacent tags, and elements holding text are copied
+        // verbatim, so text values (including their line breaks) are never altered.
+        fn indent_xml_fragment(xml: &str, offset: &str) -> String {
+            enum Token<'a> {
+                Open(&'a str),
+                Close(&'a str),
+                Empty(&'a str),
+                Text(&'a str),
+            }
+
+            let mut tokens = Vec::new();
+            let mut rest = xml;
+            while !rest.is_empty() {
+                let length = if let Some(markup) = rest.strip_prefix("<!") {
+                    // Comments and CDATA sections count as text content
+                    let close = if markup.starts_with("--") { "-->" } else { "]]>" };
+                    let length = rest.find(close).map_or(rest.len(), |end| end + close.len());
+                    tokens.push(Token::Text(&rest[..length]));
+                    length
+                } else if rest.starts_with('<') {
+                    let (length, self_closing) = find_tag_end(rest);
+                    let tag = &rest[..length];
+                    tokens.push(if tag.starts_with("</") {
+                        Token::Close(tag)
+                    } else if self_closing {
+                        Token::Empty(tag)
+                    } else {
+                        Token::Open(tag)
+                    });
+                    length
+                } else {
+                    let length = rest.find('<').unwrap_or(rest.len());
+                    tokens.push(Token::Text(&rest[..length]));
+                    length
+                };
+                rest = &rest[length..];
+            }
+
+            // An element's children are indented when it has child elements and no text
+            let mut indented = vec![false; tokens.len()];
+            let mut open: Vec<(usize, bool, bool)> = Vec::new();
+            for (index, token) in tokens.iter().enumerate() {
+                match token {
+                    Token::Open(_) => {
+                        if let Some(parent) = open.last_mut() {
+                            parent.2 = true;
+                        }
+                        open.push((index, false, false));
+                    }
+                    Token::Empty(_) => {
+                        if let Some(parent) = open.last_mut() {
+                            parent.2 = true;
+                        }
+                    }
+                    Token::Text(_) => {
+                        if let Some(parent) = open.last_mut() {
+                            parent.1 = true;
+                        }
+                    }
+                    Token::Close(_) => {
+                        if let Some((start, has_text, has_children)) = open.pop() {
+                            let indent = has_children && !has_text;
+                            indented[start] = indent;
+                            indented[index] = indent;
+                        }
+                    }
+                }
+            }
+
+            let mut output = String::with_capacity(xml.len() * 2);
+            let mut parents: Vec<bool> = Vec::new();
+            let new_line = |output: &mut String, depth: usize| {
+                output.push('\n');
+                output.push_str(offset);
+                output.push_str(&"    ".repeat(depth));
+            };
+            for (index, token) in tokens.iter().enumerate() {
+                let in_indented = parents.last().copied().unwrap_or(false);
+                match token {
+                    Token::Open(tag) => {
+                        if in_indented {
+                            new_line(&mut output, parents.len());
+                        }
+                        output.push_str(tag);
+                        parents.push(indented[index]);
+                    }
+                    Token::Close(tag) => {
+                        if parents.pop().unwrap_or(false) {
+                            new_line(&mut output, parents.len());
+                        }
+                        output.push_str(tag);
+                    }
+                    Token::Empty(tag) | Token::Text(tag) => {
+                        if in_indented {
+                            new_line(&mut output, parents.len());
+                        }
+                        output.push_str(tag);
+                    }
+                }
+            }
+
+            output
+        }
+
+        // Discovery tools append ?wsdl to the service URL, and ?xsd for a split schema
+        async fn wsdl_query_handler(
+            axum::extract::RawQuery(query): axum::extract::RawQuery,
+            headers: axum::http::HeaderMap,
+        ) -> axum::response::Response {
+            let has_parameter = |name: &str| {
+                query.as_deref().is_some_and(|query| {
+                    query
+                        .split('&')
+                        .any(|parameter| parameter.split('=').next().unwrap_or_default().eq_ignore_ascii_case(name))
+                })
+            };
+            if has_parameter("wsdl") {
+                return wsdl_handler(headers).await;
+            }
+            #xsd_query
+
+            axum::response::Response::builder()
+                .status(405)
+                .header(axum::http::header::ALLOW, "POST")
+                .header("Content-Type", "text/plain; charset=utf-8")
+                .body(#discovery_message.into())
+                .unwrap()
+        }
+
+        #schema_support
+
+        #wsdl_document
+
+        async fn wsdl_handler(headers: axum::http::HeaderMap) -> axum::response::Response {
+            let wsdl = wsdl_document();
 
             axum::response::Response::builder()
                 .status(200)
                 .header("Content-Type", "text/xml; charset=utf-8")
-                .body(wsdl.into())
+                .body(advertise_address(wsdl, &headers).into())
                 .unwrap()
         }
+
+        // The WSDL is generated for localhost, so its addresses are rewritten to the host the
+        // client asked for, with the scheme a TLS-terminating proxy reports in X-Forwarded-Proto
+        fn advertise_address(wsdl: &str, headers: &axum::http::HeaderMap) -> String {
+            let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+            let scheme = header("X-Forwarded-Proto")
+                .and_then(|proto| proto.split(',').next())
+                .map(|proto| proto.trim().to_ascii_lowercase())
+                .filter(|proto| proto == "http" || proto == "https")
+                .unwrap_or_else(|| #address_scheme.to_string());
+            // Only a plain host and port is echoed into the document
+            let host = header("Host")
+                .filter(|host| {
+                    !host.is_empty()
+                        && host.chars().all(|c| c.is_ascii_alphanumeric() || ".-:[]".contains(c))
+                })
+                .unwrap_or("localhost:8080");
+
+            wsdl.replace(
+                concat!("location=\"", #address_origin),
+                &format!("location=\"{}://{}", scheme, host),
+            )
+        }
     };
 
-    // Add the router code to the module
+    // Add the router code to the module, scoped to its own submodule
     if let Some((brace, ref mut items)) = module.content {
         // Parse the router code as items and add them
-        let router_items: syn::File = syn::parse2(router_code).unwrap();
+        let runtime_module = codegen::generate_runtime_module(router_code);
+        let router_items: syn::File = syn::parse2(runtime_module).unwrap();
         items.extend(router_items.items);
         module.content = Some((brace, items.clone()));
     }
@@ -327,45 +994,483 @@ fn generate_enhanced_module(
     quote! { #module }
 }
 
+/// Generates the `serve`, `serve_with` and `shutdown_signal` helpers for running the service.
+/// 
+/// `serve` binds a listener and drains in-flight requests on Ctrl-C or SIGTERM.
+/// `serve_with` takes `ServeOptions` for the concurrency limit, TCP keep-alive, and
+/// per-request timeout; requests over the limit or past the timeout get a `Server` fault
+/// with 503 or 504 instead of queueing. Only emitted with the `runtime` feature, since it
+/// relies on tokio's signal handling.
+fn generate_serve_helper() -> TokenStream2 {
+    if !cfg!(feature = "runtime") {
+        return TokenStream2::new();
+    }
+
+    quote! {
+        /// Tuning for `serve_with`.
+        #[derive(Debug, Clone)]
+        pub struct ServeOptions {
+            /// Requests handled at the same time; excess requests get a 503 fault.
+            pub max_concurrent_requests: usize,
+            /// Enables TCP keep-alive probes on accepted connections.
+            pub tcp_keep_alive: bool,
+            /// Connections the operating system queues before they are accepted.
+            pub listen_backlog: u32,
+            /// Time a request may take before it is answered with a 504 fault.
+            pub request_timeout: Option<std::time::Duration>,
+        }
+
+        impl Default for ServeOptions {
+            fn default() -> Self {
+                ServeOptions {
+                    max_concurrent_requests: 1024,
+                    tcp_keep_alive: true,
+                    listen_backlog: 1024,
+                    request_timeout: Some(std::time::Duration::from_secs(30)),
+                }
+            }
+        }
+
+        pub async fn serve<A>(addr: A) -> std::io::Result<()>
+        where
+            A: tokio::net::ToSocketAddrs,
+        {
+            serve_with(addr, ServeOptions::default()).await
+        }
+
+        pub async fn serve_with<A>(addr: A, options: ServeOptions) -> std::io::Result<()>
+        where
+            A: tokio::net::ToSocketAddrs,
+        {
+            let listener = bind_listener(addr, &options).await?;
+            axum::serve(listener, with_serve_options(router(), &options))
+                .with_graceful_shutdown(shutdown_signal())
+                .await
+        }
+
+        async fn bind_listener<A>(addr: A, options: &ServeOptions) -> std::io::Result<tokio::net::TcpListener>
+        where
+            A: tokio::net::ToSocketAddrs,
+        {
+            let mut last_error = None;
+            for addr in tokio::net::lookup_host(addr).await? {
+                let socket = if addr.is_ipv4() {
+                    tokio::net::TcpSocket::new_v4()?
+                } else {
+                    tokio::net::TcpSocket::new_v6()?
+                };
+                // Accepted connections inherit the keep-alive option of the listening socket
+                socket.set_keepalive(options.tcp_keep_alive)?;
+                #[cfg(unix)]
+                socket.set_reuseaddr(true)?;
+                match socket.bind(addr) {
+                    Ok(()) => return socket.listen(options.listen_backlog),
+                    Err(e) => last_error = Some(e),
+                }
+            }
+
+            Err(last_error.unwrap_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::InvalidInput, "could not resolve to any address")
+            }))
+        }
+
+        /// Applies the concurrency limit and request timeout of `options` to `router`.
+        /// 
+        /// Used by `serve_with`; call it directly when running the router yourself.
+        pub fn with_serve_options(router: axum::Router, options: &ServeOptions) -> axum::Router {
+            let limits = std::sync::Arc::new(ServeLimits {
+                permits: tokio::sync::Semaphore::new(options.max_concurrent_requests),
+                request_timeout: options.request_timeout,
+            });
+            router.layer(axum::middleware::from_fn_with_state(limits, limit_request))
+        }
+
+        struct ServeLimits {
+            permits: tokio::sync::Semaphore,
+            request_timeout: Option<std::time::Duration>,
+        }
+
+        async fn limit_request(
+            axum::extract::State(limits): axum::extract::State<std::sync::Arc<ServeLimits>>,
+            request: axum::extract::Request,
+            next: axum::middleware::Next,
+        ) -> axum::response::Response {
+            let Ok(_permit) = limits.permits.try_acquire() else {
+                return reject_request(
+                    ServiceFault::server("Too many concurrent requests")
+                        .with_status(Some(axum::http::StatusCode::SERVICE_UNAVAILABLE)),
+                );
+            };
+
+            match limits.request_timeout {
+                Some(timeout) => match tokio::time::timeout(timeout, next.run(request)).await {
+                    Ok(response) => response,
+                    Err(_) => reject_request(
+                        ServiceFault::server("Request timed out")
+                            .with_status(Some(axum::http::StatusCode::GATEWAY_TIMEOUT)),
+                    ),
+                },
+                None => next.run(request).await,
+            }
+        }
+
+        pub async fn shutdown_signal() {
+            let ctrl_c = async {
+                tokio::signal::ctrl_c()
+                    .await
+                    .expect("failed to install Ctrl-C handler");
+            };
+
+            #[cfg(unix)]
+            let terminate = async {
+                tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+                    .expect("failed to install SIGTERM handler")
+                    .recv()
+                    .await;
+            };
+
+            #[cfg(not(unix))]
+            let terminate = std::future::pending::<()>();
+
+            tokio::select! {
+                _ = ctrl_c => {},
+                _ = terminate => {},
+            }
+        }
+    }
+}
+
+/// Generates the `ServiceObserver` trait and the `set_observer` registration function.
+/// 
+/// The observer receives per-operation request, success, and fault callbacks so users
+/// can bridge to their metrics backend. A no-op observer is used until one is registered.
+fn generate_observer() -> TokenStream2 {
+    quote! {
+        pub trait ServiceObserver: Send + Sync {
+            fn on_request(&self, _operation: &str) {}
+            fn on_success(&self, _operation: &str, _duration: std::time::Duration) {}
+            fn on_fault(&self, _operation: &str, _duration: std::time::Duration, _code: &str) {}
+        }
+
+        struct NoopObserver;
+
+        impl ServiceObserver for NoopObserver {}
+
+        static OBSERVER: std::sync::RwLock<Option<std::sync::Arc<dyn ServiceObserver>>> =
+            std::sync::RwLock::new(None);
+
+        pub fn set_observer<O>(observer: O)
+        where
+            O: ServiceObserver + 'static,
+        {
+            *OBSERVER.write().unwrap() = Some(std::sync::Arc::new(observer));
+        }
+
+        fn current_observer() -> std::sync::Arc<dyn ServiceObserver> {
+            OBSERVER
+                .read()
+                .unwrap()
+                .clone()
+                .unwrap_or_else(|| std::sync::Arc::new(NoopObserver))
+        }
+    }
+}
+
 /// Generates SOAP operation handlers for dispatching requests to service functions.
 /// 
-/// Creates conditional branches that parse XML requests, call the appropriate async function,
-/// and serialize responses back to SOAP XML format.
+/// The operation element's name and namespace are resolved to the function name in one
+/// `match`, and a second `match` on the function name runs the arm that parses the XML
+/// request, calls the async function, and serializes the response back to SOAP XML.
 fn generate_operation_handlers(
+    config: &parser::ServiceConfig,
     operations: &[parser::SoapOperation],
-    namespace: &str,
 ) -> TokenStream2 {
+    let namespace = config.namespace.as_str();
+    let mut lookup = Vec::new();
     let mut handlers = Vec::new();
 
+    // Namespace-bound operations are tried first, so they take precedence over an
+    // operation of the same name that accepts any namespace
+    let mut operations: Vec<&parser::SoapOperation> = operations.iter().collect();
+    operations.sort_by_key(|operation| operation.namespace.is_none());
+
     for operation in operations {
         let op_name = &operation.name;
         let func_name = &operation.function_name;
         let request_type = &operation.request_type;
         let response_type = &operation.response_type;
+        let response_namespace = operation.namespace.as_deref().unwrap_or(namespace);
+        let codegen::ResponseWrapper {
+            prefix: response_prefix,
+            soap_12_prefix: soap_12_response_prefix,
+            suffix: response_suffix,
+        } = codegen::response_wrapper(config, response_namespace);
+        let response_element = format!("tns:{}Response", op_name);
+        let response_declarations =
+            codegen::element_declarations(&response_element, response_namespace, config.element_form_qualified);
+        let function = func_name.to_string();
+        // Case-insensitive lookups compare lowercased names
+        let lookup_name = if config.case_insensitive_operations { op_name.to_lowercase() } else { op_name.clone() };
+        lookup.push(match &operation.namespace {
+            Some(operation_namespace) => quote! { (#lookup_name, Some(#operation_namespace)) => #function, },
+            None => quote! { (#lookup_name, _) => #function, },
+        });
 
-        handlers.push(quote! {
-            if operation == #op_name {
+        // Operations taking `&Request` borrow the value the dispatcher owns
+        let borrow = if operation.borrows_request { quote! { & } } else { quote! {} };
+
+        // Unit requests carry no data, so deserialization is skipped entirely
+        let (request_parsing, request_arg) = if parser::is_unit_type(request_type) {
+            let parsing = quote! {
+                if !is_empty_element(&body_content) {
+                    return Err(ServiceFault::client(format!("Operation {} does not take any parameters", #op_name)));
+                }
+            };
+            let arg = if operation.takes_request { quote! { #borrow () } } else { quote! {} };
+            (parsing, arg)
+        } else if operation.empty_request {
+            // Requests without fields are built directly, so `<Ping/>` is never deserialized
+            let validate_check = codegen::validate_check(operation, |message| quote! { ServiceFault::client(#message) });
+            let parsing = quote! {
+                let request_data = #request_type {};
+                #validate_check
+            };
+            (parsing, quote! { #borrow request_data })
+        } else {
+            let restriction_check = codegen::restriction_check(operation, |message| quote! { ServiceFault::client(#message) });
+            let validate_check = codegen::validate_check(operation, |message| quote! { ServiceFault::client(#message) });
+            let parsing = quote! {
                 // Generic XML parsing using serde
                 let request_data: #request_type = match parse_request_from_xml(&body_content) {
                     Ok(data) => data,
-                    Err(e) => return Err(format!("Failed to parse request: {}", e)),
+                    Err(e) => return Err(ServiceFault::client(format!("Failed to parse request: {}", e))),
                 };
+                #restriction_check
+                #validate_check
+            };
+            (parsing, quote! { #borrow request_data })
+        };
+
+        // Attachments are taken out before serialization so they stay out of the XML part
+        let (result_binding, take_attachments) = if operation.returns_attachments {
+            (
+                quote! { let mut result },
+                quote! { let attachments = SoapAttachmentResponse::take_attachments(&mut result); },
+            )
+        } else {
+            (quote! { let result }, quote! { let attachments = Vec::new(); })
+        };
 
-                let result: #response_type = #func_name(request_data).await
-                    .map_err(|e| format!("Operation failed: {}", e))?;
+        // Error types implementing SoapFault choose the status of their fault response
+        let fault_status = if operation.maps_fault {
+            quote! { .with_status(SoapFault::http_status(&e)) }
+        } else {
+            quote! {}
+        };
+        // Error enums name the WSDL fault of their variant in the detail
+        let fault_variant = if operation.faults.is_empty() {
+            quote! {}
+        } else {
+            quote! { .with_variant(FaultVariant::fault_name(&e), e.to_string()) }
+        };
+        let operation_fault = quote! {
+            |e| ServiceFault::server(format!("Operation failed: {}", e)) #fault_status #fault_variant
+        };
 
-                // Generic response serialization using serde
-                let response_xml = match serialize_response_to_xml(&result) {
-                    Ok(xml) => xml,
-                    Err(e) => return Err(format!("Failed to serialize response: {}", e)),
-                };
+        // A context is built from the SOAP headers, and a trailing RawXml parameter gets the
+        // operation element as sent
+        let context_construction = codegen::context_construction(
+            operation,
+            quote! { &request.soap_headers },
+            |message| quote! { ServiceFault::client(#message) },
+        );
+        let mut arguments = Vec::new();
+        if !request_arg.is_empty() {
+            arguments.push(request_arg);
+        }
+        if operation.context_type.is_some() {
+            arguments.push(quote! { context });
+        }
+        if operation.takes_raw_xml {
+            arguments.push(quote! { RawXml(request.raw_xml.clone()) });
+        }
+        let arguments = quote! { #(#arguments),* };
 
-                return Ok(create_simple_soap_response(&response_xml, #op_name, #namespace));
+        let result = codegen::await_operation(operation, quote! { #func_name(#arguments) }, |message| {
+            quote! { ServiceFault::server(#message) }
+        });
+
+        let call = if operation.returns_headers {
+            quote! {
+                let response: WithHeaders<#response_type> = #result
+                    .map_err(#operation_fault)?;
+                let headers = response.headers;
+                #result_binding = response.body;
+            }
+        } else {
+            quote! {
+                #result_binding: #response_type = #result
+                    .map_err(#operation_fault)?;
+                let headers = axum::http::HeaderMap::new();
+            }
+        };
+
+        let authorization = match &operation.requires_role {
+            Some(role) => quote! { authorize_role(#op_name, #role)?; },
+            None => quote! {},
+        };
+        let rate_limit = codegen::rate_limit_check(operation, |message| {
+            quote! { ServiceFault::server(#message).with_status(Some(axum::http::StatusCode::TOO_MANY_REQUESTS)) }
+        });
+        let all_failed = codegen::all_failed_check(operation, |message| quote! { ServiceFault::server(#message) });
+
+        // Streamed responses are serialized item by item while the body is sent
+        let response = if operation.returns_stream {
+            // The response element is written around the items, so it is opened by hand
+            let response_open_element = format!("<{}{}>", response_element, response_declarations);
+            let response_close_element = format!("</{}>", response_element);
+            let item_declarations = codegen::element_declarations("", response_namespace, config.element_form_qualified);
+            quote! {
+                return Ok(SoapResponseBody {
+                    envelope: String::new(),
+                    attachments: Vec::new(),
+                    headers,
+                    stream: Some(stream_soap_response(
+                        result,
+                        #response_open_element,
+                        #response_close_element,
+                        #item_declarations,
+                        add_response_header(envelope_prefix(#response_prefix, #soap_12_response_prefix, request.version).to_string(), #op_name),
+                        #response_suffix,
+                    )),
+                });
+            }
+        } else {
+            // Raw bodies are inserted as they are, without serialization
+            let response_xml = if operation.returns_raw_body {
+                quote! { let response_xml = RawSoapBody::body_xml(&result); }
+            } else {
+                quote! {
+                    // Generic response serialization using serde
+                    let response_xml = match serialize_response_element(&result, #response_element, #response_declarations) {
+                        Ok(xml) => xml,
+                        Err(e) => return Err(ServiceFault::server(format!("Failed to serialize response: {}", e))),
+                    };
+                }
+            };
+
+            quote! {
+                #take_attachments
+
+                #response_xml
+
+                return Ok(SoapResponseBody {
+                    envelope: create_simple_soap_response(
+                        &response_xml,
+                        envelope_prefix(#response_prefix, #soap_12_response_prefix, request.version),
+                        #response_suffix,
+                    ),
+                    attachments,
+                    headers,
+                    stream: None,
+                });
+            }
+        };
+
+        // Mock services answer with the registered response instead of calling the operation,
+        // which is only named so it is not reported as unused
+        if config.mock {
+            handlers.push(quote! {
+                #function => {
+                    #rate_limit
+                    #authorization
+                    let _ = #func_name;
+
+                    if let Some(response_xml) = mock_response(#op_name) {
+                        return Ok(SoapResponseBody {
+                            envelope: create_simple_soap_response(
+                                &response_xml,
+                                envelope_prefix(#response_prefix, #soap_12_response_prefix, request.version),
+                                #response_suffix,
+                            ),
+                            attachments: Vec::new(),
+                            headers: axum::http::HeaderMap::new(),
+                            stream: None,
+                        });
+                    }
+                    #result_binding: #response_type = Default::default();
+                    let headers = axum::http::HeaderMap::new();
+                    #response
+                }
+            });
+            continue;
+        }
+
+        handlers.push(quote! {
+            #function => {
+                #rate_limit
+                #authorization
+                #context_construction
+                #request_parsing
+
+                #call
+                #all_failed
+                #response
             }
         });
     }
 
+    let lookup_operation = if config.case_insensitive_operations {
+        quote! { operation.to_lowercase().as_str() }
+    } else {
+        quote! { operation.as_str() }
+    };
+
+    quote! {
+        // Operation routes name their function, other requests are resolved by element name
+        let function = match route {
+            Some(route) => route.function,
+            None => match (#lookup_operation, namespace) {
+                #(#lookup)*
+                _ => "",
+            },
+        };
+
+        match function {
+            #(#handlers)*
+            _ => {}
+        }
+    }
+}
+
+/// Generates the `strict_namespace` check run before dispatch.
+/// 
+/// The operation element must be in the service namespace, or in the namespace of an
+/// operation bound with `#[operation(namespace = "...")]`. Expands to nothing in the
+/// default lenient mode.
+fn generate_namespace_validation(
+    config: &parser::ServiceConfig,
+    operations: &[parser::SoapOperation],
+) -> TokenStream2 {
+    if !config.strict_namespace {
+        return TokenStream2::new();
+    }
+
+    let service_namespace = &config.namespace;
+    let mut allowed = vec![service_namespace.clone()];
+    for operation_namespace in operations.iter().filter_map(|operation| operation.namespace.clone()) {
+        if !allowed.contains(&operation_namespace) {
+            allowed.push(operation_namespace);
+        }
+    }
+
     quote! {
-        #(#handlers)*
+        if !namespace.is_some_and(|namespace| [#(#allowed),*].contains(&namespace)) {
+            return Err(ServiceFault::client(format!(
+                "Operation element namespace {} does not match the service namespace {}",
+                namespace.unwrap_or("(none)"),
+                #service_namespace
+            )));
+        }
     }
 }
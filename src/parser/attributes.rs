@@ -1,34 +1,174 @@
 //! Parse #[service(...)] attributes
 
 use proc_macro2::TokenStream;
-use syn::{parse::Parse, punctuated::Punctuated, Error, Expr, ExprLit, Ident, Lit, Result, Token};
+use syn::{
+    parse::Parse, punctuated::Punctuated, Error, Expr, ExprLit, Ident, Lit, LitBool, Result, Token,
+};
 
 #[derive(Debug, Clone)]
 pub struct ServiceConfig {
+    /// The target namespace of the WSDL and of the operation elements (`namespace`, required).
     pub namespace: String,
+    /// The WSDL service name (`service_name`, required).
     pub service_name: String,
+    /// The WSDL port name (`port_name`, required).
     pub port_name: String,
+    /// The path the SOAP endpoint is served at (`bind_path`, required).
     pub bind_path: String,
+    /// The path the WSDL is served at (`wsdl_path`, `{bind_path}/wsdl` by default).
+    pub wsdl_path: String,
+    /// The largest request body accepted (`max_request_bytes`, 10 MiB by default).
+    pub max_request_bytes: usize,
+    /// The deepest element nesting accepted in requests (`max_depth`, 64 by default).
+    pub max_depth: usize,
+    /// The default operation timeout (`timeout_ms`), which operations may override.
+    pub timeout_ms: Option<u64>,
+    /// The namespace of a shared types schema (`types_namespace`, with `schema_import`).
+    pub types_namespace: Option<String>,
+    /// The location of the shared types schema the WSDL imports (`schema_import`).
+    pub schema_import: Option<String>,
+    /// The WSDL documentation of the service element (`service_documentation`).
+    pub service_documentation: Option<String>,
+    /// The WSDL documentation of the port element (`port_documentation`).
+    pub port_documentation: Option<String>,
+    /// The version named in the WSDL documentation (`version`).
+    pub version: Option<String>,
+    /// The build named in the WSDL documentation (`build_info`).
+    pub build_info: Option<String>,
+    /// Whether a typed `client` module is generated (`generate_client`).
+    pub generate_client: bool,
+    /// Whether operation elements outside the service namespace are refused (`strict_namespace`).
+    pub strict_namespace: bool,
+    /// The path of the JSON description of the operations (`json_schema_path`).
+    pub json_schema_path: Option<String>,
+    /// Whether the operations are also served as JSON under `{bind_path}/json` (`json_api`).
+    pub json_api: bool,
+    /// The URI faults name as their `faultactor`, and headers may target (`fault_actor`).
+    pub fault_actor: Option<String>,
+    /// The `mustUnderstand` headers accepted, as optional namespace and local name
+    /// (`understood_headers`).
+    pub understood_headers: Vec<(Option<String>, String)>,
+    /// Whether child elements are in the target namespace (`element_form`, qualified by
+    /// default).
+    pub element_form_qualified: bool,
+    /// Whether operations need Basic credentials (`auth = "basic"`).
+    pub basic_auth: bool,
+    /// Whether functions with an operation's signature that are not `pub async` are
+    /// rejected (`strict`).
+    pub strict: bool,
+    /// Whether only functions marked `#[operation]` are operations (`operations = "explicit"`).
+    pub explicit_operations: bool,
+    /// Whether the schema is served on its own and imported by the WSDL (`split_schema`).
+    pub split_schema: bool,
+    /// Whether responses and the WSDL are indented (`pretty_print`).
+    pub pretty_print: bool,
+    /// Whether faults carry the request that caused them (`echo_request_in_fault`).
+    pub echo_request_in_fault: bool,
+    /// Whether operation elements are matched ignoring case (`case_insensitive_operations`).
+    pub case_insensitive_operations: bool,
+    /// Whether WS-ReliableMessaging sequences are acknowledged (`ws_rm`).
+    pub ws_rm: bool,
+    /// Whether SOAP 1.2 is accepted next to SOAP 1.1 (`soap_versions = "1.1, 1.2"`).
+    pub soap_12: bool,
+    /// Whether requests are checked against their restriction facets (`validate_restrictions`).
+    pub validate_restrictions: bool,
+    /// The scheme of the WSDL address without `X-Forwarded-Proto` (`address_scheme`,
+    /// `"http"` by default).
+    pub address_scheme: String,
+    /// Whether the WSDL links a stylesheet rendering it in browsers (`wsdl_stylesheet`).
+    pub wsdl_stylesheet: bool,
+    /// The prefix of the envelope namespace in responses (`envelope_prefix`, `"soap"` by
+    /// default).
+    pub envelope_prefix: String,
+    /// The WS-Policy the WSDL bindings reference (`policy_url`).
+    pub policy_url: Option<String>,
+    /// Whether requests are answered with canned responses instead of the operations
+    /// (`mock`).
+    pub mock: bool,
 }
 
+/// Default limit for incoming request bodies (10 MiB).
+const DEFAULT_MAX_REQUEST_BYTES: usize = 10 * 1024 * 1024;
+
+/// Default limit for element nesting in incoming envelopes.
+const DEFAULT_MAX_DEPTH: usize = 64;
+
 struct ServiceAttribute {
     name: Ident,
-    value: String,
+    value: Expr,
+}
+
+impl ServiceAttribute {
+    /// Returns the value of a string literal attribute.
+    fn string_value(&self) -> Result<String> {
+        match &self.value {
+            Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) => Ok(lit_str.value()),
+            other => Err(Error::new_spanned(other, "Expected string literal")),
+        }
+    }
+
+    /// Returns the entries of a list attribute, given as an array of string literals or
+    /// as one comma-separated string literal.
+    fn string_list_value(&self) -> Result<Vec<String>> {
+        match &self.value {
+            Expr::Array(array) => array
+                .elems
+                .iter()
+                .map(|elem| match elem {
+                    Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) => Ok(lit_str.value()),
+                    other => Err(Error::new_spanned(other, "Expected string literal")),
+                })
+                .collect(),
+            _ => Ok(self.string_value()?.split(',').map(|entry| entry.trim().to_string()).collect()),
+        }
+    }
+
+    /// Returns the value of an integer literal attribute, rejecting zero.
+    fn positive_integer_value(&self) -> Result<usize> {
+        match &self.value {
+            Expr::Lit(ExprLit { lit: Lit::Int(lit_int), .. }) => {
+                let value = lit_int.base10_parse::<usize>()?;
+                if value == 0 {
+                    return Err(Error::new_spanned(
+                        lit_int,
+                        format!("{} must be a positive integer", self.name),
+                    ));
+                }
+                Ok(value)
+            }
+            other => Err(Error::new_spanned(other, "Expected integer literal")),
+        }
+    }
+
+    /// Returns the value of a boolean attribute; bare flags like `generate_client` are `true`.
+    fn bool_value(&self) -> Result<bool> {
+        match &self.value {
+            Expr::Lit(ExprLit { lit: Lit::Bool(lit_bool), .. }) => Ok(lit_bool.value),
+            other => Err(Error::new_spanned(other, "Expected boolean literal")),
+        }
+    }
 }
 
 impl Parse for ServiceAttribute {
     fn parse(input: syn::parse::ParseStream) -> Result<Self> {
         let name: Ident = input.parse()?;
+        
+        // A bare attribute name is a flag, equivalent to `name = true`
+        if !input.peek(Token![=]) {
+            let value = Expr::Lit(ExprLit {
+                attrs: Vec::new(),
+                lit: Lit::Bool(LitBool::new(true, name.span())),
+            });
+            return Ok(ServiceAttribute { name, value });
+        }
+        
         input.parse::<Token![=]>()?;
         let expr: Expr = input.parse()?;
         
-        if let Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) = expr {
-            Ok(ServiceAttribute {
-                name,
-                value: lit_str.value(),
-            })
-        } else {
-            Err(Error::new_spanned(expr, "Expected string literal"))
+        // Arrays are only meaningful for list attributes, which check their elements
+        match expr {
+            Expr::Lit(_) | Expr::Array(_) => Ok(ServiceAttribute { name, value: expr }),
+            _ => Err(Error::new_spanned(expr, "Expected literal")),
         }
     }
 }
@@ -47,8 +187,9 @@ impl Parse for ServiceAttributes {
 
 /// Parses the #[service(...)] attribute arguments into a validated ServiceConfig.
 /// 
-/// Expects namespace, service_name, port_name, and bind_path attributes.
-/// Validates that all required fields are present and properly formatted.
+/// The attributes and their defaults are described on the `ServiceConfig` fields.
+/// Required attributes must be present, values must be well-formed, and the paths of
+/// the service routes must not collide.
 pub fn parse_service_attributes(args: TokenStream) -> Result<ServiceConfig> {
     let parsed = syn::parse2::<ServiceAttributes>(args)?;
     
@@ -56,24 +197,222 @@ pub fn parse_service_attributes(args: TokenStream) -> Result<ServiceConfig> {
     let mut service_name = None;
     let mut port_name = None;
     let mut bind_path = None;
+    let mut wsdl_path = None;
+    let mut max_request_bytes = None;
+    let mut max_depth = None;
+    let mut timeout_ms = None;
+    let mut types_namespace = None;
+    let mut schema_import = None;
+    let mut service_documentation = None;
+    let mut port_documentation = None;
+    let mut version = None;
+    let mut build_info = None;
+    let mut generate_client = false;
+    let mut strict_namespace = false;
+    let mut json_schema_path = None;
+    let mut json_api = false;
+    let mut fault_actor = None;
+    let mut understood_headers = Vec::new();
+    let mut element_form_qualified = true;
+    let mut basic_auth = false;
+    let mut strict = false;
+    let mut explicit_operations = false;
+    let mut split_schema = false;
+    let mut pretty_print = false;
+    let mut echo_request_in_fault = false;
+    let mut case_insensitive_operations = false;
+    let mut ws_rm = false;
+    let mut soap_12 = false;
+    let mut validate_restrictions = false;
+    let mut address_scheme = "http".to_string();
+    let mut wsdl_stylesheet = false;
+    let mut envelope_prefix = "soap".to_string();
+    let mut policy_url = None;
+    let mut mock = false;
     
     for attr in parsed.attributes {
         match attr.name.to_string().as_str() {
             "namespace" => {
-                validate_namespace(&attr.value)?;
-                namespace = Some(attr.value);
+                let value = attr.string_value()?;
+                validate_namespace(&value).map_err(|e| Error::new_spanned(&attr.value, e))?;
+                namespace = Some(value);
             }
             "service_name" => {
-                validate_identifier(&attr.value, "service_name")?;
-                service_name = Some(attr.value);
+                let value = attr.string_value()?;
+                validate_identifier(&value, "service_name")
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+                service_name = Some(value);
             }
             "port_name" => {
-                validate_identifier(&attr.value, "port_name")?;
-                port_name = Some(attr.value);
+                let value = attr.string_value()?;
+                validate_identifier(&value, "port_name")
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+                port_name = Some(value);
             }
             "bind_path" => {
-                validate_bind_path(&attr.value)?;
-                bind_path = Some(attr.value);
+                let value = attr.string_value()?;
+                validate_bind_path(&value, "bind_path")
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+                bind_path = Some(value);
+            }
+            "wsdl_path" => {
+                let value = attr.string_value()?;
+                validate_bind_path(&value, "wsdl_path")
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+                wsdl_path = Some(value);
+            }
+            "max_request_bytes" => {
+                max_request_bytes = Some(attr.positive_integer_value()?);
+            }
+            "max_depth" => {
+                max_depth = Some(attr.positive_integer_value()?);
+            }
+            "timeout_ms" => {
+                if !cfg!(feature = "runtime") {
+                    return Err(Error::new_spanned(&attr.name, "timeout_ms requires the runtime feature"));
+                }
+                timeout_ms = Some(attr.positive_integer_value()? as u64);
+            }
+            "types_namespace" => {
+                let value = attr.string_value()?;
+                validate_namespace(&value).map_err(|e| Error::new_spanned(&attr.value, e))?;
+                types_namespace = Some(value);
+            }
+            "schema_import" => {
+                let value = attr.string_value()?;
+                validate_uri(&value, "schema_import")
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+                schema_import = Some(value);
+            }
+            "service_documentation" => {
+                let value = attr.string_value()?;
+                validate_non_empty(&value, "service_documentation")
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+                service_documentation = Some(value);
+            }
+            "port_documentation" => {
+                let value = attr.string_value()?;
+                validate_non_empty(&value, "port_documentation")
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+                port_documentation = Some(value);
+            }
+            "version" => {
+                let value = attr.string_value()?;
+                validate_non_empty(&value, "version")
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+                version = Some(value);
+            }
+            "build_info" => {
+                let value = attr.string_value()?;
+                validate_non_empty(&value, "build_info")
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+                build_info = Some(value);
+            }
+            "generate_client" => {
+                generate_client = attr.bool_value()?;
+            }
+            "strict_namespace" => {
+                strict_namespace = attr.bool_value()?;
+            }
+            "json_schema_path" => {
+                let value = attr.string_value()?;
+                validate_bind_path(&value, "json_schema_path")
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+                json_schema_path = Some(value);
+            }
+            "json_api" => {
+                json_api = attr.bool_value()?;
+            }
+            "fault_actor" => {
+                let value = attr.string_value()?;
+                validate_uri(&value, "fault_actor")
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+                fault_actor = Some(value);
+            }
+            "understood_headers" => {
+                let value = attr.string_value()?;
+                understood_headers = parse_understood_headers(&value)
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+            }
+            "element_form" => {
+                element_form_qualified = match attr.string_value()?.as_str() {
+                    "qualified" => true,
+                    "unqualified" => false,
+                    _ => {
+                        return Err(Error::new_spanned(
+                            &attr.value,
+                            "element_form must be \"qualified\" or \"unqualified\"",
+                        ));
+                    }
+                };
+            }
+            "auth" => {
+                basic_auth = match attr.string_value()?.as_str() {
+                    "basic" => true,
+                    _ => {
+                        return Err(Error::new_spanned(&attr.value, "auth must be \"basic\""));
+                    }
+                };
+            }
+            "strict" => {
+                strict = attr.bool_value()?;
+            }
+            "split_schema" => {
+                split_schema = attr.bool_value()?;
+            }
+            "pretty_print" => {
+                pretty_print = attr.bool_value()?;
+            }
+            "echo_request_in_fault" => {
+                echo_request_in_fault = attr.bool_value()?;
+            }
+            "case_insensitive_operations" => {
+                case_insensitive_operations = attr.bool_value()?;
+            }
+            "ws_rm" => {
+                ws_rm = attr.bool_value()?;
+            }
+            "validate_restrictions" => {
+                validate_restrictions = attr.bool_value()?;
+            }
+            "address_scheme" => {
+                address_scheme = attr.string_value()?;
+                if address_scheme != "http" && address_scheme != "https" {
+                    return Err(Error::new_spanned(&attr.value, "address_scheme must be \"http\" or \"https\""));
+                }
+            }
+            "wsdl_stylesheet" => {
+                wsdl_stylesheet = attr.bool_value()?;
+            }
+            "envelope_prefix" => {
+                envelope_prefix = attr.string_value()?;
+                validate_envelope_prefix(&envelope_prefix)
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+            }
+            "policy_url" => {
+                let value = attr.string_value()?;
+                validate_policy_url(&value).map_err(|e| Error::new_spanned(&attr.value, e))?;
+                policy_url = Some(value);
+            }
+            "mock" => {
+                mock = attr.bool_value()?;
+            }
+            "soap_versions" => {
+                let versions = attr.string_list_value()?;
+                soap_12 = parse_soap_versions(&versions)
+                    .map_err(|e| Error::new_spanned(&attr.value, e))?;
+            }
+            "operations" => {
+                explicit_operations = match attr.string_value()?.as_str() {
+                    "implicit" => false,
+                    "explicit" => true,
+                    _ => {
+                        return Err(Error::new_spanned(
+                            &attr.value,
+                            "operations must be \"implicit\" or \"explicit\"",
+                        ));
+                    }
+                };
             }
             _ => {
                 return Err(Error::new_spanned(
@@ -98,16 +437,111 @@ pub fn parse_service_attributes(args: TokenStream) -> Result<ServiceConfig> {
         Error::new(proc_macro2::Span::call_site(), "Missing required attribute: bind_path")
     })?;
     
+    // A shared schema import needs both its namespace and its location
+    if types_namespace.is_some() != schema_import.is_some() {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            "types_namespace and schema_import must be specified together",
+        ));
+    }
+    
+    let wsdl_path = wsdl_path.unwrap_or_else(|| format!("{}/wsdl", bind_path));
+    if wsdl_path == bind_path {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            "wsdl_path must differ from bind_path; the WSDL is also served at {bind_path}?wsdl",
+        ));
+    }
+    let json_path = format!("{}/json", bind_path);
+    if split_schema && wsdl_path == format!("{}/schema.xsd", bind_path)
+        || json_api && (wsdl_path == json_path || wsdl_path.starts_with(&format!("{}/", json_path)))
+    {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            "wsdl_path conflicts with the service routes",
+        ));
+    }
+    
+    // The JSON description needs a route of its own
+    if let Some(path) = &json_schema_path {
+        if *path == bind_path || *path == wsdl_path {
+            return Err(Error::new(
+                proc_macro2::Span::call_site(),
+                "json_schema_path must differ from bind_path and the WSDL path",
+            ));
+        }
+        if split_schema && *path == format!("{}/schema.xsd", bind_path) {
+            return Err(Error::new(
+                proc_macro2::Span::call_site(),
+                "json_schema_path must differ from the split schema path",
+            ));
+        }
+    }
+    
+    // The JSON routes call the operations directly, which a mock service never does
+    if mock && json_api {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            "mock cannot be combined with json_api",
+        ));
+    }
+    
+    // The stylesheet is served next to the WSDL
+    let stylesheet_path = format!("{}/wsdl.xsl", bind_path);
+    if wsdl_stylesheet
+        && (wsdl_path == stylesheet_path || json_schema_path.as_ref() == Some(&stylesheet_path))
+    {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            "wsdl_path and json_schema_path must differ from the stylesheet path",
+        ));
+    }
+    
     Ok(ServiceConfig {
         namespace,
         service_name,
         port_name,
         bind_path,
+        wsdl_path,
+        max_request_bytes: max_request_bytes.unwrap_or(DEFAULT_MAX_REQUEST_BYTES),
+        max_depth: max_depth.unwrap_or(DEFAULT_MAX_DEPTH),
+        timeout_ms,
+        types_namespace,
+        schema_import,
+        service_documentation,
+        port_documentation,
+        version,
+        build_info,
+        generate_client,
+        strict_namespace,
+        json_schema_path,
+        json_api,
+        fault_actor,
+        understood_headers,
+        element_form_qualified,
+        basic_auth,
+        strict,
+        explicit_operations,
+        split_schema,
+        pretty_print,
+        echo_request_in_fault,
+        case_insensitive_operations,
+        ws_rm,
+        soap_12,
+        validate_restrictions,
+        address_scheme,
+        wsdl_stylesheet,
+        envelope_prefix,
+        policy_url,
+        mock,
     })
 }
 
-/// Validates that the namespace is a proper URI starting with http:// or https://.
-fn validate_namespace(namespace: &str) -> Result<()> {
+/// Validates that the namespace is an absolute URI.
+/// 
+/// Any scheme is accepted; `http(s)` namespaces need a host and `urn:` namespaces
+/// must be well-formed names. Relative references are rejected.
+pub(crate) fn validate_namespace(namespace: &str) -> Result<()> {
     if namespace.is_empty() {
         return Err(Error::new(
             proc_macro2::Span::call_site(),
@@ -115,19 +549,78 @@ fn validate_namespace(namespace: &str) -> Result<()> {
         ));
     }
     
-    // Basic URI validation - should start with http:// or https://
-    if !namespace.starts_with("http://") && !namespace.starts_with("https://") {
+    // Characters that may not appear unescaped anywhere in a URI
+    if namespace
+        .chars()
+        .any(|c| c.is_whitespace() || c.is_control() || "<>\"{}|\\^`".contains(c))
+    {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            format!("Namespace {:?} contains characters that are not allowed in a URI", namespace),
+        ));
+    }
+    
+    let (scheme, rest) = match namespace.split_once(':') {
+        Some((scheme, rest)) if is_uri_scheme(scheme) => (scheme.to_ascii_lowercase(), rest),
+        _ => {
+            return Err(Error::new(
+                proc_macro2::Span::call_site(),
+                format!("Namespace {:?} must be an absolute URI with a scheme, e.g. http://... or urn:...", namespace),
+            ));
+        }
+    };
+    
+    let problem = match scheme.as_str() {
+        "http" | "https" => {
+            let host = rest
+                .strip_prefix("//")
+                .map(|rest| rest.split(['/', '?', '#']).next().unwrap_or_default());
+            match host {
+                Some(host) if !host.is_empty() && !host.starts_with(':') => None,
+                _ => Some("an http(s) namespace needs a host"),
+            }
+        }
+        "urn" if !is_urn(namespace) => Some("a urn: namespace must have the form urn:<nid>:<nss>"),
+        _ if rest.is_empty() => Some("the namespace has nothing after its scheme"),
+        _ => None,
+    };
+    if let Some(problem) = problem {
         return Err(Error::new(
             proc_macro2::Span::call_site(),
-            "Namespace must be a valid URI starting with http:// or https://",
+            format!("Namespace {:?} is not a valid absolute URI: {}", namespace, problem),
         ));
     }
     
     Ok(())
 }
 
+/// Checks whether a value is a URI scheme: a letter followed by letters, digits, `+`, `-`, or `.`.
+fn is_uri_scheme(value: &str) -> bool {
+    let mut chars = value.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Checks whether a value is a `urn:<nid>:<nss>` name (RFC 8141).
+fn is_urn(value: &str) -> bool {
+    let rest = match value.get(..4) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("urn:") => &value[4..],
+        _ => return false,
+    };
+    let (nid, nss) = match rest.split_once(':') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    
+    let valid_nid = (2..=32).contains(&nid.len())
+        && nid.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && !nid.starts_with('-')
+        && !nid.ends_with('-');
+    valid_nid && !nss.is_empty()
+}
+
 /// Validates that a value is a proper identifier (alphanumeric + underscores, starts with letter/underscore).
-fn validate_identifier(value: &str, field_name: &str) -> Result<()> {
+pub(crate) fn validate_identifier(value: &str, field_name: &str) -> Result<()> {
     if value.is_empty() {
         return Err(Error::new(
             proc_macro2::Span::call_site(),
@@ -153,21 +646,148 @@ fn validate_identifier(value: &str, field_name: &str) -> Result<()> {
     Ok(())
 }
 
-/// Validates that the bind path starts with '/' and is not just the root path.
-fn validate_bind_path(path: &str) -> Result<()> {
+/// Validates that the envelope prefix is an NCName, i.e. an XML name without a colon.
+/// 
+/// Prefixes starting with `xml` are reserved, and `tns` is taken by the service namespace
+/// in response envelopes.
+fn validate_envelope_prefix(prefix: &str) -> Result<()> {
+    let mut chars = prefix.chars();
+    let is_ncname = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+    if !is_ncname {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            format!("envelope_prefix {:?} must be an XML name without a colon, e.g. \"soapenv\"", prefix),
+        ));
+    }
+    
+    if prefix.to_ascii_lowercase().starts_with("xml") || prefix == "tns" {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            format!("envelope_prefix {:?} is reserved", prefix),
+        ));
+    }
+    
+    Ok(())
+}
+
+/// Validates that the policy URL is an absolute `http(s)` URL that governance tooling can fetch.
+fn validate_policy_url(url: &str) -> Result<()> {
+    validate_uri(url, "policy_url")?;
+    
+    let host = url
+        .strip_prefix("http://")
+        .or_else(|| url.strip_prefix("https://"))
+        .map(|rest| rest.split(['/', '?', '#']).next().unwrap_or_default());
+    match host {
+        Some(host) if !host.is_empty() && !host.starts_with(':') => Ok(()),
+        _ => Err(Error::new(
+            proc_macro2::Span::call_site(),
+            format!("policy_url {:?} must be an absolute http:// or https:// URL", url),
+        )),
+    }
+}
+
+/// Validates that a route path starts with '/' and is not just the root path.
+pub(crate) fn validate_bind_path(path: &str, field_name: &str) -> Result<()> {
     if !path.starts_with('/') {
         return Err(Error::new(
             proc_macro2::Span::call_site(),
-            "bind_path must start with '/'",
+            format!("{} must start with '/'", field_name),
         ));
     }
     
     if path == "/" {
         return Err(Error::new(
             proc_macro2::Span::call_site(),
-            "bind_path cannot be just '/'",
+            format!("{} cannot be just '/'", field_name),
         ));
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Parses a comma-separated list of header names, each a local name or `{namespace}LocalName`.
+fn parse_understood_headers(value: &str) -> Result<Vec<(Option<String>, String)>> {
+    let mut headers = Vec::new();
+    
+    for entry in value.split(',').map(str::trim) {
+        let (namespace, local_name) = match entry.strip_prefix('{').and_then(|rest| rest.split_once('}')) {
+            Some((namespace, local_name)) => (Some(namespace.to_string()), local_name),
+            None => (None, entry),
+        };
+        
+        let invalid_namespace = namespace.as_ref().is_some_and(|namespace| namespace.is_empty());
+        let invalid_name = local_name.is_empty()
+            || local_name.contains(|c: char| c.is_whitespace() || matches!(c, ':' | '{' | '}'));
+        if invalid_namespace || invalid_name {
+            return Err(Error::new(
+                proc_macro2::Span::call_site(),
+                format!("understood_headers entry '{}' must be a local name or {{namespace}}LocalName", entry),
+            ));
+        }
+        
+        headers.push((namespace, local_name.to_string()));
+    }
+    
+    Ok(headers)
+}
+
+/// Validates the listed SOAP versions, returning whether SOAP 1.2 is served too.
+/// 
+/// SOAP 1.1 is always served, since faults to envelopes of an unknown version use it.
+fn parse_soap_versions(listed: &[String]) -> Result<bool> {
+    let mut versions: Vec<&str> = Vec::new();
+    
+    for version in listed.iter().map(String::as_str) {
+        if !matches!(version, "1.1" | "1.2") {
+            return Err(Error::new(
+                proc_macro2::Span::call_site(),
+                format!("Unsupported SOAP version '{}'; expected \"1.1\" or \"1.2\"", version),
+            ));
+        }
+        if versions.contains(&version) {
+            return Err(Error::new(
+                proc_macro2::Span::call_site(),
+                format!("SOAP version {} is listed more than once", version),
+            ));
+        }
+        versions.push(version);
+    }
+    
+    if !versions.contains(&"1.1") {
+        return Err(Error::new(proc_macro2::Span::call_site(), "soap_versions must include \"1.1\""));
+    }
+    Ok(versions.contains(&"1.2"))
+}
+
+/// Validates that an optional free-text value is not empty or whitespace-only.
+fn validate_non_empty(value: &str, field_name: &str) -> Result<()> {
+    if value.trim().is_empty() {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            format!("{} cannot be empty", field_name),
+        ));
+    }
+    
+    Ok(())
+}
+
+/// Validates that a URI value (a schema location or fault actor) is non-empty and contains no whitespace.
+fn validate_uri(uri: &str, field_name: &str) -> Result<()> {
+    if uri.is_empty() {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            format!("{} cannot be empty", field_name),
+        ));
+    }
+    
+    if uri.chars().any(char::is_whitespace) {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            format!("{} must be a valid URL without whitespace", field_name),
+        ));
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,396 @@
+//! Parse #[soap(...)] field and struct attributes
+
+use syn::{
+    punctuated::Punctuated, Attribute, Error, Expr, ExprLit, Field, ItemStruct, Lit, LitInt,
+    LitStr, Meta, Result, Token,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct SoapFieldAttributes {
+    pub scale: Option<usize>,
+    pub attribute: bool,
+    pub entry: Option<String>,
+    pub default: Option<String>,
+    pub facets: Facets,
+    /// XSD type declared for the field instead of the one derived from its Rust type.
+    pub xsd: Option<String>,
+}
+
+/// XSD restriction facets of a simple-typed field, in their lexical form.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Facets {
+    pub pattern: Option<String>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub min: Option<String>,
+    pub max: Option<String>,
+}
+
+impl Facets {
+    pub fn is_empty(&self) -> bool {
+        *self == Facets::default()
+    }
+}
+
+/// Parses the `#[soap(...)]` attributes on a struct field.
+/// 
+/// Supported keys: `scale = N` fixes the number of decimal places for float fields,
+/// `attribute` serializes the field as an XML attribute of the wrapper element,
+/// `entry = "..."` names the repeated entry element of a map field, and
+/// `default = "..."` gives a simple-typed field the value used when it is missing (or
+/// `xsi:nil`), in its XSD lexical form. The value is checked against the built-in XSD types.
+/// 
+/// Restriction facets: `pattern = "..."`, `min_length = N` and `max_length = N` apply to
+/// `String` fields, and `min = N` and `max = N` (inclusive) to numeric fields. They are
+/// emitted on the field's XSD type.
+/// 
+/// `xsd = "anyURI"` declares a `String` field with another built-in XSD type, e.g.
+/// `QName`, `duration`, or `token`, for contracts that require it. The value is passed
+/// through as written.
+pub fn parse_soap_field_attributes(field: &Field) -> Result<SoapFieldAttributes> {
+    let mut attributes = SoapFieldAttributes::default();
+    
+    for attr in field.attrs.iter().filter(|attr| is_soap_attribute(attr)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("scale") {
+                let lit: LitInt = meta.value()?.parse()?;
+                attributes.scale = Some(lit.base10_parse()?);
+                Ok(())
+            } else if meta.path.is_ident("attribute") {
+                attributes.attribute = true;
+                Ok(())
+            } else if meta.path.is_ident("entry") {
+                let lit: LitStr = meta.value()?.parse()?;
+                crate::parser::validate_identifier(&lit.value(), "entry")
+                    .map_err(|e| Error::new_spanned(&lit, e))?;
+                attributes.entry = Some(lit.value());
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                let lit: LitStr = meta.value()?.parse()?;
+                let value_type = crate::parser::option_inner_type(&field.ty).unwrap_or(&field.ty);
+                if !is_valid_default(&lit.value(), &crate::parser::xsd_type_name(value_type)) {
+                    return Err(Error::new_spanned(
+                        &lit,
+                        format!("default {:?} is not a valid value of the field's type", lit.value()),
+                    ));
+                }
+                attributes.default = Some(lit.value());
+                Ok(())
+            } else if meta.path.is_ident("pattern") {
+                let lit: LitStr = meta.value()?.parse()?;
+                crate::parser::parse_pattern(&lit.value())
+                    .map_err(|e| Error::new_spanned(&lit, format!("Invalid pattern: {}", e)))?;
+                attributes.facets.pattern = Some(lit.value());
+                Ok(())
+            } else if meta.path.is_ident("min_length") {
+                let lit: LitInt = meta.value()?.parse()?;
+                attributes.facets.min_length = Some(lit.base10_parse()?);
+                Ok(())
+            } else if meta.path.is_ident("max_length") {
+                let lit: LitInt = meta.value()?.parse()?;
+                attributes.facets.max_length = Some(lit.base10_parse()?);
+                Ok(())
+            } else if meta.path.is_ident("min") {
+                attributes.facets.min = Some(parse_bound(&meta.value()?.parse()?, field)?);
+                Ok(())
+            } else if meta.path.is_ident("max") {
+                attributes.facets.max = Some(parse_bound(&meta.value()?.parse()?, field)?);
+                Ok(())
+            } else if meta.path.is_ident("xsd") {
+                let lit: LitStr = meta.value()?.parse()?;
+                let value = lit.value();
+                let name = value.strip_prefix("xsd:").unwrap_or(&value);
+                if !XSD_BUILT_IN_TYPES.contains(&name) {
+                    return Err(Error::new_spanned(
+                        &lit,
+                        format!("xsd {:?} is not a built-in XSD simple type", value),
+                    ));
+                }
+                attributes.xsd = Some(format!("xsd:{}", name));
+                Ok(())
+            } else {
+                Err(meta.error("Unknown soap field attribute"))
+            }
+        })?;
+    }
+    
+    if attributes.scale.is_some() && !crate::parser::is_float_field_type(&field.ty) {
+        return Err(Error::new_spanned(
+            &field.ty,
+            "scale can only be applied to f32 and f64 fields",
+        ));
+    }
+    
+    if attributes.attribute && !crate::parser::is_simple_field_type(&field.ty) {
+        return Err(Error::new_spanned(
+            &field.ty,
+            "attribute can only be applied to fields with a simple XSD type",
+        ));
+    }
+    
+    if attributes.entry.is_some() && crate::parser::map_inner_types(&field.ty).is_none() {
+        return Err(Error::new_spanned(
+            &field.ty,
+            "entry can only be applied to HashMap and BTreeMap fields",
+        ));
+    }
+    
+    if attributes.default.is_some() {
+        if !crate::parser::is_simple_field_type(&field.ty) {
+            return Err(Error::new_spanned(
+                &field.ty,
+                "default can only be applied to fields with a simple XSD type",
+            ));
+        }
+        if has_serde_key(field, "default") {
+            return Err(Error::new_spanned(
+                &field.ty,
+                "default cannot be combined with #[serde(default)]",
+            ));
+        }
+    }
+    
+    if attributes.xsd.is_some() {
+        let value_type = crate::parser::field_value_type(&field.ty);
+        if crate::parser::map_inner_types(&field.ty).is_some() || crate::parser::xsd_type_name(value_type) != "xsd:string" {
+            return Err(Error::new_spanned(
+                &field.ty,
+                "xsd can only be applied to String fields",
+            ));
+        }
+    }
+    
+    validate_facets(field, &attributes.facets)?;
+    
+    Ok(attributes)
+}
+
+/// The built-in XSD simple types a `String` field may be declared as with `xsd = "..."`.
+const XSD_BUILT_IN_TYPES: &[&str] = &[
+    "string", "normalizedString", "token", "language", "Name", "NCName", "NMTOKEN", "NMTOKENS",
+    "ID", "IDREF", "IDREFS", "ENTITY", "ENTITIES", "anyURI", "QName", "duration", "dateTime",
+    "date", "time", "gYear", "gYearMonth", "gMonth", "gMonthDay", "gDay", "boolean",
+    "base64Binary", "hexBinary", "float", "double", "decimal", "integer", "nonPositiveInteger",
+    "negativeInteger", "long", "int", "short", "byte", "nonNegativeInteger", "unsignedLong",
+    "unsignedInt", "unsignedShort", "unsignedByte", "positiveInteger",
+];
+
+/// Reads a `min`/`max` bound: a possibly negated number valid for the field's type.
+fn parse_bound(expr: &Expr, field: &Field) -> Result<String> {
+    let (negative, lit) = match expr {
+        Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => (true, expr.as_ref()),
+        expr => (false, expr),
+    };
+    let digits = match lit {
+        Expr::Lit(ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_digits().to_string(),
+        Expr::Lit(ExprLit { lit: Lit::Float(lit), .. }) => lit.base10_digits().to_string(),
+        _ => return Err(Error::new_spanned(expr, "Expected a numeric bound")),
+    };
+    let value = if negative { format!("-{}", digits) } else { digits };
+    
+    let xsd_type = crate::parser::xsd_type_name(crate::parser::field_value_type(&field.ty));
+    if !is_valid_default(&value, &xsd_type) {
+        return Err(Error::new_spanned(
+            expr,
+            format!("bound {} is not a valid value of the field's type", value),
+        ));
+    }
+    Ok(value)
+}
+
+/// Checks that the facets of a field apply to its value type and are consistent.
+fn validate_facets(field: &Field, facets: &Facets) -> Result<()> {
+    let xsd_type = crate::parser::xsd_type_name(crate::parser::field_value_type(&field.ty));
+    let has_string_facets = facets.pattern.is_some() || facets.min_length.is_some() || facets.max_length.is_some();
+    let has_bounds = facets.min.is_some() || facets.max.is_some();
+    
+    if has_string_facets && xsd_type != "xsd:string" {
+        return Err(Error::new_spanned(
+            &field.ty,
+            "pattern, min_length and max_length can only be applied to String fields",
+        ));
+    }
+    
+    if has_bounds && !crate::parser::is_numeric_xsd_type(&xsd_type) {
+        return Err(Error::new_spanned(
+            &field.ty,
+            "min and max can only be applied to numeric fields",
+        ));
+    }
+    
+    if let (Some(min_length), Some(max_length)) = (facets.min_length, facets.max_length) {
+        if min_length > max_length {
+            return Err(Error::new_spanned(&field.ty, "min_length is greater than max_length"));
+        }
+    }
+    
+    if let (Some(min), Some(max)) = (&facets.min, &facets.max) {
+        if min.parse::<f64>().ok() > max.parse::<f64>().ok() {
+            return Err(Error::new_spanned(&field.ty, "min is greater than max"));
+        }
+    }
+    
+    Ok(())
+}
+
+/// Checks that a default value parses as the built-in XSD type of its field.
+/// 
+/// Values of other simple types (e.g. decimals) are parsed when the default is applied.
+fn is_valid_default(value: &str, xsd_type: &str) -> bool {
+    match xsd_type {
+        "xsd:boolean" => value.parse::<bool>().is_ok(),
+        "xsd:byte" => value.parse::<i8>().is_ok(),
+        "xsd:unsignedByte" => value.parse::<u8>().is_ok(),
+        "xsd:short" => value.parse::<i16>().is_ok(),
+        "xsd:unsignedShort" => value.parse::<u16>().is_ok(),
+        "xsd:int" => value.parse::<i32>().is_ok(),
+        "xsd:unsignedInt" => value.parse::<u32>().is_ok(),
+        "xsd:long" => value.parse::<i64>().is_ok(),
+        "xsd:unsignedLong" => value.parse::<u64>().is_ok(),
+        "xsd:integer" => value.parse::<i128>().is_ok() || value.parse::<u128>().is_ok(),
+        "xsd:float" => value.parse::<f32>().is_ok(),
+        "xsd:double" => value.parse::<f64>().is_ok(),
+        _ => true,
+    }
+}
+
+/// Returns the entry element name of a map field, or `None` for other fields.
+/// 
+/// Defaults to the field's XML name singularized, e.g. `Properties` becomes `Property`.
+pub fn map_entry_name(field: &Field, attributes: &SoapFieldAttributes) -> Option<String> {
+    crate::parser::map_inner_types(&field.ty)?;
+    
+    let entry = attributes
+        .entry
+        .clone()
+        .unwrap_or_else(|| singularize(&field_xml_name(field)));
+    Some(entry)
+}
+
+/// Derives a singular element name from a plural field name.
+fn singularize(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies").filter(|stem| !stem.is_empty()) {
+        return format!("{}y", stem);
+    }
+    if ["sses", "uses", "xes", "ches", "shes"].iter().any(|suffix| name.ends_with(suffix)) {
+        return name[..name.len() - 2].to_string();
+    }
+    if name.len() > 1 && name.ends_with('s') && !["ss", "us", "is"].iter().any(|suffix| name.ends_with(suffix)) {
+        return name[..name.len() - 1].to_string();
+    }
+    name.to_string()
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SoapStructAttributes {
+    pub element: Option<String>,
+}
+
+/// Parses the `#[soap(...)]` attributes on a struct.
+/// 
+/// Supported keys: `element = "..."` sets the XSD element and complex type name used
+/// in the WSDL, so the contract name can differ from the Rust identifier.
+pub fn parse_soap_struct_attributes(item_struct: &ItemStruct) -> Result<SoapStructAttributes> {
+    let mut attributes = SoapStructAttributes::default();
+    
+    for attr in item_struct.attrs.iter().filter(|attr| is_soap_attribute(attr)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("element") {
+                let lit: LitStr = meta.value()?.parse()?;
+                crate::parser::validate_identifier(&lit.value(), "element")
+                    .map_err(|e| Error::new_spanned(&lit, e))?;
+                attributes.element = Some(lit.value());
+                Ok(())
+            } else {
+                Err(meta.error("Unknown soap struct attribute"))
+            }
+        })?;
+    }
+    
+    Ok(attributes)
+}
+
+/// Returns the XML name of a field: its `#[serde(rename = "...")]` value or its identifier.
+pub fn field_xml_name(field: &Field) -> String {
+    for meta in serde_metas(field) {
+        match meta {
+            Meta::NameValue(name_value) if name_value.path.is_ident("rename") => {
+                if let Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) = &name_value.value {
+                    return lit_str.value();
+                }
+            }
+            Meta::List(list) if list.path.is_ident("rename") => {
+                // rename(serialize = "...", deserialize = "...")
+                let mut name = None;
+                let _ = list.parse_nested_meta(|meta| {
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    if name.is_none() || meta.path.is_ident("serialize") {
+                        name = Some(lit.value());
+                    }
+                    Ok(())
+                });
+                if let Some(name) = name {
+                    return name;
+                }
+            }
+            _ => {}
+        }
+    }
+    
+    field
+        .ident
+        .as_ref()
+        .map(|ident| ident.to_string())
+        .unwrap_or_default()
+}
+
+/// Removes a key from the field's `#[serde(...)]` attributes, dropping attributes left empty.
+pub fn remove_serde_key(field: &mut Field, key: &str) {
+    let mut attrs = Vec::with_capacity(field.attrs.len());
+    
+    for attr in field.attrs.drain(..) {
+        if !attr.path().is_ident("serde") {
+            attrs.push(attr);
+            continue;
+        }
+        
+        let metas = match attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+            Ok(metas) => metas,
+            Err(_) => {
+                attrs.push(attr);
+                continue;
+            }
+        };
+        
+        let kept: Vec<Meta> = metas.into_iter().filter(|meta| !meta.path().is_ident(key)).collect();
+        if !kept.is_empty() {
+            attrs.push(syn::parse_quote!(#[serde(#(#kept),*)]));
+        }
+    }
+    
+    field.attrs = attrs;
+}
+
+/// Parses the entries of all `#[serde(...)]` attributes on a field.
+fn serde_metas(field: &Field) -> Vec<Meta> {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .collect()
+}
+
+/// Checks whether an attribute is a `#[soap(...)]` field attribute.
+pub fn is_soap_attribute(attr: &Attribute) -> bool {
+    attr.path().is_ident("soap")
+}
+
+/// Checks whether a field carries a `#[serde(...)]` attribute containing the given key.
+pub fn has_serde_key(field: &Field, key: &str) -> bool {
+    serde_metas(field).iter().any(|meta| meta.path().is_ident(key))
+}
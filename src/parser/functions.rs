@@ -1,8 +1,9 @@
 //! Parse and validate async functions
 
+use crate::parser::ServiceConfig;
 use syn::{
-    Error, FnArg, GenericArgument, Ident, Item, ItemFn, ItemMod, PathArguments, Result, ReturnType,
-    Type, TypePath, Visibility,
+    Attribute, Error, FnArg, GenericArgument, Ident, Item, ItemFn, ItemMod, LitInt, LitStr,
+    PathArguments, Result, ReturnType, Type, TypePath, Visibility,
 };
 
 #[derive(Debug, Clone)]
@@ -11,63 +12,543 @@ pub struct SoapOperation {
     pub function_name: Ident,
     pub request_type: Type,
     pub response_type: Type,
+    pub error_type: Type,
+    pub takes_request: bool,
+    pub borrows_request: bool,
+    /// Whether the request type is a module struct without fields, built without parsing.
+    pub empty_request: bool,
+    /// Whether the function's last parameter is a `RawXml` receiving the request as sent.
+    pub takes_raw_xml: bool,
+    /// The parameter type implementing `FromSoapHeaders`, built from the SOAP headers.
+    pub context_type: Option<Type>,
+    pub returns_headers: bool,
+    pub returns_attachments: bool,
+    pub returns_stream: bool,
+    pub returns_raw_body: bool,
+    pub maps_fault: bool,
+    pub namespace: Option<String>,
+    pub path: Option<String>,
+    pub requires_role: Option<String>,
+    /// Milliseconds the operation may run, from the operation or the service default.
+    pub timeout_ms: Option<u64>,
+    /// The token bucket limiting how often the operation may be called.
+    pub rate_limit: Option<RateLimit>,
+    /// Whether a batch response whose items all failed is answered with a fault.
+    pub fault_when_all_fail: bool,
+    /// Whether the request is checked against its restriction facets before the call.
+    pub checks_restrictions: bool,
+    /// Whether the request type implements `Validate`, which is called before the operation.
+    pub validates_request: bool,
+    /// The faults of the variants of the error type, when it is an enum of the module.
+    pub faults: Vec<crate::parser::FaultInfo>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OperationAttributes {
+    /// The operation name, instead of the one derived from the function name (`name`).
+    pub name: Option<String>,
+    /// The namespace the operation element must be in to dispatch here (`namespace`).
+    pub namespace: Option<String>,
+    /// An extra route dispatching straight to this operation (`path`).
+    pub path: Option<String>,
+    /// The role the principal needs to call the operation (`requires_role`).
+    pub requires_role: Option<String>,
+    /// How long the call may run, overriding the service's `timeout_ms` (`timeout_ms`).
+    pub timeout_ms: Option<u64>,
+    /// How often the operation may be called, e.g. `rate_limit = "10/s"`, per client
+    /// address with `rate_limit_per_client`.
+    pub rate_limit: Option<RateLimit>,
+    /// Whether a batch response whose items all failed is a fault (`fault_when_all_fail`).
+    pub fault_when_all_fail: bool,
+    /// Whether the function is a helper rather than an operation (`skip`), which excludes
+    /// every other key.
+    pub skip: bool,
+}
+
+/// A limit of `requests` calls per `period_secs`, parsed from `rate_limit = "10/s"`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// The calls allowed per period, which is also the largest burst.
+    pub requests: u32,
+    pub period_secs: u64,
+    /// Whether each client address (from `X-Forwarded-For`) has a bucket of its own.
+    pub per_client: bool,
 }
 
 /// Extracts SOAP operations from all valid async functions in a module.
 /// 
 /// Searches for public async functions with the correct signature and converts
-/// them into SoapOperation structs with extracted type information.
-pub fn extract_soap_operations(module: &ItemMod) -> Result<Vec<SoapOperation>> {
-    let mut operations = Vec::new();
+/// them into SoapOperation structs with extracted type information. Functions marked
+/// `#[operation(skip)]` are left to the module as helpers. With
+/// `operations = "explicit"` only functions marked `#[operation]` are considered. A module without
+/// any operations is rejected, since its WSDL would describe an empty service.
+/// 
+/// Operation names must be unique per namespace (ignoring case with
+/// `case_insensitive_operations`) and must not clash with another operation's
+/// `{Operation}Response` element.
+pub fn extract_soap_operations(config: &ServiceConfig, module: &ItemMod) -> Result<Vec<SoapOperation>> {
+    let mut operations: Vec<SoapOperation> = Vec::new();
+    let restricted = if config.validate_restrictions {
+        crate::parser::restricted_struct_names(module)?
+    } else {
+        Vec::new()
+    };
     
     if let Some((_, items)) = &module.content {
         for item in items {
             if let Item::Fn(func) = item {
-                if is_valid_soap_function(func)? {
-                    let operation = parse_soap_function(func)?;
+                if parse_operation_attributes(func)?.skip {
+                    continue;
+                }
+                // In explicit mode unmarked functions are helpers, whatever their signature
+                if config.explicit_operations && !func.attrs.iter().any(is_operation_attribute) {
+                    continue;
+                }
+                if is_valid_soap_function(func, config.strict, module)? {
+                    let mut operation = parse_soap_function(func, module)?;
+                    operation.empty_request = crate::parser::is_empty_struct(&operation.request_type, module);
+                    operation.returns_attachments =
+                        crate::parser::implements_attachment_response(module, &operation.response_type);
+                    operation.returns_stream =
+                        crate::parser::implements_streaming_response(module, &operation.response_type);
+                    operation.returns_raw_body =
+                        crate::parser::implements_raw_soap_body(module, &operation.response_type);
+                    operation.maps_fault = crate::parser::implements_soap_fault(module, &operation.error_type);
+                    operation.faults = crate::parser::error_faults(module, &operation.error_type);
+                    operation.validates_request = crate::parser::implements_validate(module, &operation.request_type);
+                    operation.timeout_ms = operation.timeout_ms.or(config.timeout_ms);
+                    operation.checks_restrictions = crate::parser::flattened_struct(&operation.request_type, module)
+                        .is_some_and(|item_struct| restricted.contains(&item_struct.ident.to_string()));
+                    if operation.returns_attachments && operation.returns_stream {
+                        return Err(Error::new_spanned(
+                            &func.sig.output,
+                            "A response type cannot implement both SoapAttachmentResponse and SoapStreamingResponse",
+                        ));
+                    }
+                    if operation.fault_when_all_fail
+                        && !crate::parser::implements_partial_success(module, &operation.response_type)
+                    {
+                        return Err(Error::new_spanned(
+                            &func.sig.output,
+                            "fault_when_all_fail requires the response type to implement PartialSuccess in the service module",
+                        ));
+                    }
+                    if operation.returns_raw_body && operation.returns_stream {
+                        return Err(Error::new_spanned(
+                            &func.sig.output,
+                            "A response type cannot implement both RawSoapBody and SoapStreamingResponse",
+                        ));
+                    }
+                    
+                    // The same operation name may only be reused under a different namespace
+                    if operations
+                        .iter()
+                        .any(|other| other.name == operation.name && other.namespace == operation.namespace)
+                    {
+                        return Err(Error::new_spanned(
+                            &func.sig.ident,
+                            format!("Duplicate SOAP operation {} in the same namespace", operation.name),
+                        ));
+                    }
+                    // Case-insensitive dispatch could not tell these apart
+                    if let Some(other) = operations.iter().find(|other| {
+                        config.case_insensitive_operations
+                            && other.namespace == operation.namespace
+                            && other.name.to_lowercase() == operation.name.to_lowercase()
+                    }) {
+                        return Err(Error::new_spanned(
+                            &func.sig.ident,
+                            format!(
+                                "SOAP operations {} and {} differ only in case, which case_insensitive_operations cannot distinguish",
+                                other.name, operation.name
+                            ),
+                        ));
+                    }
+                    // Responses are written as {Operation}Response, which another operation may be named
+                    if let Some(other) = operations.iter().find(|other| {
+                        other.name == format!("{}Response", operation.name)
+                            || operation.name == format!("{}Response", other.name)
+                    }) {
+                        return Err(Error::new_spanned(
+                            &func.sig.ident,
+                            format!(
+                                "SOAP operations {} and {} collide: the response element of one is the request element of the other",
+                                other.name, operation.name
+                            ),
+                        ));
+                    }
                     operations.push(operation);
                 }
             }
         }
     }
     
+    qualify_shared_fault_names(&mut operations);
+    
+    if operations.is_empty() {
+        let hint = if config.explicit_operations {
+            "mark at least one `pub async fn` returning a Result with #[operation]"
+        } else {
+            "declare at least one `pub async fn` returning a Result"
+        };
+        return Err(Error::new_spanned(
+            &module.ident,
+            format!("Service module has no SOAP operations; {}", hint),
+        ));
+    }
+    
     Ok(operations)
 }
 
+/// Names the faults of variants that several error enums share `{Enum}{Variant}Fault`.
+/// 
+/// WSDL fault messages and their detail elements share one namespace, so a `NotFound`
+/// variant of two enums would otherwise declare the same fault twice.
+fn qualify_shared_fault_names(operations: &mut [SoapOperation]) {
+    let faults: Vec<crate::parser::FaultInfo> =
+        operations.iter().flat_map(|operation| operation.faults.clone()).collect();
+    
+    for fault in operations.iter_mut().flat_map(|operation| operation.faults.iter_mut()) {
+        let shared = faults
+            .iter()
+            .any(|other| other.name == fault.name && other.error_type != fault.error_type);
+        if shared {
+            fault.name = format!("{}{}Fault", fault.error_type, fault.variant);
+        }
+    }
+}
+
 /// Checks if a function is valid for SOAP operations (public and async).
-fn is_valid_soap_function(func: &ItemFn) -> Result<bool> {
+/// 
+/// Functions that are not `pub async` are skipped, unless they carry an
+/// `#[operation(...)]` marker or, with `strict`, have an operation's signature (at most
+/// one parameter and a `Result` return type). Those are rejected with a diagnostic
+/// naming the missing qualifiers, since skipping them silently leaves the operation out.
+fn is_valid_soap_function(func: &ItemFn, strict: bool, module: &ItemMod) -> Result<bool> {
+    let mut missing = Vec::new();
+    
     // Check if function is public
     if !matches!(func.vis, Visibility::Public(_)) {
-        return Ok(false);
+        missing.push("pub");
     }
     
     // Check if function is async
     if func.sig.asyncness.is_none() {
-        return Ok(false);
+        missing.push("async");
+    }
+    
+    if missing.is_empty() {
+        return Ok(true);
     }
     
-    Ok(true)
+    let marked = func.attrs.iter().any(is_operation_attribute);
+    if marked || (strict && has_operation_signature(func, module)) {
+        return Err(Error::new_spanned(
+            &func.sig.ident,
+            format!(
+                "Function {} looks like a SOAP operation but is not {}; operations must be declared as `pub async fn`",
+                func.sig.ident,
+                missing.join(" or "),
+            ),
+        ));
+    }
+    
+    Ok(false)
+}
+
+/// Checks whether a function takes at most one parameter besides its context and `RawXml`
+/// and returns a `Result`.
+fn has_operation_signature(func: &ItemFn, module: &ItemMod) -> bool {
+    let returns_result = match &func.sig.output {
+        ReturnType::Type(_, ty) => match ty.as_ref() {
+            Type::Path(TypePath { path, .. }) => path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Result"),
+            _ => false,
+        },
+        ReturnType::Default => false,
+    };
+    
+    request_inputs(func, module).len() <= 1 && returns_result
+}
+
+/// Returns the parameters of a function other than a trailing `RawXml` and the context
+/// parameter before it.
+fn request_inputs<'a>(func: &'a ItemFn, module: &ItemMod) -> Vec<&'a FnArg> {
+    let mut inputs: Vec<&FnArg> = func.sig.inputs.iter().collect();
+    if inputs.last().is_some_and(|input| is_raw_xml_parameter(input)) {
+        inputs.pop();
+    }
+    if inputs.last().and_then(|input| context_type(input, module)).is_some() {
+        inputs.pop();
+    }
+    inputs
+}
+
+/// Returns the type of a parameter taking a context built from the SOAP headers by value,
+/// one implementing `FromSoapHeaders` in the module.
+fn context_type(input: &FnArg, module: &ItemMod) -> Option<Type> {
+    match input {
+        FnArg::Typed(pat_type) if crate::parser::implements_from_soap_headers(module, &pat_type.ty) => {
+            Some((*pat_type.ty).clone())
+        }
+        _ => None,
+    }
+}
+
+/// Checks whether a parameter takes the `RawXml` of the request by value.
+fn is_raw_xml_parameter(input: &FnArg) -> bool {
+    match input {
+        FnArg::Typed(pat_type) => matches!(
+            &*pat_type.ty,
+            Type::Path(TypePath { path, .. }) if path.segments.last().is_some_and(|segment| segment.ident == "RawXml")
+        ),
+        FnArg::Receiver(_) => false,
+    }
 }
 
 /// Parses a valid async function into a SoapOperation with extracted types.
 /// 
-/// Validates the function signature and extracts request/response types.
-fn parse_soap_function(func: &ItemFn) -> Result<SoapOperation> {
+/// Validates the function signature and extracts request/response types. The request may
+/// be followed by a context implementing `FromSoapHeaders` and then by `RawXml`.
+fn parse_soap_function(func: &ItemFn, module: &ItemMod) -> Result<SoapOperation> {
     let function_name = func.sig.ident.clone();
-    let name = generate_operation_name(&function_name);
+    let attributes = parse_operation_attributes(func)?;
+    let name = attributes
+        .name
+        .unwrap_or_else(|| generate_operation_name(&function_name));
     
     // Validate function signature
-    let request_type = extract_request_type(func)?;
-    let (response_type, _error_type) = extract_return_types(func)?;
+    let (request_type, borrows_request) = extract_request_type(func, module)?;
+    let (response_type, error_type) = extract_return_types(func)?;
+    let inputs = request_inputs(func, module);
+    let takes_request = !inputs.is_empty();
+    let takes_raw_xml = func.sig.inputs.last().is_some_and(is_raw_xml_parameter);
+    // The context comes right after the request
+    let context_type = func
+        .sig
+        .inputs
+        .iter()
+        .nth(inputs.len())
+        .and_then(|input| context_type(input, module));
+    
+    // WithHeaders<T> only adds HTTP headers, the SOAP response is still T
+    let (response_type, returns_headers) = match crate::parser::with_headers_inner_type(&response_type) {
+        Some(inner) => (inner.clone(), true),
+        None => (response_type, false),
+    };
     
     Ok(SoapOperation {
         name,
         function_name,
         request_type,
         response_type,
+        error_type,
+        takes_request,
+        borrows_request,
+        empty_request: false,
+        takes_raw_xml,
+        context_type,
+        returns_headers,
+        returns_attachments: false,
+        returns_stream: false,
+        returns_raw_body: false,
+        maps_fault: false,
+        namespace: attributes.namespace,
+        path: attributes.path,
+        requires_role: attributes.requires_role,
+        timeout_ms: attributes.timeout_ms,
+        rate_limit: attributes.rate_limit,
+        fault_when_all_fail: attributes.fault_when_all_fail,
+        checks_restrictions: false,
+        validates_request: false,
+        faults: Vec::new(),
     })
 }
 
+/// Parses the `#[operation(...)]` attributes on a service function.
+/// 
+/// The keys are described on the `OperationAttributes` fields. A bare `#[operation]`
+/// sets none of them.
+pub fn parse_operation_attributes(func: &ItemFn) -> Result<OperationAttributes> {
+    let mut attributes = OperationAttributes::default();
+    let mut per_client = None;
+    
+    for attr in func.attrs.iter().filter(|attr| is_operation_attribute(attr)) {
+        // A bare #[operation] only marks the function in explicit mode
+        if matches!(attr.meta, syn::Meta::Path(_)) {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let value: LitStr = meta.value()?.parse()?;
+                crate::parser::validate_identifier(&value.value(), "name")
+                    .map_err(|e| Error::new_spanned(&value, e))?;
+                attributes.name = Some(value.value());
+                Ok(())
+            } else if meta.path.is_ident("namespace") {
+                let value: LitStr = meta.value()?.parse()?;
+                crate::parser::validate_namespace(&value.value())
+                    .map_err(|e| Error::new_spanned(&value, e))?;
+                attributes.namespace = Some(value.value());
+                Ok(())
+            } else if meta.path.is_ident("path") {
+                let value: LitStr = meta.value()?.parse()?;
+                crate::parser::validate_bind_path(&value.value(), "path")
+                    .map_err(|e| Error::new_spanned(&value, e))?;
+                attributes.path = Some(value.value());
+                Ok(())
+            } else if meta.path.is_ident("requires_role") {
+                let value: LitStr = meta.value()?.parse()?;
+                if value.value().trim().is_empty() {
+                    return Err(Error::new_spanned(&value, "requires_role cannot be empty"));
+                }
+                attributes.requires_role = Some(value.value());
+                Ok(())
+            } else if meta.path.is_ident("timeout_ms") {
+                let value: LitInt = meta.value()?.parse()?;
+                if value.base10_parse::<u64>()? == 0 {
+                    return Err(Error::new_spanned(&value, "timeout_ms must be a positive integer"));
+                }
+                if !cfg!(feature = "runtime") {
+                    return Err(Error::new_spanned(&value, "timeout_ms requires the runtime feature"));
+                }
+                attributes.timeout_ms = Some(value.base10_parse()?);
+                Ok(())
+            } else if meta.path.is_ident("rate_limit") {
+                let value: LitStr = meta.value()?.parse()?;
+                let (requests, period_secs) =
+                    parse_rate_limit(&value.value()).map_err(|e| Error::new_spanned(&value, e))?;
+                if !cfg!(feature = "runtime") {
+                    return Err(Error::new_spanned(&value, "rate_limit requires the runtime feature"));
+                }
+                attributes.rate_limit = Some(RateLimit { requests, period_secs, per_client: false });
+                Ok(())
+            } else if meta.path.is_ident("rate_limit_per_client") {
+                per_client = Some(meta.path.clone());
+                Ok(())
+            } else if meta.path.is_ident("fault_when_all_fail") {
+                attributes.fault_when_all_fail = true;
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                attributes.skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("Unknown operation attribute"))
+            }
+        })?;
+    }
+    
+    if let Some(path) = per_client {
+        match &mut attributes.rate_limit {
+            Some(rate_limit) => rate_limit.per_client = true,
+            None => return Err(Error::new_spanned(path, "rate_limit_per_client requires a rate_limit")),
+        }
+    }
+    
+    let configured = attributes.name.is_some()
+        || attributes.namespace.is_some()
+        || attributes.path.is_some()
+        || attributes.requires_role.is_some()
+        || attributes.timeout_ms.is_some()
+        || attributes.rate_limit.is_some()
+        || attributes.fault_when_all_fail;
+    if attributes.skip && configured {
+        return Err(Error::new_spanned(
+            &func.sig.ident,
+            "#[operation(skip)] cannot be combined with other operation attributes",
+        ));
+    }
+    
+    Ok(attributes)
+}
+
+/// Parses a `rate_limit` of the form `{requests}/{unit}` into the requests and the
+/// period in seconds.
+fn parse_rate_limit(value: &str) -> std::result::Result<(u32, u64), String> {
+    let invalid = || format!("rate_limit must look like \"10/s\", with a unit of s, m, or h, not \"{}\"", value);
+    let (requests, unit) = value.split_once('/').ok_or_else(invalid)?;
+    let requests = requests.trim().parse::<u32>().map_err(|_| invalid())?;
+    if requests == 0 {
+        return Err("rate_limit must allow at least one request".to_string());
+    }
+    let period_secs = match unit.trim() {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        _ => return Err(invalid()),
+    };
+    
+    Ok((requests, period_secs))
+}
+
+/// Validates the operations against the service configuration.
+/// 
+/// Per-operation paths must be unique and not shadow the service routes, and
+/// `requires_role` needs an authenticated principal, so it requires `auth`.
+pub fn validate_operations(config: &ServiceConfig, operations: &[SoapOperation]) -> Result<()> {
+    let json_path = format!("{}/json", config.bind_path);
+    let schema_path = format!("{}/schema.xsd", config.bind_path);
+    let mut seen: Vec<&str> = Vec::new();
+    
+    for operation in operations {
+        if operation.requires_role.is_some() && !config.basic_auth {
+            return Err(Error::new_spanned(
+                &operation.function_name,
+                "requires_role needs an authenticated principal; configure auth = \"basic\" on the service",
+            ));
+        }
+        
+        let path = match &operation.path {
+            Some(path) => path.as_str(),
+            None => continue,
+        };
+        
+        let json_conflict = config.json_api
+            && (path == json_path || path.starts_with(&format!("{}/", json_path)));
+        let schema_conflict = config.split_schema && path == schema_path;
+        if path == config.bind_path
+            || path == config.wsdl_path
+            || schema_conflict
+            || config.json_schema_path.as_deref() == Some(path)
+            || json_conflict
+        {
+            return Err(Error::new_spanned(
+                &operation.function_name,
+                format!("Operation path {} conflicts with the service routes", path),
+            ));
+        }
+        if seen.contains(&path) {
+            return Err(Error::new_spanned(
+                &operation.function_name,
+                format!("Operation path {} is used by more than one operation", path),
+            ));
+        }
+        seen.push(path);
+    }
+    
+    Ok(())
+}
+
+/// Removes the `#[operation(...)]` attributes from the module's functions.
+/// 
+/// They are only markers for the macro and must not reach the compiler.
+pub fn strip_operation_attributes(module: &mut ItemMod) {
+    if let Some((_, items)) = &mut module.content {
+        for item in items.iter_mut() {
+            if let Item::Fn(func) = item {
+                func.attrs.retain(|attr| !is_operation_attribute(attr));
+            }
+        }
+    }
+}
+
+/// Checks whether an attribute is an `#[operation(...)]` marker.
+fn is_operation_attribute(attr: &Attribute) -> bool {
+    attr.path().is_ident("operation")
+}
+
 /// Converts snake_case function names to PascalCase operation names.
 /// 
 /// Example: `add_numbers` becomes `AddNumbers`.
@@ -88,20 +569,33 @@ fn generate_operation_name(function_name: &Ident) -> String {
 
 /// Extracts the request type from a function's single parameter.
 /// 
-/// Validates that the function has exactly one parameter (no self).
-fn extract_request_type(func: &ItemFn) -> Result<Type> {
-    let inputs = &func.sig.inputs;
+/// Validates that the function has at most one parameter (no self) besides a context and
+/// a trailing `RawXml`. Functions without a request parameter take the unit type `()` as their request.
+fn extract_request_type(func: &ItemFn, module: &ItemMod) -> Result<(Type, bool)> {
+    let inputs = request_inputs(func, module);
+    
+    if inputs.is_empty() {
+        return Ok((syn::parse_quote!(()), false));
+    }
     
-    // Function should have exactly one parameter (the request)
+    // Function should have at most one parameter (the request), besides a context and a trailing RawXml
     if inputs.len() != 1 {
         return Err(Error::new_spanned(
             &func.sig,
-            "SOAP operation functions must have exactly one parameter (the request type)",
+            "SOAP operation functions must have at most one parameter (the request type), optionally followed by a FromSoapHeaders context and RawXml",
         ));
     }
     
-    match inputs.first().unwrap() {
-        FnArg::Typed(pat_type) => Ok((*pat_type.ty).clone()),
+    match inputs[0] {
+        // A shared reference borrows the request the dispatcher deserialized
+        FnArg::Typed(pat_type) => match &*pat_type.ty {
+            Type::Reference(reference) if reference.mutability.is_some() => Err(Error::new_spanned(
+                reference,
+                "SOAP operation functions cannot take the request by mutable reference; take it by value or by shared reference",
+            )),
+            Type::Reference(reference) => Ok(((*reference.elem).clone(), true)),
+            ty => Ok((ty.clone(), false)),
+        },
         FnArg::Receiver(_) => Err(Error::new_spanned(
             &func.sig,
             "SOAP operation functions cannot have self parameters",
@@ -1,7 +1,11 @@
 pub mod attributes;
+pub mod fields;
 pub mod functions;
+pub mod pattern;
 pub mod types;
 
 pub use attributes::*;
+pub use fields::*;
 pub use functions::*;
-pub use types::*;
\ No newline at end of file
+pub use pattern::*;
+pub use types::*;
@@ -0,0 +1,214 @@
+//! Parse XSD regular expressions used by `pattern` facets
+
+/// A set of characters matched by a pattern atom.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatternChars {
+    Range(char, char),
+    /// `\d`
+    Digit,
+    /// `\s`
+    Space,
+    /// `\w`
+    Word,
+    /// `\i`
+    NameStart,
+    /// `\c`
+    NameChar,
+    Not(Box<PatternChars>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatternNode {
+    /// One character in (or, when negated, not in) any of the sets.
+    Class(Vec<PatternChars>, bool),
+    /// Alternative branches, each a sequence of nodes.
+    Group(Vec<Vec<PatternNode>>),
+    /// A node repeated between a minimum and an optional maximum number of times.
+    Repeat(Box<PatternNode>, u32, Option<u32>),
+}
+
+/// Parses an XSD regular expression into a group of its top-level branches.
+/// 
+/// Supports the XSD syntax except Unicode category escapes (`\p{..}`) and character
+/// class subtraction. As in XSD, the pattern matches the whole value, and `^` and `$`
+/// are ordinary characters.
+pub fn parse_pattern(pattern: &str) -> Result<PatternNode, String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut pos = 0;
+    let branches = parse_branches(&chars, &mut pos)?;
+
+    match chars.get(pos) {
+        None => Ok(PatternNode::Group(branches)),
+        Some(_) => Err("Unmatched ')'".to_string()),
+    }
+}
+
+/// Parses branches separated by `|` up to a closing `)` or the end.
+fn parse_branches(chars: &[char], pos: &mut usize) -> Result<Vec<Vec<PatternNode>>, String> {
+    let mut branches = vec![Vec::new()];
+
+    while let Some(&c) = chars.get(*pos) {
+        match c {
+            ')' => break,
+            '|' => {
+                *pos += 1;
+                branches.push(Vec::new());
+            }
+            _ => {
+                let atom = parse_atom(chars, pos)?;
+                let node = parse_quantifier(chars, pos, atom)?;
+                branches.last_mut().unwrap().push(node);
+            }
+        }
+    }
+
+    Ok(branches)
+}
+
+fn parse_atom(chars: &[char], pos: &mut usize) -> Result<PatternNode, String> {
+    let c = chars[*pos];
+    *pos += 1;
+
+    match c {
+        '(' => {
+            let branches = parse_branches(chars, pos)?;
+            if chars.get(*pos) != Some(&')') {
+                return Err("Unclosed '('".to_string());
+            }
+            *pos += 1;
+            Ok(PatternNode::Group(branches))
+        }
+        '[' => parse_class(chars, pos),
+        '.' => Ok(PatternNode::Class(
+            vec![PatternChars::Range('\n', '\n'), PatternChars::Range('\r', '\r')],
+            true,
+        )),
+        '\\' => Ok(PatternNode::Class(vec![parse_escape(chars, pos)?], false)),
+        '?' | '*' | '+' | '{' => Err(format!("Quantifier '{}' does not follow anything to repeat", c)),
+        ']' | '}' => Err(format!("Unmatched '{}'", c)),
+        c => Ok(PatternNode::Class(vec![PatternChars::Range(c, c)], false)),
+    }
+}
+
+fn parse_quantifier(chars: &[char], pos: &mut usize, atom: PatternNode) -> Result<PatternNode, String> {
+    let (min, max) = match chars.get(*pos) {
+        Some('?') => (0, Some(1)),
+        Some('*') => (0, None),
+        Some('+') => (1, None),
+        Some('{') => {
+            let end = chars[*pos..]
+                .iter()
+                .position(|&c| c == '}')
+                .ok_or("Unclosed '{'")?;
+            let quantity: String = chars[*pos + 1..*pos + end].iter().collect();
+            *pos += end;
+            parse_quantity(&quantity)?
+        }
+        _ => return Ok(atom),
+    };
+    *pos += 1;
+
+    Ok(PatternNode::Repeat(Box::new(atom), min, max))
+}
+
+/// Parses the `n`, `n,` or `n,m` inside a `{...}` quantifier.
+fn parse_quantity(quantity: &str) -> Result<(u32, Option<u32>), String> {
+    let invalid = || format!("Invalid quantifier {{{}}}", quantity);
+    let parse = |value: &str| value.parse::<u32>().map_err(|_| invalid());
+
+    match quantity.split_once(',') {
+        None => {
+            let count = parse(quantity)?;
+            Ok((count, Some(count)))
+        }
+        Some((min, "")) => Ok((parse(min)?, None)),
+        Some((min, max)) => {
+            let (min, max) = (parse(min)?, parse(max)?);
+            if min > max {
+                return Err(invalid());
+            }
+            Ok((min, Some(max)))
+        }
+    }
+}
+
+/// Parses a character class after its opening `[`.
+fn parse_class(chars: &[char], pos: &mut usize) -> Result<PatternNode, String> {
+    let negated = chars.get(*pos) == Some(&'^');
+    if negated {
+        *pos += 1;
+    }
+    let mut sets = Vec::new();
+
+    loop {
+        let c = *chars.get(*pos).ok_or("Unclosed '['")?;
+        *pos += 1;
+        let start = match c {
+            ']' if !sets.is_empty() => return Ok(PatternNode::Class(sets, negated)),
+            ']' => return Err("Empty character class".to_string()),
+            '[' => return Err("Nested character classes are not supported".to_string()),
+            '-' if chars.get(*pos) == Some(&'[') => {
+                return Err("Character class subtraction is not supported".to_string());
+            }
+            '\\' => match parse_escape(chars, pos)? {
+                PatternChars::Range(c, _) => c,
+                set => {
+                    sets.push(set);
+                    continue;
+                }
+            },
+            c => c,
+        };
+
+        // A '-' before the closing ']' is an ordinary character
+        if chars.get(*pos) == Some(&'-') && !matches!(chars.get(*pos + 1), Some(']') | Some('[') | None) {
+            *pos += 1;
+            let end = match chars[*pos] {
+                '\\' => {
+                    *pos += 1;
+                    match parse_escape(chars, pos)? {
+                        PatternChars::Range(c, _) => c,
+                        _ => return Err("A range cannot end in a character class escape".to_string()),
+                    }
+                }
+                c => {
+                    *pos += 1;
+                    c
+                }
+            };
+            if end < start {
+                return Err(format!("Invalid range {}-{}", start, end));
+            }
+            sets.push(PatternChars::Range(start, end));
+        } else {
+            sets.push(PatternChars::Range(start, start));
+        }
+    }
+}
+
+/// Parses an escape after its `\`, either a single character or a class escape.
+fn parse_escape(chars: &[char], pos: &mut usize) -> Result<PatternChars, String> {
+    let c = *chars.get(*pos).ok_or("Pattern ends with '\\'")?;
+    *pos += 1;
+
+    let single = |c| Ok(PatternChars::Range(c, c));
+    let negated = |set| Ok(PatternChars::Not(Box::new(set)));
+    match c {
+        'n' => single('\n'),
+        'r' => single('\r'),
+        't' => single('\t'),
+        '\\' | '|' | '.' | '-' | '^' | '?' | '*' | '+' | '{' | '}' | '(' | ')' | '[' | ']' => single(c),
+        'd' => Ok(PatternChars::Digit),
+        'D' => negated(PatternChars::Digit),
+        's' => Ok(PatternChars::Space),
+        'S' => negated(PatternChars::Space),
+        'w' => Ok(PatternChars::Word),
+        'W' => negated(PatternChars::Word),
+        'i' => Ok(PatternChars::NameStart),
+        'I' => negated(PatternChars::NameStart),
+        'c' => Ok(PatternChars::NameChar),
+        'C' => negated(PatternChars::NameChar),
+        'p' | 'P' => Err("Unicode category escapes are not supported".to_string()),
+        c => Err(format!("Unknown escape '\\{}'", c)),
+    }
+}
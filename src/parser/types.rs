@@ -1,36 +1,74 @@
 //! Analyze request/response struct types
 
+use crate::parser::{
+    field_xml_name, Facets, has_serde_key, map_entry_name, parse_soap_field_attributes,
+    parse_soap_struct_attributes,
+};
 use std::collections::HashMap;
-use syn::{Error, Result, Type, TypePath};
+use syn::{
+    Error, Expr, ExprLit, Fields, Ident, ImplItem, Item, ItemImpl, ItemMod, ItemStruct, Lit, Result, Type, TypePath,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TypeInfo {
     pub name: String,
     pub fields: Vec<FieldInfo>,
+    /// Whether the type is declared outside the service module, so its fields are unknown.
+    pub external: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FieldInfo {
     pub xml_name: String,
     pub field_type: String,
     pub optional: bool,
+    pub repeated: bool,
+    pub nillable: bool,
+    pub attribute: bool,
+    /// XSD types of the `key` and `value` children of a map field's entries.
+    pub map_entry: Option<(String, String)>,
+    /// Value applied when the field is missing, from `#[soap(default = "...")]`.
+    pub default: Option<String>,
+    /// Restriction facets of the field's simple type.
+    pub facets: Facets,
+}
+
+/// A WSDL fault declared by a variant of an operation's error enum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FaultInfo {
+    /// The error enum declaring the variant.
+    pub error_type: Ident,
+    pub variant: Ident,
+    /// The name of the fault, its message, and its detail element, e.g. `NotFoundFault`.
+    pub name: String,
 }
 
 /// Analyzes a Rust type and creates TypeInfo for WSDL generation.
 /// 
-/// Currently creates placeholder TypeInfo with empty fields.
-/// Future implementation would extract actual struct field information.
-pub fn analyze_type(ty: &Type) -> Result<TypeInfo> {
+/// Fields are extracted from the struct definition when it is declared in the
+/// service module, and its `#[soap(element = "...")]` overrides the element name.
+/// Types defined elsewhere get external TypeInfo named after the type, without fields. Only
+/// a plain or `self::` path refers to the module's own struct; a type reached through
+/// another module is never mistaken for a same-named struct of the service.
+pub fn analyze_type(ty: &Type, module: &ItemMod) -> Result<TypeInfo> {
     match ty {
         Type::Path(type_path) => {
             let type_name = extract_type_name(type_path);
+            let local_struct = if type_path_name(type_path).contains("::") {
+                None
+            } else {
+                find_struct(module, &type_name)
+            };
+            let external = local_struct.is_none();
+            let (name, fields) = match local_struct {
+                Some(item_struct) => (
+                    parse_soap_struct_attributes(item_struct)?.element.unwrap_or(type_name),
+                    analyze_struct_fields(item_struct, module, &mut Vec::new())?,
+                ),
+                None => (type_name, vec![]),
+            };
             
-            // For now, we'll create a placeholder TypeInfo
-            // In a real implementation, we'd need access to the actual struct definition
-            Ok(TypeInfo {
-                name: type_name,
-                fields: vec![], // Would be populated from actual struct definition
-            })
+            Ok(TypeInfo { name, fields, external })
         }
         _ => Err(Error::new_spanned(
             ty,
@@ -39,6 +77,344 @@ pub fn analyze_type(ty: &Type) -> Result<TypeInfo> {
     }
 }
 
+/// Finds a struct declared directly in the module by name.
+fn find_struct<'a>(module: &'a ItemMod, name: &str) -> Option<&'a ItemStruct> {
+    module.content.as_ref()?.1.iter().find_map(|item| match item {
+        Item::Struct(item_struct) if item_struct.ident == name => Some(item_struct),
+        _ => None,
+    })
+}
+
+/// Extracts FieldInfo for each named field, honoring serde renames and `#[soap(...)]` attributes.
+/// 
+/// The fields of a `#[serde(flatten)]` struct are inlined in place of the field, and all
+/// become optional when it is an `Option`. `flattening` holds the structs being inlined,
+/// so a struct that flattens itself is rejected instead of recursing forever.
+fn analyze_struct_fields<'a>(
+    item_struct: &'a ItemStruct,
+    module: &'a ItemMod,
+    flattening: &mut Vec<&'a Ident>,
+) -> Result<Vec<FieldInfo>> {
+    let mut fields = Vec::new();
+    
+    if let Fields::Named(named) = &item_struct.fields {
+        for field in &named.named {
+            // Skipped fields (e.g. attachment payloads) never appear in the XML
+            if has_serde_key(field, "skip") {
+                continue;
+            }
+            
+            if has_serde_key(field, "flatten") {
+                let (ty, optional) = match option_inner_type(&field.ty) {
+                    Some(inner) => (inner, true),
+                    None => (&field.ty, false),
+                };
+                let nested = flattened_struct(ty, module).ok_or_else(|| {
+                    Error::new_spanned(ty, "Flattened fields must have a struct type declared in the service module")
+                })?;
+                if nested.ident == item_struct.ident || flattening.contains(&&nested.ident) {
+                    return Err(Error::new_spanned(ty, format!("Struct {} is flattened into itself", nested.ident)));
+                }
+                
+                flattening.push(&item_struct.ident);
+                let mut nested_fields = analyze_struct_fields(nested, module, flattening)?;
+                flattening.pop();
+                if optional {
+                    nested_fields.iter_mut().for_each(|field| field.optional = true);
+                }
+                fields.extend(nested_fields);
+                continue;
+            }
+            
+            let soap_attributes = parse_soap_field_attributes(field)?;
+            
+            // Maps are written as repeated key/value entries, which may be absent
+            if let Some((key_type, value_type)) = map_inner_types(&field.ty) {
+                fields.push(FieldInfo {
+                    xml_name: map_entry_name(field, &soap_attributes).unwrap_or_default(),
+                    field_type: xsd_type_name(value_type),
+                    optional: true,
+                    repeated: true,
+                    nillable: false,
+                    attribute: false,
+                    map_entry: Some((xsd_type_name(key_type), xsd_type_name(value_type))),
+                    default: None,
+                    facets: Facets::default(),
+                });
+                continue;
+            }
+            
+            let shape = analyze_field_shape(&field.ty);
+            
+            fields.push(FieldInfo {
+                xml_name: field_xml_name(field),
+                field_type: soap_attributes.xsd.clone().unwrap_or_else(|| xsd_type_name(shape.value_type)),
+                // A list missing from the request only deserializes with a serde default,
+                // and any field with a soap default may be left out
+                optional: shape.optional
+                    || (shape.repeated && has_serde_key(field, "default"))
+                    || soap_attributes.default.is_some(),
+                repeated: shape.repeated,
+                // Attributes cannot be nil, so only optional elements accept xsi:nil
+                nillable: shape.nillable && !soap_attributes.attribute,
+                attribute: soap_attributes.attribute,
+                map_entry: None,
+                default: soap_attributes.default,
+                facets: soap_attributes.facets,
+            });
+        }
+    }
+    
+    Ok(fields)
+}
+
+/// Returns the names of the module structs with restriction facets on their fields,
+/// directly or through a field whose type is another such struct.
+pub fn restricted_struct_names(module: &ItemMod) -> Result<Vec<String>> {
+    let mut structs: Vec<(String, Vec<String>)> = Vec::new();
+    let mut restricted = Vec::new();
+    
+    for item in module.content.iter().flat_map(|(_, items)| items) {
+        let item_struct = match item {
+            Item::Struct(item_struct) if item_struct.generics.params.is_empty() => item_struct,
+            _ => continue,
+        };
+        let mut nested = Vec::new();
+        for field in &item_struct.fields {
+            let name = item_struct.ident.to_string();
+            if !parse_soap_field_attributes(field)?.facets.is_empty() && !restricted.contains(&name) {
+                restricted.push(name);
+            }
+            if let Some(nested_struct) = flattened_struct(field_value_type(&field.ty), module) {
+                nested.push(nested_struct.ident.to_string());
+            }
+        }
+        structs.push((item_struct.ident.to_string(), nested));
+    }
+    
+    // Structs holding a restricted struct are restricted too, until nothing changes
+    loop {
+        let before = restricted.len();
+        for (name, nested) in &structs {
+            if !restricted.contains(name) && nested.iter().any(|nested| restricted.contains(nested)) {
+                restricted.push(name.clone());
+            }
+        }
+        if restricted.len() == before {
+            return Ok(restricted);
+        }
+    }
+}
+
+/// Finds the module struct a `#[serde(flatten)]` field refers to by a plain or `self::` path.
+pub fn flattened_struct<'a>(ty: &Type, module: &'a ItemMod) -> Option<&'a ItemStruct> {
+    match ty {
+        Type::Path(type_path) if !type_path_name(type_path).contains("::") => {
+            find_struct(module, &extract_type_name(type_path))
+        }
+        _ => None,
+    }
+}
+
+/// Checks whether a type is a non-generic module struct without fields, e.g. `struct PingRequest;`.
+pub fn is_empty_struct(ty: &Type, module: &ItemMod) -> bool {
+    flattened_struct(ty, module)
+        .is_some_and(|item_struct| item_struct.fields.is_empty() && item_struct.generics.params.is_empty())
+}
+
+/// How a field type wraps its value in `Option` and `Vec`.
+struct FieldShape<'a> {
+    value_type: &'a Type,
+    optional: bool,
+    repeated: bool,
+    nillable: bool,
+}
+
+/// Peels `Option` and `Vec` off a field type down to its value type.
+/// 
+/// An outer `Option` makes the field optional and nillable, a `Vec` makes it repeated,
+/// and an `Option` inside the `Vec` makes each item nillable. `Option<Vec<T>>` is
+/// therefore an optional list and `Vec<Option<T>>` a list with nillable items. `Box`,
+/// `Arc`, and `Rc` serialize as their content, so they are peeled off at every level,
+/// e.g. `Vec<Box<Node>>` is a list of `Node`.
+fn analyze_field_shape(ty: &Type) -> FieldShape<'_> {
+    let ty = strip_pointers(ty);
+    let (ty, optional) = match option_inner_type(ty) {
+        Some(inner) => (strip_pointers(inner), true),
+        None => (ty, false),
+    };
+    let (ty, repeated) = match vec_inner_type(ty) {
+        Some(inner) => (strip_pointers(inner), true),
+        None => (ty, false),
+    };
+    let (value_type, nillable) = match option_inner_type(ty) {
+        Some(inner) if repeated => (strip_pointers(inner), true),
+        _ => (ty, optional && !repeated),
+    };
+    
+    FieldShape { value_type, optional, repeated, nillable }
+}
+
+/// Returns the value type of a field with its `Option`, `Vec`, and pointer wrappers removed.
+pub fn field_value_type(ty: &Type) -> &Type {
+    analyze_field_shape(ty).value_type
+}
+
+/// Checks whether an XSD built-in type has a numeric value space.
+pub fn is_numeric_xsd_type(xsd_type: &str) -> bool {
+    matches!(
+        xsd_type,
+        "xsd:byte" | "xsd:unsignedByte" | "xsd:short" | "xsd:unsignedShort" | "xsd:int"
+            | "xsd:unsignedInt" | "xsd:long" | "xsd:unsignedLong" | "xsd:integer"
+            | "xsd:decimal" | "xsd:float" | "xsd:double"
+    )
+}
+
+/// Maps a Rust field type to its XSD built-in type, falling back to `xsd:anyType`.
+pub fn xsd_type_name(ty: &Type) -> String {
+    // Borrowed, owned, and pointed-to values share the XSD type
+    if let Some(inner) = cow_inner_type(ty).or_else(|| pointer_inner_type(ty)) {
+        return xsd_type_name(inner);
+    }
+    let name = match ty {
+        Type::Path(type_path) => extract_type_name(type_path),
+        Type::Reference(reference) => return xsd_type_name(&reference.elem),
+        _ => String::new(),
+    };
+    
+    match name.as_str() {
+        "String" | "str" => "xsd:string",
+        "bool" => "xsd:boolean",
+        // The XSD value spaces match the Rust ranges, so no bound facets are needed
+        "i8" => "xsd:byte",
+        "u8" => "xsd:unsignedByte",
+        "i16" => "xsd:short",
+        "u16" => "xsd:unsignedShort",
+        "i32" => "xsd:int",
+        "i64" => "xsd:long",
+        "u32" => "xsd:unsignedInt",
+        "u64" => "xsd:unsignedLong",
+        "i128" | "u128" => "xsd:integer",
+        "Decimal" if cfg!(feature = "decimal") => "xsd:decimal",
+        "f32" => "xsd:float",
+        "f64" => "xsd:double",
+        _ => "xsd:anyType",
+    }
+    .to_string()
+}
+
+/// Checks whether a field type (optionally wrapped in `Option`) maps to a simple XSD type.
+pub fn is_simple_field_type(ty: &Type) -> bool {
+    xsd_type_name(option_inner_type(ty).unwrap_or(ty)) != "xsd:anyType"
+}
+
+/// Checks whether a type is the unit type `()`, used for requests without parameters.
+pub fn is_unit_type(ty: &Type) -> bool {
+    matches!(ty, Type::Tuple(tuple) if tuple.elems.is_empty())
+}
+
+/// Returns the inner type of an `Option<T>`, or `None` for any other type.
+pub fn option_inner_type(ty: &Type) -> Option<&Type> {
+    single_generic_argument(ty, "Option")
+}
+
+/// Returns the item type of a `Vec<T>`, or `None` for any other type.
+pub fn vec_inner_type(ty: &Type) -> Option<&Type> {
+    single_generic_argument(ty, "Vec")
+}
+
+/// Returns `T` for a `Box<T>`, `Arc<T>`, or `Rc<T>`, or `None` for any other type.
+pub fn pointer_inner_type(ty: &Type) -> Option<&Type> {
+    single_generic_argument(ty, "Box")
+        .or_else(|| single_generic_argument(ty, "Arc"))
+        .or_else(|| single_generic_argument(ty, "Rc"))
+}
+
+/// Removes any `Box`, `Arc`, and `Rc` wrappers around a type.
+fn strip_pointers(mut ty: &Type) -> &Type {
+    while let Some(inner) = pointer_inner_type(ty) {
+        ty = inner;
+    }
+    ty
+}
+
+/// Returns the borrowed type of a `Cow<'a, B>`, e.g. `str` for `Cow<'a, str>`.
+pub fn cow_inner_type(ty: &Type) -> Option<&Type> {
+    if let Type::Path(type_path) = ty {
+        let segment = type_path.path.segments.last()?;
+        if segment.ident == "Cow" {
+            if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                return args.args.iter().find_map(|arg| match arg {
+                    syn::GenericArgument::Type(inner) => Some(inner),
+                    _ => None,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Returns the key and value types of a `HashMap<K, V>` or `BTreeMap<K, V>`.
+pub fn map_inner_types(ty: &Type) -> Option<(&Type, &Type)> {
+    if let Type::Path(type_path) = ty {
+        let segment = type_path.path.segments.last()?;
+        if segment.ident == "HashMap" || segment.ident == "BTreeMap" {
+            if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                let mut types = args.args.iter().filter_map(|arg| match arg {
+                    syn::GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                });
+                return Some((types.next()?, types.next()?));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the inner type of a `WithHeaders<T>` response, or `None` for any other type.
+pub fn with_headers_inner_type(ty: &Type) -> Option<&Type> {
+    single_generic_argument(ty, "WithHeaders")
+}
+
+/// Returns `T` for a type named `wrapper<T>`, matching on the last path segment.
+fn single_generic_argument<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    if let Type::Path(type_path) = ty {
+        let segment = type_path.path.segments.last()?;
+        if segment.ident == wrapper {
+            if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                    return Some(inner);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Checks whether a type is `f32` or `f64`.
+pub fn is_float_type(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.path.is_ident("f64") || type_path.path.is_ident("f32"))
+}
+
+/// Checks whether a field type is a float or an optional float.
+pub fn is_float_field_type(ty: &Type) -> bool {
+    is_float_type(option_inner_type(ty).unwrap_or(ty))
+}
+
+/// Checks whether a field type (optionally wrapped in `Option`) is serialized through its
+/// `Display`/`FromStr` text, because serde_xml_rs cannot handle it natively or exactly.
+/// 
+/// Covers `i128`, `u128`, and `Decimal` with the `decimal` feature.
+pub fn is_lexical_field_type(ty: &Type) -> bool {
+    match option_inner_type(ty).unwrap_or(ty) {
+        Type::Path(type_path) => {
+            let name = extract_type_name(type_path);
+            matches!(name.as_str(), "i128" | "u128") || (name == "Decimal" && cfg!(feature = "decimal"))
+        }
+        _ => false,
+    }
+}
+
 /// Extracts the type name from a TypePath, returning the last segment.
 fn extract_type_name(type_path: &TypePath) -> String {
     type_path
@@ -49,26 +425,260 @@ fn extract_type_name(type_path: &TypePath) -> String {
         .unwrap_or_else(|| "Unknown".to_string())
 }
 
+/// Returns the path of a type without generics or a leading `self::`, e.g. `other::Request`.
+fn type_path_name(type_path: &TypePath) -> String {
+    let segments: Vec<String> = type_path
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .skip_while(|segment| segment == "self")
+        .collect();
+    let leading_colon = if type_path.path.leading_colon.is_some() { "::" } else { "" };
+    
+    format!("{}{}", leading_colon, segments.join("::"))
+}
+
+/// Returns the faults of an error type declared as an enum in the service module.
+/// 
+/// Each variant becomes a fault named `{Variant}Fault`, in declaration order. Other error
+/// types, including generic enums, have no faults of their own and only get the generic
+/// SOAP fault.
+pub fn error_faults(module: &ItemMod, ty: &Type) -> Vec<FaultInfo> {
+    let type_path = match ty {
+        Type::Path(type_path) if !type_path_name(type_path).contains("::") => type_path,
+        _ => return Vec::new(),
+    };
+    let type_name = extract_type_name(type_path);
+    let items = match &module.content {
+        Some((_, items)) => items,
+        None => return Vec::new(),
+    };
+    let item_enum = items.iter().find_map(|item| match item {
+        Item::Enum(item_enum) if item_enum.ident == type_name && item_enum.generics.params.is_empty() => {
+            Some(item_enum)
+        }
+        _ => None,
+    });
+
+    item_enum
+        .map(|item_enum| {
+            item_enum
+                .variants
+                .iter()
+                .map(|variant| FaultInfo {
+                    error_type: item_enum.ident.clone(),
+                    variant: variant.ident.clone(),
+                    name: format!("{}Fault", variant.ident),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Checks whether the module implements `SoapAttachmentResponse` for the given type.
+/// 
+/// Operations returning such a type are answered with a `multipart/related` response.
+pub fn implements_attachment_response(module: &ItemMod, ty: &Type) -> bool {
+    implements_module_trait(module, ty, "SoapAttachmentResponse")
+}
+
+/// Checks whether the module implements `FromSoapHeaders` for the given type.
+/// 
+/// An operation parameter of such a type is built from the request's SOAP headers.
+pub fn implements_from_soap_headers(module: &ItemMod, ty: &Type) -> bool {
+    implements_module_trait(module, ty, "FromSoapHeaders")
+}
+
+/// Checks whether the module implements `PartialSuccess` for the given type.
+/// 
+/// Only batch responses implementing it can be faulted when all their items fail.
+pub fn implements_partial_success(module: &ItemMod, ty: &Type) -> bool {
+    implements_module_trait(module, ty, "PartialSuccess")
+}
+
+/// Checks whether the module implements `RawSoapBody` for the given type.
+/// 
+/// Operations returning such a type write their own body XML instead of serializing it.
+pub fn implements_raw_soap_body(module: &ItemMod, ty: &Type) -> bool {
+    implements_module_trait(module, ty, "RawSoapBody")
+}
+
+/// Checks whether the module implements `Validate` for the given type.
+/// 
+/// Requests of such a type are validated before the operation is called.
+pub fn implements_validate(module: &ItemMod, ty: &Type) -> bool {
+    implements_module_trait(module, ty, "Validate")
+}
+
+/// Checks whether the module implements `SoapFault` for the given type.
+/// 
+/// Errors of such a type choose the HTTP status of their fault response.
+pub fn implements_soap_fault(module: &ItemMod, ty: &Type) -> bool {
+    implements_module_trait(module, ty, "SoapFault")
+}
+
+/// Checks whether the module implements `SoapStreamingResponse` for the given type.
+/// 
+/// Operations returning such a type stream their response with chunked encoding.
+pub fn implements_streaming_response(module: &ItemMod, ty: &Type) -> bool {
+    implements_module_trait(module, ty, "SoapStreamingResponse")
+}
+
+/// Returns the `ITEM_ELEMENT` children a streamed response type is written as.
+/// 
+/// The element name and item type are read from the module's `SoapStreamingResponse`
+/// implementation, so `ITEM_ELEMENT` must be a string literal.
+pub fn streaming_item_field(module: &ItemMod, ty: &Type) -> Result<FieldInfo> {
+    let item_impl = find_module_impl(module, ty, "SoapStreamingResponse")
+        .ok_or_else(|| Error::new_spanned(ty, "Missing SoapStreamingResponse implementation"))?;
+    let mut item_element = None;
+    let mut item_type = None;
+    for item in &item_impl.items {
+        match item {
+            ImplItem::Const(constant) if constant.ident == "ITEM_ELEMENT" => match &constant.expr {
+                Expr::Lit(ExprLit { lit: Lit::Str(name), .. }) => item_element = Some(name.value()),
+                expr => return Err(Error::new_spanned(expr, "ITEM_ELEMENT must be a string literal")),
+            },
+            ImplItem::Type(item) if item.ident == "Item" => item_type = Some(&item.ty),
+            _ => {}
+        }
+    }
+    let (Some(xml_name), Some(item_type)) = (item_element, item_type) else {
+        return Err(Error::new_spanned(item_impl, "SoapStreamingResponse must define ITEM_ELEMENT and Item"));
+    };
+    
+    // A stream may end before its first item
+    Ok(FieldInfo {
+        xml_name,
+        field_type: xsd_type_name(item_type),
+        optional: true,
+        repeated: true,
+        nillable: false,
+        attribute: false,
+        map_entry: None,
+        default: None,
+        facets: Facets::default(),
+    })
+}
+
+/// Checks whether the module contains an `impl {trait_name} for {ty}` block.
+fn implements_module_trait(module: &ItemMod, ty: &Type, trait_name: &str) -> bool {
+    find_module_impl(module, ty, trait_name).is_some()
+}
+
+/// Finds the module's `impl {trait_name} for {ty}` block.
+fn find_module_impl<'a>(module: &'a ItemMod, ty: &Type, trait_name: &str) -> Option<&'a ItemImpl> {
+    let type_name = match ty {
+        Type::Path(type_path) => extract_type_name(type_path),
+        _ => return None,
+    };
+    
+    module.content.as_ref()?.1.iter().find_map(|item| match item {
+        Item::Impl(item_impl) => {
+            let implements_trait = item_impl
+                .trait_
+                .as_ref()
+                .and_then(|(_, path, _)| path.segments.last())
+                .is_some_and(|segment| segment.ident == trait_name);
+            let matches_type = matches!(
+                item_impl.self_ty.as_ref(),
+                Type::Path(self_path) if extract_type_name(self_path) == type_name
+            );
+            (implements_trait && matches_type).then_some(item_impl)
+        }
+        _ => None,
+    })
+}
+
+/// Returns the Rust type name a type is keyed under in the collected types.
+fn type_key(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => extract_type_name(type_path),
+        _ => String::new(),
+    }
+}
+
 /// Collects all unique types from SOAP operations for WSDL generation.
 /// 
-/// Analyzes request and response types from all operations and returns
-/// a map of type names to TypeInfo structs.
+/// Analyzes request and response types from all operations against the struct
+/// definitions in the module and returns a map of Rust type names to TypeInfo structs.
+/// Distinct types mapping to the same XSD element with different fields are rejected,
+/// since the WSDL can only describe one of them. So are types that share a name but are
+/// reached through different paths (e.g. `Request` and `v1::Request`), since types are
+/// keyed by name.
 pub fn collect_types_from_operations(
     operations: &[crate::parser::SoapOperation],
+    module: &ItemMod,
 ) -> Result<HashMap<String, TypeInfo>> {
     let mut types = HashMap::new();
+    let mut paths = HashMap::new();
     
     for operation in operations {
-        // Analyze request type
-        let request_type_info = analyze_type(&operation.request_type)?;
-        types.insert(request_type_info.name.clone(), request_type_info);
+        // Analyze request type (unit requests get an empty element in the WSDL instead)
+        if !is_unit_type(&operation.request_type) {
+            insert_type(&mut types, &mut paths, &operation.request_type, module)?;
+        }
         
         // Analyze response type  
-        let response_type_info = analyze_type(&operation.response_type)?;
-        types.insert(response_type_info.name.clone(), response_type_info);
+        insert_type(&mut types, &mut paths, &operation.response_type, module)?;
+        
+        // Streamed responses are written as their items, not their fields
+        if operation.returns_stream {
+            let item_field = streaming_item_field(module, &operation.response_type)?;
+            if let Some(type_info) = types.get_mut(&type_key(&operation.response_type)) {
+                type_info.fields = vec![item_field];
+            }
+        }
         
         // Note: We skip error types for now as they're typically not part of WSDL
     }
     
     Ok(types)
-}
\ No newline at end of file
+}
+
+/// Analyzes a type and adds it to the collected types, checking for name conflicts.
+fn insert_type(
+    types: &mut HashMap<String, TypeInfo>,
+    paths: &mut HashMap<String, String>,
+    ty: &Type,
+    module: &ItemMod,
+) -> Result<()> {
+    let key = type_key(ty);
+    let type_info = analyze_type(ty, module)?;
+    
+    if let Type::Path(type_path) = ty {
+        let path = type_path_name(type_path);
+        match paths.get(&key) {
+            Some(other_path) if *other_path != path => {
+                return Err(Error::new_spanned(
+                    ty,
+                    format!(
+                        "Types {} and {} share the name {}; refer to a type by the same path in every operation, or rename one of them",
+                        other_path, path, key
+                    ),
+                ));
+            }
+            Some(_) => {}
+            None => {
+                paths.insert(key.clone(), path);
+            }
+        }
+    }
+    
+    let conflict = types.iter().find(|(other_key, other)| {
+        **other_key != key && other.name == type_info.name && other.fields != type_info.fields
+    });
+    if let Some((other_key, _)) = conflict {
+        return Err(Error::new_spanned(
+            ty,
+            format!(
+                "Types {} and {} both map to the XSD element {} but have different fields",
+                other_key, key, type_info.name
+            ),
+        ));
+    }
+    
+    types.insert(key, type_info);
+    Ok(())
+}
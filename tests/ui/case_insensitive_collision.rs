@@ -0,0 +1,35 @@
+use soap_service::service;
+
+#[service(
+    namespace = "http://example.com/values",
+    service_name = "ValueService",
+    port_name = "ValuePort",
+    bind_path = "/soap/values",
+    case_insensitive_operations = true
+)]
+mod value_service {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ValueRequest {
+        #[serde(rename = "Key")]
+        pub key: String,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct ValueResponse {
+        #[serde(rename = "Value")]
+        pub value: String,
+    }
+
+    pub async fn get_value(req: ValueRequest) -> Result<ValueResponse, std::io::Error> {
+        Ok(ValueResponse { value: req.key })
+    }
+
+    #[operation(name = "Getvalue")]
+    pub async fn get_value_legacy(req: ValueRequest) -> Result<ValueResponse, std::io::Error> {
+        Ok(ValueResponse { value: req.key })
+    }
+}
+
+fn main() {}
@@ -0,0 +1,53 @@
+use soap_service::service;
+
+#[derive(Debug)]
+pub struct ServiceError(pub String);
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+#[service(
+    namespace = "http://example.com/lookup",
+    service_name = "LookupService",
+    port_name = "LookupPort",
+    bind_path = "/soap/lookup"
+)]
+mod lookup_service {
+    use super::ServiceError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct FindRequest {
+        #[serde(rename = "Id")]
+        pub id: i32,
+    }
+
+    // A different struct with the same element name as FindRequest
+    #[derive(Deserialize, Serialize, Debug)]
+    #[soap(element = "FindRequest")]
+    pub struct SearchRequest {
+        #[serde(rename = "Query")]
+        pub query: String,
+    }
+
+    #[derive(Deserialize, Serialize, Debug)]
+    pub struct FindResponse {
+        #[serde(rename = "Name")]
+        pub name: String,
+    }
+
+    pub async fn find(req: FindRequest) -> Result<FindResponse, ServiceError> {
+        Ok(FindResponse { name: req.id.to_string() })
+    }
+
+    pub async fn search(req: SearchRequest) -> Result<FindResponse, ServiceError> {
+        Ok(FindResponse { name: req.query })
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use soap_service::service;
+
+#[service(
+    namespace = "http://example.com/paths",
+    service_name = "PathService",
+    port_name = "PathPort",
+    bind_path = "/soap/paths",
+    wsdl_path = "paths.wsdl"
+)]
+mod path_service {
+    pub struct PingRequest {}
+
+    pub struct PingResponse {}
+
+    pub async fn ping(_request: PingRequest) -> Result<PingResponse, String> {
+        Ok(PingResponse {})
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use soap_service::service;
+
+#[service(
+    namespace = "http://example.com/empty",
+    service_name = "EmptyService",
+    port_name = "EmptyPort",
+    bind_path = "/soap/empty"
+)]
+mod empty_service {
+    pub fn helper() {}
+}
+
+fn main() {}